@@ -0,0 +1,163 @@
+// Third Party
+use quote::Tokens;
+use syn::{Attribute, Ident, Lit, MetaItem, NestedMetaItem};
+
+// A switch given by `#[clap(short)]` or `#[clap(long)]`, which is either derived from the name of
+// the field or given explicitly (i.e. `#[clap(long = "dry-run")]`)
+pub enum Switch {
+    Derived,
+    Named(String),
+}
+
+// The settings of an `App` or `Arg`, collected from the `#[clap(...)]` attributes and the doc
+// comments of a struct, enum, variant or field
+pub struct Attrs {
+    pub name: Option<String>,
+    pub short: Option<Switch>,
+    pub long: Option<Switch>,
+    // `#[clap(subcommand)]`, the field holds the subcommand which was used
+    pub subcommand: bool,
+    // `#[clap(occurrences)]`, the field holds the number of times a flag was used
+    pub occurrences: bool,
+    // All other attributes, which call the method of the same name with the value, or with
+    // `true` for attributes without a value (i.e. `#[clap(global)]`)
+    methods: Vec<(Ident, Tokens)>,
+    // The paragraphs of the doc comment
+    doc: Vec<String>,
+}
+
+impl Attrs {
+    pub fn from_attrs(attrs: &[Attribute]) -> Self {
+        let mut ret = Attrs {
+            name: None,
+            short: None,
+            long: None,
+            subcommand: false,
+            occurrences: false,
+            methods: vec![],
+            doc: vec![],
+        };
+        let mut doc = String::new();
+        for attr in attrs {
+            match attr.value {
+                MetaItem::NameValue(ref ident, Lit::Str(ref s, _)) if ident == "doc" => {
+                    doc.push_str(strip_doc(s));
+                    doc.push('\n');
+                }
+                MetaItem::List(ref ident, ref items) if ident == "clap" => {
+                    for item in items {
+                        ret.push(item);
+                    }
+                }
+                _ => (),
+            }
+        }
+        ret.doc = paragraphs(&doc);
+        ret
+    }
+
+    fn push(&mut self, item: &NestedMetaItem) {
+        match *item {
+            NestedMetaItem::MetaItem(MetaItem::Word(ref ident)) => {
+                match ident.as_ref() {
+                    "short" => self.short = Some(Switch::Derived),
+                    "long" => self.long = Some(Switch::Derived),
+                    "subcommand" => self.subcommand = true,
+                    "occurrences" => self.occurrences = true,
+                    _ => self.methods.push((ident.clone(), quote!(true))),
+                }
+            }
+            NestedMetaItem::MetaItem(MetaItem::NameValue(ref ident, ref lit)) => {
+                match (ident.as_ref(), lit) {
+                    ("name", &Lit::Str(ref s, _)) => self.name = Some(s.clone()),
+                    ("short", &Lit::Str(ref s, _)) => self.short = Some(Switch::Named(s.clone())),
+                    ("long", &Lit::Str(ref s, _)) => self.long = Some(Switch::Named(s.clone())),
+                    ("name", _) | ("short", _) | ("long", _) => {
+                        panic!("clap_derive: the value of `{}` must be a string", ident)
+                    }
+                    _ => self.methods.push((ident.clone(), quote!(#lit))),
+                }
+            }
+            _ => panic!("clap_derive: unsupported attribute, expected `#[clap(key)]` or \
+                         `#[clap(key = \"value\")]`"),
+        }
+    }
+
+    // Whether the attributes call the method `name`, i.e. to not override an explicit
+    // `#[clap(about = "...")]` with the doc comment
+    pub fn has_method(&self, name: &str) -> bool { self.methods.iter().any(|&(ref m, _)| m == name) }
+
+    // The calls of the methods given by the attributes
+    pub fn methods(&self) -> Tokens {
+        let calls = self.methods.iter().map(|&(ref m, ref v)| quote!(.#m(#v)));
+        quote!(#(#calls)*)
+    }
+
+    // The doc comment as calls of the methods `short` and `long` (i.e. `about` and `long_about`),
+    // the first paragraph becoming the short description, and the whole comment the long one
+    // if it has more than one paragraph
+    pub fn doc(&self, short: &str, long: &str) -> Tokens {
+        let mut ret = Tokens::new();
+        if let Some(first) = self.doc.first() {
+            if !self.has_method(short) {
+                let short = Ident::new(short);
+                ret.append_all(&[quote!(.#short(#first))]);
+            }
+        }
+        if self.doc.len() > 1 && !self.has_method(long) {
+            let text = self.doc.join("\n\n");
+            let long = Ident::new(long);
+            ret.append_all(&[quote!(.#long(#text))]);
+        }
+        ret
+    }
+}
+
+// Converts an identifier to kebab case, i.e. `dry_run` and `DryRun` to `dry-run`
+pub fn kebab_case(ident: &str) -> String {
+    let mut ret = String::with_capacity(ident.len());
+    for (i, c) in ident.chars().enumerate() {
+        if c == '_' {
+            ret.push('-');
+        } else if c.is_uppercase() {
+            if i > 0 && !ret.ends_with('-') {
+                ret.push('-');
+            }
+            ret.extend(c.to_lowercase());
+        } else {
+            ret.push(c);
+        }
+    }
+    ret
+}
+
+// Doc comments are either passed as `#[doc = " text"]` or, by older compilers, as the comment
+// itself (i.e. `/// text`)
+fn strip_doc(s: &str) -> &str {
+    let s = if s.starts_with("///") || s.starts_with("//!") {
+        &s[3..]
+    } else {
+        s
+    };
+    if s.starts_with(' ') { &s[1..] } else { s }
+}
+
+// Splits a doc comment into paragraphs, joining the lines of each paragraph with spaces
+fn paragraphs(doc: &str) -> Vec<String> {
+    let mut ret = vec![];
+    let mut cur: Vec<&str> = vec![];
+    for line in doc.lines().map(str::trim) {
+        if line.is_empty() {
+            if !cur.is_empty() {
+                ret.push(cur.join(" "));
+                cur.clear();
+            }
+        } else {
+            cur.push(line);
+        }
+    }
+    if !cur.is_empty() {
+        ret.push(cur.join(" "));
+    }
+    ret
+}
@@ -0,0 +1,219 @@
+// Third Party
+use quote::Tokens;
+use syn::{Field, Ident, PathParameters, Ty};
+
+// Internal
+use attrs::{kebab_case, Attrs, Switch};
+
+// How a field is parsed, which is determined by its type and attributes
+enum Kind<'f> {
+    // `bool`, whether a flag was used
+    Flag,
+    // `#[clap(occurrences)]`, how many times a flag was used
+    Occurrences,
+    // `Option<T>`, an optional value
+    Optional(&'f Ty),
+    // `Vec<T>`, any number of values
+    Multiple(&'f Ty),
+    // Any other type, a required value
+    Required,
+    // `#[clap(subcommand)]`, the subcommand which was used, if it's an `Option`
+    Subcommand { optional: bool, ty: &'f Ty },
+}
+
+// A field of a struct or of an enum variant with named fields
+struct ArgField<'f> {
+    ident: &'f Ident,
+    ty: &'f Ty,
+    kind: Kind<'f>,
+    attrs: Attrs,
+}
+
+impl<'f> ArgField<'f> {
+    fn new(field: &'f Field) -> Self {
+        let ident = field.ident.as_ref().expect("clap_derive: fields must be named");
+        let attrs = Attrs::from_attrs(&field.attrs);
+        let kind = if attrs.subcommand {
+            match inner_ty("Option", &field.ty) {
+                Some(ty) => Kind::Subcommand {
+                    optional: true,
+                    ty: ty,
+                },
+                None => Kind::Subcommand {
+                    optional: false,
+                    ty: &field.ty,
+                },
+            }
+        } else if attrs.occurrences {
+            Kind::Occurrences
+        } else if is_ty("bool", &field.ty) {
+            Kind::Flag
+        } else if let Some(ty) = inner_ty("Option", &field.ty) {
+            Kind::Optional(ty)
+        } else if let Some(ty) = inner_ty("Vec", &field.ty) {
+            Kind::Multiple(ty)
+        } else {
+            Kind::Required
+        };
+        ArgField {
+            ident: ident,
+            ty: &field.ty,
+            kind: kind,
+            attrs: attrs,
+        }
+    }
+
+    // The name of the argument, which is the name of the field unless given with
+    // `#[clap(name = "...")]`
+    fn name(&self) -> String { self.attrs.name.clone().unwrap_or_else(|| self.ident.to_string()) }
+
+    // Adds the argument (or the subcommands) of the field to `app`
+    fn augment(&self) -> Tokens {
+        if let Kind::Subcommand { optional, ty } = self.kind {
+            let setting = if optional {
+                quote!()
+            } else {
+                quote!(.setting(::clap::AppSettings::SubcommandRequiredElseHelp))
+            };
+            return quote! {
+                let app = <#ty as ::clap::Subcommand>::augment_subcommands(app) #setting;
+            };
+        }
+
+        let name = self.name();
+        let short = match self.attrs.short {
+            Some(Switch::Derived) => {
+                let s = name.chars().next().expect("clap_derive: empty argument name").to_string();
+                quote!(.short(#s))
+            }
+            Some(Switch::Named(ref s)) => quote!(.short(#s)),
+            None => quote!(),
+        };
+        let long = match self.attrs.long {
+            Some(Switch::Derived) => {
+                let l = kebab_case(&name);
+                quote!(.long(#l))
+            }
+            Some(Switch::Named(ref l)) => quote!(.long(#l)),
+            None => quote!(),
+        };
+        let settings = match self.kind {
+            Kind::Flag | Kind::Occurrences if self.attrs.short.is_none() &&
+                                               self.attrs.long.is_none() => {
+                panic!("clap_derive: the flag `{}` needs `#[clap(short)]` or `#[clap(long)]`",
+                       self.ident)
+            }
+            Kind::Flag => quote!(),
+            Kind::Occurrences => quote!(.multiple(true)),
+            Kind::Optional(_) => quote!(.takes_value(true)),
+            Kind::Multiple(_) => quote!(.takes_value(true).multiple(true)),
+            Kind::Required if self.attrs.has_method("default_value") => quote!(.takes_value(true)),
+            Kind::Required => quote!(.takes_value(true).required(true)),
+            Kind::Subcommand { .. } => unreachable!(),
+        };
+        let doc = self.attrs.doc("help", "long_help");
+        let methods = self.attrs.methods();
+        quote! {
+            let app = app.arg(::clap::Arg::with_name(#name)
+                #short #long #settings #doc #methods);
+        }
+    }
+
+    // The value of the field, read from `matches`
+    fn value(&self, app_name: &Tokens) -> Tokens {
+        let name = self.name();
+        let ty = self.ty;
+        match self.kind {
+            Kind::Flag => quote!(matches.is_present(#name)),
+            Kind::Occurrences => quote!(matches.occurrences_of(#name) as #ty),
+            Kind::Optional(inner) => {
+                quote! {
+                    if matches.is_present(#name) {
+                        Some(matches.value_of_t::<#inner, _>(#name)?)
+                    } else {
+                        None
+                    }
+                }
+            }
+            Kind::Multiple(inner) => {
+                quote! {
+                    if matches.is_present(#name) {
+                        matches.values_of_t::<#inner, _>(#name)?
+                    } else {
+                        Vec::new()
+                    }
+                }
+            }
+            Kind::Required => quote!(matches.value_of_t::<#ty, _>(#name)?),
+            Kind::Subcommand { optional, ty } => {
+                let missing = if optional {
+                    quote!(None)
+                } else {
+                    quote! {
+                        return Err(::clap::Error::missing_subcommand_auto(#app_name,
+                                                                          matches.usage()))
+                    }
+                };
+                let found = if optional { quote!(Some(r?)) } else { quote!(r?) };
+                quote! {
+                    {
+                        let (name, sub) = matches.subcommand();
+                        match <#ty as ::clap::Subcommand>::from_subcommand(name, sub) {
+                            Some(r) => #found,
+                            None => #missing,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// The statements adding the arguments of the fields to an `App` bound to `app`
+pub fn augment(fields: &[Field]) -> Tokens {
+    let stmts = fields.iter().map(|f| ArgField::new(f).augment());
+    quote!(#(#stmts)*)
+}
+
+// The fields of a struct expression, reading the values from `ArgMatches` bound to `matches`
+pub fn construct(fields: &[Field], app_name: &Tokens) -> Tokens {
+    let values = fields
+        .iter()
+        .map(|f| {
+                 let f = ArgField::new(f);
+                 let ident = f.ident;
+                 let value = f.value(app_name);
+                 quote!(#ident: #value)
+             });
+    quote!(#(#values),*)
+}
+
+// Whether the type is `name`, i.e. `bool`
+fn is_ty(name: &str, ty: &Ty) -> bool {
+    match *ty {
+        Ty::Path(None, ref path) => {
+            path.segments.len() == 1 && path.segments[0].ident == name &&
+            path.segments[0].parameters.is_empty()
+        }
+        _ => false,
+    }
+}
+
+// The type parameter of the type if it's `name<T>`, i.e. `T` for `Option<T>`
+fn inner_ty<'t>(name: &str, ty: &'t Ty) -> Option<&'t Ty> {
+    let segment = match *ty {
+        Ty::Path(None, ref path) => path.segments.last(),
+        _ => None,
+    };
+    match segment {
+        Some(s) if s.ident == name => {
+            match s.parameters {
+                PathParameters::AngleBracketed(ref params) if params.types.len() == 1 => {
+                    Some(&params.types[0])
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
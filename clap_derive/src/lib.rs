@@ -0,0 +1,253 @@
+//! Derives [`clap`] `App`s from the definition of a struct or an enum, and builds the struct or
+//! enum back from the `ArgMatches`, so that arguments are accessed as typed fields instead of by
+//! their names.
+//!
+//! `#[derive(Clap)]` implements the `clap::Clap` trait, whose documentation describes how the
+//! struct or enum is turned into an `App`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate clap_derive;
+//! extern crate clap;
+//!
+//! use clap::Clap;
+//!
+//! /// Manages remote repositories
+//! #[derive(Clap, Debug, PartialEq)]
+//! #[clap(name = "remote")]
+//! struct Remote {
+//!     /// Show more details
+//!     #[clap(short, long, occurrences)]
+//!     verbose: u8,
+//!     #[clap(subcommand)]
+//!     cmd: Command,
+//! }
+//!
+//! #[derive(Clap, Debug, PartialEq)]
+//! enum Command {
+//!     /// Adds a remote
+//!     Add {
+//!         /// Fetch the remote after adding it
+//!         #[clap(short)]
+//!         fetch: bool,
+//!         name: String,
+//!         url: String,
+//!     },
+//!     /// Lists the remotes
+//!     List,
+//! }
+//!
+//! fn main() {
+//!     let remote = Remote::parse_from(vec!["remote", "-vv", "add", "-f", "origin", "/repo"]);
+//!     assert_eq!(remote,
+//!                Remote {
+//!                    verbose: 2,
+//!                    cmd: Command::Add {
+//!                        fetch: true,
+//!                        name: "origin".into(),
+//!                        url: "/repo".into(),
+//!                    },
+//!                });
+//! }
+//! ```
+//! [`clap`]: https://docs.rs/clap/
+
+#![recursion_limit = "256"]
+
+extern crate proc_macro;
+extern crate syn;
+#[macro_use]
+extern crate quote;
+
+mod attrs;
+mod fields;
+
+// Third Party
+use proc_macro::TokenStream;
+use quote::Tokens;
+use syn::{Body, DeriveInput, Ident, Variant, VariantData};
+
+// Internal
+use attrs::{kebab_case, Attrs};
+
+/// Implements `clap::Clap` (and the traits it requires) for a struct, and additionally
+/// `clap::Subcommand` for an enum.
+#[proc_macro_derive(Clap, attributes(clap))]
+pub fn derive_clap(input: TokenStream) -> TokenStream {
+    let ast = syn::parse_derive_input(&input.to_string())
+        .expect("clap_derive: failed to parse the input");
+    let gen = match ast.body {
+        Body::Struct(VariantData::Struct(ref fields)) => derive_struct(&ast, fields),
+        Body::Struct(VariantData::Unit) => derive_struct(&ast, &[]),
+        Body::Struct(VariantData::Tuple(_)) => {
+            panic!("clap_derive: tuple structs aren't supported, the fields must be named")
+        }
+        Body::Enum(ref variants) => derive_enum(&ast, variants),
+    };
+    gen.parse().expect("clap_derive: failed to parse the generated code")
+}
+
+// The `App` of a struct or enum, which is named after the crate unless named explicitly
+fn app(attrs: &Attrs) -> (Tokens, Tokens) {
+    let name = match attrs.name {
+        Some(ref n) => quote!(#n),
+        None => quote!(env!("CARGO_PKG_NAME")),
+    };
+    let version = if attrs.has_method("version") {
+        quote!()
+    } else {
+        quote!(.version(env!("CARGO_PKG_VERSION")))
+    };
+    let doc = attrs.doc("about", "long_about");
+    let methods = attrs.methods();
+    (quote!(::clap::App::new(#name) #version #doc #methods), name)
+}
+
+fn derive_struct(ast: &DeriveInput, fields: &[syn::Field]) -> Tokens {
+    let ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let attrs = Attrs::from_attrs(&ast.attrs);
+    let (app, app_name) = app(&attrs);
+    let augment = fields::augment(fields);
+    let construct = fields::construct(fields, &app_name);
+    let unused = if fields.is_empty() {
+        quote!(let _ = matches;)
+    } else {
+        quote!()
+    };
+
+    quote! {
+        impl #impl_generics ::clap::IntoApp for #ident #ty_generics #where_clause {
+            fn into_app<'a, 'b>() -> ::clap::App<'a, 'b> {
+                Self::augment_app(#app)
+            }
+
+            fn augment_app<'a, 'b>(app: ::clap::App<'a, 'b>) -> ::clap::App<'a, 'b> {
+                #augment
+                app
+            }
+        }
+
+        impl #impl_generics ::clap::FromArgMatches for #ident #ty_generics #where_clause {
+            fn from_arg_matches(matches: &::clap::ArgMatches) -> ::clap::Result<Self> {
+                #unused
+                Ok(#ident { #construct })
+            }
+        }
+
+        impl #impl_generics ::clap::Clap for #ident #ty_generics #where_clause {}
+    }
+}
+
+fn derive_enum(ast: &DeriveInput, variants: &[Variant]) -> Tokens {
+    let ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let attrs = Attrs::from_attrs(&ast.attrs);
+    let (app, app_name) = app(&attrs);
+    let subcommands = variants.iter().map(|v| subcommand(v));
+    let arms = variants.iter().map(|v| subcommand_arm(ident, v));
+
+    quote! {
+        impl #impl_generics ::clap::Subcommand for #ident #ty_generics #where_clause {
+            fn augment_subcommands<'a, 'b>(app: ::clap::App<'a, 'b>) -> ::clap::App<'a, 'b> {
+                app #(.subcommand(#subcommands))*
+            }
+
+            fn from_subcommand(name: &str, sub: Option<&::clap::ArgMatches>)
+                               -> Option<::clap::Result<Self>> {
+                match (name, sub) {
+                    #(#arms)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl #impl_generics ::clap::IntoApp for #ident #ty_generics #where_clause {
+            fn into_app<'a, 'b>() -> ::clap::App<'a, 'b> {
+                Self::augment_app(#app)
+            }
+
+            fn augment_app<'a, 'b>(app: ::clap::App<'a, 'b>) -> ::clap::App<'a, 'b> {
+                <Self as ::clap::Subcommand>::augment_subcommands(app)
+                    .setting(::clap::AppSettings::SubcommandRequiredElseHelp)
+            }
+        }
+
+        impl #impl_generics ::clap::FromArgMatches for #ident #ty_generics #where_clause {
+            fn from_arg_matches(matches: &::clap::ArgMatches) -> ::clap::Result<Self> {
+                let (name, sub) = matches.subcommand();
+                match <Self as ::clap::Subcommand>::from_subcommand(name, sub) {
+                    Some(r) => r,
+                    None => Err(::clap::Error::missing_subcommand_auto(#app_name,
+                                                                       matches.usage())),
+                }
+            }
+        }
+
+        impl #impl_generics ::clap::Clap for #ident #ty_generics #where_clause {}
+    }
+}
+
+// The name of the subcommand of a variant, which is the name of the variant in kebab case
+// unless named explicitly
+fn subcommand_name(variant: &Variant, attrs: &Attrs) -> String {
+    attrs.name.clone().unwrap_or_else(|| kebab_case(variant.ident.as_ref()))
+}
+
+// The `SubCommand` of a variant, with the arguments of its fields or of the struct it wraps
+fn subcommand(variant: &Variant) -> Tokens {
+    let attrs = Attrs::from_attrs(&variant.attrs);
+    let name = subcommand_name(variant, &attrs);
+    let doc = attrs.doc("about", "long_about");
+    let methods = attrs.methods();
+    let augment = match variant.data {
+        VariantData::Struct(ref fields) => fields::augment(fields),
+        VariantData::Tuple(ref fields) if fields.len() == 1 => {
+            let ty = &fields[0].ty;
+            quote!(let app = <#ty as ::clap::IntoApp>::augment_app(app);)
+        }
+        VariantData::Tuple(_) => {
+            panic!("clap_derive: the variant `{}` must have named fields or wrap a single type",
+                   variant.ident)
+        }
+        VariantData::Unit => quote!(),
+    };
+    quote! {
+        {
+            let app = ::clap::SubCommand::with_name(#name) #doc #methods;
+            #augment
+            app
+        }
+    }
+}
+
+// The match arm building a variant from the matches of its subcommand
+fn subcommand_arm(enum_ident: &Ident, variant: &Variant) -> Tokens {
+    let attrs = Attrs::from_attrs(&variant.attrs);
+    let name = subcommand_name(variant, &attrs);
+    let ident = &variant.ident;
+    match variant.data {
+        VariantData::Struct(ref fields) => {
+            let construct = fields::construct(fields, &quote!(#name));
+            quote! {
+                (#name, Some(matches)) => {
+                    Some((|| -> ::clap::Result<Self> {
+                              Ok(#enum_ident::#ident { #construct })
+                          })())
+                }
+            }
+        }
+        VariantData::Tuple(ref fields) => {
+            let ty = &fields[0].ty;
+            quote! {
+                (#name, Some(matches)) => {
+                    Some(<#ty as ::clap::FromArgMatches>::from_arg_matches(matches)
+                             .map(#enum_ident::#ident))
+                }
+            }
+        }
+        VariantData::Unit => quote!((#name, Some(_)) => Some(Ok(#enum_ident::#ident)),),
+    }
+}
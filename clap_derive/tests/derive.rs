@@ -0,0 +1,148 @@
+#[macro_use]
+extern crate clap_derive;
+extern crate clap;
+
+use clap::{Clap, ErrorKind, IntoApp};
+
+/// Copies files around
+#[derive(Clap, Debug, PartialEq)]
+#[clap(name = "cp", version = "1.0")]
+struct Copy {
+    /// Overwrite existing files
+    #[clap(short, long)]
+    force: bool,
+    /// Show what's copied, more times for more details
+    #[clap(short, long, occurrences)]
+    verbose: u64,
+    /// How many times to retry
+    #[clap(long, default_value = "3")]
+    retries: u32,
+    /// Keep these attributes
+    #[clap(long = "preserve", short = "p",
+           possible_value = "mode", possible_value = "ownership")]
+    preserve: Option<String>,
+    /// The files to copy
+    #[clap(required)]
+    sources: Vec<String>,
+}
+
+#[test]
+fn flags_options_and_positionals() {
+    let copy = Copy::try_parse_from(vec!["cp", "-fvv", "--preserve", "mode", "a", "b"]).unwrap();
+    assert_eq!(copy,
+               Copy {
+                   force: true,
+                   verbose: 2,
+                   retries: 3,
+                   preserve: Some("mode".into()),
+                   sources: vec!["a".into(), "b".into()],
+               });
+}
+
+#[test]
+fn defaults_and_missing_values() {
+    let copy = Copy::try_parse_from(vec!["cp", "a"]).unwrap();
+    assert!(!copy.force);
+    assert_eq!(copy.verbose, 0);
+    assert_eq!(copy.retries, 3);
+    assert_eq!(copy.preserve, None);
+}
+
+#[test]
+fn invalid_value_is_a_typed_error() {
+    let err = Copy::try_parse_from(vec!["cp", "--retries", "many", "a"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+}
+
+#[test]
+fn raw_attributes_are_method_calls() {
+    let err = Copy::try_parse_from(vec!["cp", "-p", "time", "a"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidValue);
+
+    let err = Copy::try_parse_from(vec!["cp"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn doc_comments_are_help() {
+    let mut help = vec![];
+    Copy::into_app().write_help(&mut help).unwrap();
+    let help = String::from_utf8(help).unwrap();
+    assert!(help.starts_with("cp 1.0\nCopies files around\n"));
+    assert!(help.contains("Overwrite existing files"));
+    assert!(help.contains("How many times to retry [default: 3]"));
+    assert!(help.contains("<sources>...    The files to copy"));
+}
+
+/// Builds the project
+#[derive(Clap, Debug, PartialEq)]
+struct Build {
+    /// The build profile
+    #[clap(long, default_value = "debug")]
+    profile: String,
+    #[clap(name = "jobs", short = "j")]
+    num_jobs: Option<usize>,
+}
+
+#[derive(Clap, Debug, PartialEq)]
+#[clap(name = "tool")]
+enum Tool {
+    Build(Build),
+    /// Removes the build artifacts
+    Clean {
+        /// Only remove the documentation
+        #[clap(long)]
+        doc: bool,
+    },
+    /// Prints the version
+    ShowVersion,
+}
+
+#[test]
+fn enum_variants_are_subcommands() {
+    assert_eq!(Tool::try_parse_from(vec!["tool", "build", "-j", "4"]).unwrap(),
+               Tool::Build(Build {
+                               profile: "debug".into(),
+                               num_jobs: Some(4),
+                           }));
+    assert_eq!(Tool::try_parse_from(vec!["tool", "clean", "--doc"]).unwrap(),
+               Tool::Clean { doc: true });
+    assert_eq!(Tool::try_parse_from(vec!["tool", "show-version"]).unwrap(),
+               Tool::ShowVersion);
+}
+
+#[test]
+fn enum_requires_a_subcommand() {
+    let err = Tool::try_parse_from(vec!["tool"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MissingArgumentOrSubcommand);
+}
+
+#[derive(Clap, Debug, PartialEq)]
+#[clap(name = "cargo")]
+struct Cargo {
+    #[clap(long)]
+    offline: bool,
+    #[clap(subcommand)]
+    cmd: Option<Tool>,
+}
+
+#[test]
+fn subcommand_fields() {
+    assert_eq!(Cargo::try_parse_from(vec!["cargo", "--offline", "clean"]).unwrap(),
+               Cargo {
+                   offline: true,
+                   cmd: Some(Tool::Clean { doc: false }),
+               });
+    assert_eq!(Cargo::try_parse_from(vec!["cargo"]).unwrap(),
+               Cargo {
+                   offline: false,
+                   cmd: None,
+               });
+}
+
+#[test]
+fn from_arg_matches_reports_missing_subcommand() {
+    let m = clap::App::new("other").get_matches_from(vec!["other"]);
+    let err = <Tool as clap::FromArgMatches>::from_arg_matches(&m).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MissingSubcommand);
+}
@@ -0,0 +1,321 @@
+// Std
+use std::ascii::AsciiExt;
+
+// Internal
+use app::parser::Parser;
+use args::{AnyArg, Arg, ArgGroup};
+use args::settings::ArgSettings;
+
+// The switches by which an argument is used on the command line
+struct Switches<'z> {
+    name: &'z str,
+    short: Option<char>,
+    long: Option<&'z str>,
+    aliases: Vec<&'z str>,
+    global: bool,
+}
+
+impl<'z> Switches<'z> {
+    fn describe(&self) -> String {
+        if self.global {
+            format!("the global argument '{}'", self.name)
+        } else {
+            format!("'{}'", self.name)
+        }
+    }
+}
+
+// Pushes every problem of the definition of the app of `p` and of its subcommands to `problems`,
+// where `path` is the path of the app (i.e. `subcommands.build.`) and `globals` are the global
+// arguments of the apps above it, which are propagated to it when it's parsed
+pub fn definition_problems<'a, 'b>(p: &Parser<'a, 'b>,
+                                   path: &str,
+                                   globals: &[&Arg<'a, 'b>],
+                                   problems: &mut Vec<(String, String)>) {
+    debugln!("checks::definition_problems: path={}", path);
+    let arg_path = |name: &str| format!("{}args.{}", path, name);
+
+    switch_problems(p, globals, &arg_path, problems);
+    relation_problems(p, path, globals, &arg_path, problems);
+    requirement_problems(p, &arg_path, problems);
+    positional_problems(p, &arg_path, problems);
+    default_problems(p, &arg_path, problems);
+    subcommand_problems(p, path, problems);
+
+    let mut sc_globals = globals.to_vec();
+    sc_globals.extend(&p.global_args);
+    for sc in &p.subcommands {
+        let sc_path = format!("{}subcommands.{}.", path, sc.p.meta.name);
+        definition_problems(&sc.p, &*sc_path, &*sc_globals, problems);
+    }
+}
+
+// Shorts, longs and aliases which are used by more than one argument, and names which are
+// already used by a global argument
+fn switch_problems<F>(p: &Parser,
+                      globals: &[&Arg],
+                      arg_path: &F,
+                      problems: &mut Vec<(String, String)>)
+    where F: Fn(&str) -> String
+{
+    fn aliases<'z>(a: &'z Option<Vec<(&'z str, bool)>>) -> Vec<&'z str> {
+        a.iter().flat_map(|v| v).map(|&(n, _)| n).collect()
+    }
+
+    let mut switches: Vec<Switches> = globals
+        .iter()
+        .map(|a| {
+                 Switches {
+                     name: a.b.name,
+                     short: a.s.short,
+                     long: a.s.long,
+                     aliases: aliases(&a.s.aliases),
+                     global: true,
+                 }
+             })
+        .collect();
+    let n_globals = switches.len();
+    let own = p.flags
+        .iter()
+        .map(|f| (&f.b, &f.s))
+        .chain(p.opts.iter().map(|o| (&o.b, &o.s)));
+    switches.extend(own.map(|(b, s)| {
+                                Switches {
+                                    name: b.name,
+                                    short: s.short,
+                                    long: s.long,
+                                    aliases: aliases(&s.aliases),
+                                    global: false,
+                                }
+                            }));
+    for name in p.positionals.values().map(|p| p.b.name) {
+        if let Some(g) = switches[..n_globals].iter().find(|g| g.name == name) {
+            problems.push((arg_path(name), format!("{} has the same name", g.describe())));
+        }
+    }
+
+    for (i, a) in switches.iter().enumerate().skip(n_globals) {
+        let before = &switches[..i];
+        if let Some(g) = before[..n_globals].iter().find(|g| g.name == a.name) {
+            problems.push((arg_path(a.name), format!("{} has the same name", g.describe())));
+        }
+        if let Some(s) = a.short {
+            if let Some(o) = before.iter().find(|o| o.short == Some(s)) {
+                problems.push((arg_path(a.name),
+                               format!("the short '-{}' is also used by {}", s, o.describe())));
+            }
+        }
+        if let Some(l) = a.long {
+            if let Some(o) = before.iter().find(|o| o.long == Some(l)) {
+                problems.push((arg_path(a.name),
+                               format!("the long '--{}' is also used by {}", l, o.describe())));
+            }
+        }
+        for &al in &a.aliases {
+            let others = switches.iter().enumerate().filter(|&(j, _)| j != i);
+            if let Some((_, o)) = others.clone().find(|&(_, o)| o.long == Some(al)) {
+                problems.push((arg_path(a.name),
+                               format!("the alias '--{}' is the long of {}", al, o.describe())));
+            } else if let Some((_, o)) =
+                others.take_while(|&(j, _)| j < i).find(|&(_, o)| o.aliases.contains(&al)) {
+                problems.push((arg_path(a.name),
+                               format!("the alias '--{}' is also an alias of {}",
+                                       al,
+                                       o.describe())));
+            }
+        }
+    }
+}
+
+// Relations to arguments or groups which don't exist
+fn relation_problems<F>(p: &Parser,
+                        path: &str,
+                        globals: &[&Arg],
+                        arg_path: &F,
+                        problems: &mut Vec<(String, String)>)
+    where F: Fn(&str) -> String
+{
+    let exists = |n: &str| {
+        arg_names!(p).any(|a| a == n) || p.groups.iter().any(|g| g.name == n) ||
+        globals.iter().any(|a| a.b.name == n)
+    };
+    for (name, relation, other) in p.relations() {
+        if !exists(other) {
+            let key = if p.groups.iter().any(|g| g.name == name) {
+                format!("{}groups.{}", path, name)
+            } else {
+                arg_path(name)
+            };
+            problems.push((key,
+                           format!("it {} '{}', which isn't an argument or group",
+                                   relation,
+                                   other)));
+        }
+    }
+}
+
+// Arguments which can never be used, because the arguments they require (directly, or through
+// other arguments) conflict with each other or with the argument itself, and required arguments
+// which conflict with other required arguments or with every argument of a required group
+fn requirement_problems<F>(p: &Parser, arg_path: &F, problems: &mut Vec<(String, String)>)
+    where F: Fn(&str) -> String
+{
+    let bases: Vec<_> = p.flags
+        .iter()
+        .map(|f| &f.b)
+        .chain(p.opts.iter().map(|o| &o.b))
+        .chain(p.positionals.values().map(|p| &p.b))
+        .collect();
+    let find = |n: &str| bases.iter().find(|b| b.name == n);
+    let in_groups = |n: &str| -> Vec<&ArgGroup> {
+        p.groups.iter().filter(|g| g.args.contains(&n)).collect()
+    };
+    let conflicts_with = |a: &str, other: &str| {
+        let names = in_groups(other).into_iter().map(|g| g.name).chain(Some(other));
+        let bl = find(a).and_then(|b| b.blacklist.as_ref());
+        names.clone().any(|n| bl.map_or(false, |bl| bl.contains(&n))) ||
+        in_groups(a)
+            .iter()
+            .any(|g| g.conflicts.as_ref().map_or(false, |c| names.clone().any(|n| c.contains(&n))))
+    };
+
+    for b in &bases {
+        let mut reached = vec![b.name];
+        let mut i = 0;
+        let mut found = None;
+        'reach: while i < reached.len() {
+            let reqs = find(reached[i]).and_then(|r| r.requires.as_ref());
+            for &(_, r) in reqs.iter().flat_map(|v| v.iter()).filter(|&&(v, _)| v.is_none()) {
+                if reached.contains(&r) || find(r).is_none() {
+                    continue;
+                }
+                if let Some(&c) = reached
+                       .iter()
+                       .find(|&&c| conflicts_with(c, r) || conflicts_with(r, c)) {
+                    found = Some((r, c));
+                    break 'reach;
+                }
+                reached.push(r);
+            }
+            i += 1;
+        }
+        if let Some((r, c)) = found {
+            let reason = if b.is_set(ArgSettings::Required) {
+                format!("it's required, but it requires '{}', which conflicts with '{}'", r, c)
+            } else {
+                format!("it can never be used, because it requires '{}', which conflicts with '{}'",
+                        r,
+                        c)
+            };
+            problems.push((arg_path(b.name), reason));
+        }
+    }
+
+    let required: Vec<_> = bases.iter().filter(|b| b.is_set(ArgSettings::Required)).collect();
+    for (i, a) in required.iter().enumerate() {
+        for b in &required[i + 1..] {
+            let (x, y) = if conflicts_with(a.name, b.name) {
+                (a.name, b.name)
+            } else if conflicts_with(b.name, a.name) {
+                (b.name, a.name)
+            } else {
+                continue;
+            };
+            problems.push((arg_path(x),
+                           format!("it's required, but it conflicts with '{}', which is required \
+                                    too",
+                                   y)));
+        }
+        for g in p.groups.iter().filter(|g| g.required && !g.args.is_empty()) {
+            if g.args.iter().all(|&m| conflicts_with(a.name, m) || conflicts_with(m, a.name)) {
+                problems.push((arg_path(a.name),
+                               format!("it's required, but it conflicts with every argument of \
+                                        the required group '{}'",
+                                       g.name)));
+            }
+        }
+    }
+}
+
+// Gaps between the indices of the positional arguments
+fn positional_problems<F>(p: &Parser, arg_path: &F, problems: &mut Vec<(String, String)>)
+    where F: Fn(&str) -> String
+{
+    let mut expected = 1;
+    for (idx, pos) in &p.positionals {
+        if idx != expected {
+            problems.push((arg_path(pos.b.name),
+                           format!("its index is {}, but there's no positional argument with \
+                                    index {}",
+                                   idx,
+                                   expected)));
+        }
+        expected = idx + 1;
+    }
+}
+
+// Default values which aren't one of the possible values of their argument
+fn default_problems<F>(p: &Parser, arg_path: &F, problems: &mut Vec<(String, String)>)
+    where F: Fn(&str) -> String
+{
+    fn check<'a, 'b, A, F>(a: &A, arg_path: &F, problems: &mut Vec<(String, String)>)
+        where A: AnyArg<'a, 'b>,
+              F: Fn(&str) -> String
+    {
+        let p_vals = match a.possible_vals() {
+            Some(p_vals) => p_vals,
+            None => return,
+        };
+        let defaults = a.default_val()
+            .into_iter()
+            .chain(a.default_vals_ifs().into_iter().flat_map(|it| it.map(|&(_, _, d)| d)));
+        for default in defaults {
+            let default = default.to_string_lossy();
+            let vals: Vec<&str> = match a.val_delim() {
+                Some(delim) if !default.is_empty() => default.split(delim).collect(),
+                _ => vec![&*default],
+            };
+            for val in vals {
+                let ok = if a.is_set(ArgSettings::CaseInsensitive) {
+                    p_vals.iter().any(|pv| pv.eq_ignore_ascii_case(val))
+                } else {
+                    p_vals.contains(&val)
+                };
+                if !ok {
+                    problems.push((arg_path(a.name()),
+                                   format!("its default value '{}' isn't one of its possible \
+                                            values",
+                                           val)));
+                }
+            }
+        }
+    }
+
+    for o in &p.opts {
+        check(o, arg_path, problems);
+    }
+    for pos in p.positionals.values() {
+        check(pos, arg_path, problems);
+    }
+}
+
+// Subcommand aliases which collide with the name or an alias of another subcommand
+fn subcommand_problems(p: &Parser, path: &str, problems: &mut Vec<(String, String)>) {
+    for (i, sc) in p.subcommands.iter().enumerate() {
+        for &(al, _) in sc.p.meta.aliases.iter().flat_map(|v| v) {
+            let others = p.subcommands.iter().enumerate().filter(|&(j, _)| j != i);
+            let reason = if let Some((_, o)) = others.clone().find(|&(_, o)| o.p.meta.name == al) {
+                format!("its alias '{}' is the name of the subcommand '{}'", al, o.p.meta.name)
+            } else if let Some((_, o)) = others.take_while(|&(j, _)| j < i).find(|&(_, o)| {
+                o.p.meta.aliases.iter().flat_map(|v| v).any(|&(n, _)| n == al)
+            }) {
+                format!("its alias '{}' is also an alias of the subcommand '{}'",
+                        al,
+                        o.p.meta.name)
+            } else {
+                continue;
+            };
+            problems.push((format!("{}subcommands.{}", path, sc.p.meta.name), reason));
+        }
+    }
+}
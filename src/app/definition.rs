@@ -0,0 +1,813 @@
+// Third Party
+#[cfg(feature = "yaml")]
+use yaml_rust::Yaml;
+#[cfg(feature = "json")]
+use serde_json;
+#[cfg(feature = "toml")]
+use toml;
+
+// Internal
+use app::{App, AppSettings};
+use app::positions::Positions;
+use args::{Arg, ArgGroup, ArgSettings, ValueHint};
+use errors::{Error, Result as ClapResult};
+
+// A document describing an App, which every supported format is converted into so that they all
+// share the same keys and the same validation. Strings are borrowed from the original document,
+// because the App borrows them in turn.
+pub enum Node<'a> {
+    // TOML doesn't have null
+    #[cfg_attr(not(any(feature = "yaml", feature = "json")), allow(dead_code))]
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float,
+    Str(&'a str),
+    Array(Vec<Node<'a>>),
+    Table(Vec<(&'a str, Node<'a>)>),
+    // A value no key accepts, such as a TOML date, along with the name of its type
+    #[cfg_attr(not(any(feature = "yaml", feature = "toml")), allow(dead_code))]
+    Other(&'static str),
+}
+
+impl<'a> Node<'a> {
+    fn type_name(&self) -> &'static str {
+        match *self {
+            Node::Null => "null",
+            Node::Bool(_) => "a boolean",
+            Node::Int(_) => "an integer",
+            Node::Float => "a float",
+            Node::Str(_) => "a string",
+            Node::Array(_) => "an array",
+            Node::Table(_) => "a table",
+            Node::Other(n) => n,
+        }
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl<'a> From<&'a Yaml> for Node<'a> {
+    fn from(y: &'a Yaml) -> Self {
+        match *y {
+            Yaml::Null => Node::Null,
+            Yaml::Boolean(b) => Node::Bool(b),
+            Yaml::Integer(i) => Node::Int(i),
+            Yaml::Real(_) => Node::Float,
+            Yaml::String(ref s) => Node::Str(s),
+            Yaml::Array(ref a) => Node::Array(a.iter().map(Node::from).collect()),
+            Yaml::Hash(ref h) => {
+                let mut table = vec![];
+                for (k, v) in h {
+                    match *k {
+                        Yaml::String(ref k) => table.push((&**k, Node::from(v))),
+                        _ => return Node::Other("a mapping with keys which aren't strings"),
+                    }
+                }
+                Node::Table(table)
+            }
+            Yaml::Alias(_) => Node::Other("an alias"),
+            Yaml::BadValue => Node::Other("an invalid value"),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a> From<&'a serde_json::Value> for Node<'a> {
+    fn from(v: &'a serde_json::Value) -> Self {
+        use serde_json::Value;
+        match *v {
+            Value::Null => Node::Null,
+            Value::Bool(b) => Node::Bool(b),
+            Value::Number(ref n) => n.as_i64().map_or(Node::Float, Node::Int),
+            Value::String(ref s) => Node::Str(s),
+            Value::Array(ref a) => Node::Array(a.iter().map(Node::from).collect()),
+            Value::Object(ref o) => {
+                Node::Table(o.iter().map(|(k, v)| (&**k, Node::from(v))).collect())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl<'a> From<&'a toml::Value> for Node<'a> {
+    fn from(v: &'a toml::Value) -> Self {
+        use toml::Value;
+        match *v {
+            Value::Boolean(b) => Node::Bool(b),
+            Value::Integer(i) => Node::Int(i),
+            Value::Float(_) => Node::Float,
+            Value::String(ref s) => Node::Str(s),
+            Value::Datetime(_) => Node::Other("a datetime"),
+            Value::Array(ref a) => Node::Array(a.iter().map(Node::from).collect()),
+            Value::Table(ref t) => {
+                Node::Table(t.iter().map(|(k, v)| (&**k, Node::from(v))).collect())
+            }
+        }
+    }
+}
+
+// The version of the schema of the documents written by `App::gen_json_to`, which is increased
+// whenever a key is removed or its meaning changes (adding keys doesn't change the version)
+pub const SCHEMA_VERSION: u64 = 1;
+
+// Builds an App from a Node, collecting every problem along with the path of the key which caused
+// it (i.e. `subcommands[2].args[0].possible_values`) instead of stopping at the first one
+pub struct Builder {
+    problems: Vec<(String, String)>,
+    // The paths of the keys whose positions are reported for each problem, which for an unknown
+    // key is the key itself rather than its table
+    located: Vec<String>,
+    // Whether the document was written by `App::gen_json_to` (it has a `schema_version` key), in
+    // which case the derived `usage` isn't used as a custom usage string
+    exported: bool,
+}
+
+impl Builder {
+    pub fn build<'a>(def: &Node<'a>, positions: Option<&Positions>) -> ClapResult<App<'a, 'a>> {
+        debugln!("Builder::build;");
+        let exported = match *def {
+            Node::Table(ref t) => t.iter().any(|&(k, _)| k == "schema_version"),
+            _ => false,
+        };
+        let mut b = Builder {
+            problems: vec![],
+            located: vec![],
+            exported: exported,
+        };
+        let app = b.app("", def, &[]);
+        match app {
+            Some(app) if b.problems.is_empty() => Ok(app),
+            _ => {
+                let pos = positions.map(|p| b.located.iter().map(|l| p.find(l)).collect());
+                Err(Error::invalid_definition(b.problems, pos))
+            }
+        }
+    }
+
+    // `globals` are the names of the global arguments of the apps above this one, which are
+    // skipped when they're listed again in its `args` (as `App::gen_json_to` does)
+    fn app<'a>(&mut self, path: &str, def: &Node<'a>, globals: &[&'a str]) -> Option<App<'a, 'a>> {
+        debugln!("Builder::app: path={}", path);
+        let (name, settings) = match self.named(path, def) {
+            Some(n) => n,
+            None => return None,
+        };
+        let mut a = App::new(name);
+        // The global args are needed to build the subcommands, which may come before the args
+        let mut sc_globals = globals.to_vec();
+        if let Some(&&(_, Node::Array(ref args))) = settings.iter().find(|&&&(k, _)| k == "args") {
+            // Their problems are reported when they're built below
+            let mut scratch = Builder {
+                problems: vec![],
+                located: vec![],
+                exported: self.exported,
+            };
+            sc_globals.extend(args.iter()
+                                  .filter(|def| !is_generated(def))
+                                  .filter_map(|def| scratch.arg("", def))
+                                  .filter(|arg| arg.is_set(ArgSettings::Global))
+                                  .map(|arg| arg.b.name));
+        }
+
+        for &&(key, ref v) in &settings {
+            if let Node::Null = *v {
+                continue;
+            }
+            let p = child(path, key);
+            macro_rules! set {
+                ($conv:ident, $f:ident) => {
+                    if let Some(val) = self.$conv(&*p, v) {
+                        a = a.$f(val);
+                    }
+                };
+            }
+            macro_rules! set_each {
+                ($conv:ident, $f:ident) => {
+                    for val in self.$conv(&*p, v) {
+                        a = a.$f(val);
+                    }
+                };
+            }
+            match key {
+                "schema_version" if path.is_empty() => {
+                    match self.u64(&*p, v) {
+                        Some(n) if n > SCHEMA_VERSION => {
+                            self.problem(&*p,
+                                         format!("unsupported schema version {}, the newest \
+                                                  supported version is {}",
+                                                 n,
+                                                 SCHEMA_VERSION))
+                        }
+                        _ => (),
+                    }
+                }
+                "version" => set!(string, version),
+                "long_version" => set!(string, long_version),
+                "author" => set!(string, author),
+                "bin_name" => set!(string, bin_name),
+                "about" => set!(string, about),
+                "long_about" => set!(string, long_about),
+                "before_help" => set!(string, before_help),
+                "after_help" => set!(string, after_help),
+                "template" => set!(string, template),
+                "arg_template" => set!(string, arg_template),
+                "usage" if self.exported => {
+                    self.string(&*p, v);
+                }
+                "usage" => set!(string, usage),
+                "help" => set!(string, help),
+                "help_short" => set!(string, help_short),
+                "version_short" => set!(string, version_short),
+                "help_message" => set!(string, help_message),
+                "version_message" => set!(string, version_message),
+                "alias" => set!(string, alias),
+                "visible_alias" => set!(string, visible_alias),
+                "display_order" => set!(usize, display_order),
+                "help_heading" => set!(string, help_heading),
+                "generated" if !path.is_empty() => {
+                    self.bool(&*p, v);
+                }
+                "setting" | "settings" => set_each!(app_settings, setting),
+                "global_setting" | "global_settings" => set_each!(app_settings, global_setting),
+                "aliases" => {
+                    for (alias, visible) in self.aliases(&*p, v) {
+                        a = if visible {
+                            a.visible_alias(alias)
+                        } else {
+                            a.alias(alias)
+                        };
+                    }
+                }
+                "visible_aliases" => set_each!(strings, visible_alias),
+                "args" => {
+                    for (i, def) in self.array(&*p, v).iter().enumerate() {
+                        let i_p = index(&*p, i);
+                        let arg = match self.arg(&*i_p, def) {
+                            Some(arg) => arg,
+                            None => continue,
+                        };
+                        if is_generated(def) {
+                            // The help and version flags are generated again, so only their
+                            // short and help message are used
+                            let short = arg.s.short.map(|c| c.to_string());
+                            match arg.b.name {
+                                "help" => {
+                                    if let Some(s) = short {
+                                        a = a.help_short(s);
+                                    }
+                                    if let Some(h) = arg.b.help {
+                                        a = a.help_message(h);
+                                    }
+                                }
+                                "version" => {
+                                    if let Some(s) = short {
+                                        a = a.version_short(s);
+                                    }
+                                    if let Some(h) = arg.b.help {
+                                        a = a.version_message(h);
+                                    }
+                                }
+                                n => {
+                                    self.problem(&*i_p,
+                                                 format!("'{}' isn't a generated argument", n))
+                                }
+                            }
+                        } else if arg.is_set(ArgSettings::Global) && globals.contains(&arg.b.name) {
+                            debugln!("Builder::app: skipping the propagated global arg {}",
+                                     arg.b.name);
+                        } else {
+                            a = a.arg(arg);
+                        }
+                    }
+                }
+                "subcommands" => {
+                    for (i, sc) in self.array(&*p, v).iter().enumerate() {
+                        // The help subcommand is generated again
+                        if is_generated(sc) {
+                            continue;
+                        }
+                        if let Some(sc) = self.app(&*index(&*p, i), sc, &*sc_globals) {
+                            a = a.subcommand(sc);
+                        }
+                    }
+                }
+                "groups" => {
+                    for (i, group) in self.array(&*p, v).iter().enumerate() {
+                        if let Some(group) = self.group(&*index(&*p, i), group) {
+                            a = a.group(group);
+                        }
+                    }
+                }
+                k => self.unknown_key(path, k),
+            }
+        }
+
+        Some(a)
+    }
+
+    fn arg<'a>(&mut self, path: &str, def: &Node<'a>) -> Option<Arg<'a, 'a>> {
+        debugln!("Builder::arg: path={}", path);
+        let (name, settings) = match self.named(path, def) {
+            Some(n) => n,
+            None => return None,
+        };
+        let mut a = Arg::with_name(name);
+
+        for &&(key, ref v) in &settings {
+            if let Node::Null = *v {
+                continue;
+            }
+            let p = child(path, key);
+            macro_rules! set {
+                ($conv:ident, $f:ident) => {
+                    if let Some(val) = self.$conv(&*p, v) {
+                        a = a.$f(val);
+                    }
+                };
+            }
+            macro_rules! set_each {
+                ($conv:ident, $f:ident) => {
+                    for val in self.$conv(&*p, v) {
+                        a = a.$f(val);
+                    }
+                };
+            }
+            match key {
+                "kind" => {
+                    match self.string(&*p, v) {
+                        Some("flag") | Some("positional") | None => (),
+                        Some("option") => a = a.takes_value(true),
+                        Some(k) => self.problem(&*p, format!("unknown kind of argument '{}'", k)),
+                    }
+                }
+                "generated" => {
+                    self.bool(&*p, v);
+                }
+                "short" => set!(string, short),
+                "long" => set!(string, long),
+                "aliases" => {
+                    for (alias, visible) in self.aliases(&*p, v) {
+                        a = if visible {
+                            a.visible_alias(alias)
+                        } else {
+                            a.alias(alias)
+                        };
+                    }
+                }
+                "help" => set!(string, help),
+                "long_help" => set!(string, long_help),
+                "required" => set!(bool, required),
+                "required_if" | "required_ifs" => {
+                    for t in self.tuples(&*p, v, &["arg", "value"], None) {
+                        if let (Some(arg), Some(val)) = (t[0], t[1]) {
+                            a = a.required_if(arg, val);
+                        }
+                    }
+                }
+                "takes_value" => set!(bool, takes_value),
+                "index" => set!(u64, index),
+                "global" => set!(bool, global),
+                "multiple" => set!(bool, multiple),
+                "hidden" => set!(bool, hidden),
+                "next_line_help" => set!(bool, next_line_help),
+                "empty_values" => set!(bool, empty_values),
+                "group" => set!(string, group),
+                "number_of_values" => set!(u64, number_of_values),
+                "max_values" => set!(u64, max_values),
+                "min_values" => set!(u64, min_values),
+                "value_name" => set!(string, value_name),
+                "use_delimiter" => set!(bool, use_delimiter),
+                "allow_hyphen_values" => set!(bool, allow_hyphen_values),
+                "require_delimiter" => set!(bool, require_delimiter),
+                "value_delimiter" => set!(string, value_delimiter),
+                "value_terminator" => set!(string, value_terminator),
+                "required_unless" => set_each!(strings, required_unless),
+                "display_order" => set!(usize, display_order),
+                "help_heading" => set!(string, help_heading),
+                "default_value" => set!(string, default_value),
+                "default_value_if" | "default_value_ifs" => {
+                    for t in self.tuples(&*p, v, &["arg", "value", "default"], Some(1)) {
+                        if let (Some(arg), Some(default)) = (t[0], t[2]) {
+                            a = a.default_value_if(arg, t[1], default);
+                        }
+                    }
+                }
+                "env" => set!(string, env),
+                "hide_env_values" => set!(bool, hide_env_values),
+                // `Unknown` is the default, setting it would make a flag take a value
+                "value_hint" => {
+                    match self.value_hint(&*p, v) {
+                        Some(ValueHint::Unknown) | None => (),
+                        Some(hint) => a = a.value_hint(hint),
+                    }
+                }
+                "value_names" => set_each!(strings, value_name),
+                "groups" => set_each!(strings, group),
+                "requires" => {
+                    for (val, arg) in self.requirements(&*p, v) {
+                        a = match val {
+                            Some(val) => a.requires_if(val, arg),
+                            None => a.requires(arg),
+                        };
+                    }
+                }
+                "requires_if" | "requires_ifs" => {
+                    for t in self.tuples(&*p, v, &["value", "arg"], None) {
+                        if let (Some(val), Some(arg)) = (t[0], t[1]) {
+                            a = a.requires_if(val, arg);
+                        }
+                    }
+                }
+                "conflicts_with" => set_each!(strings, conflicts_with),
+                "overrides_with" => set_each!(strings, overrides_with),
+                "possible_values" => set_each!(strings, possible_value),
+                "case_insensitive" => set!(bool, case_insensitive),
+                "setting" | "settings" => set_each!(arg_settings, set),
+                "required_unless_one" => set_each!(strings, required_unless),
+                "required_unless_all" => {
+                    set_each!(strings, required_unless);
+                    a.setb(ArgSettings::RequiredUnlessAll);
+                }
+                k => self.unknown_key(path, k),
+            }
+        }
+
+        Some(a)
+    }
+
+    fn group<'a>(&mut self, path: &str, def: &Node<'a>) -> Option<ArgGroup<'a>> {
+        debugln!("Builder::group: path={}", path);
+        let (name, settings) = match self.named(path, def) {
+            Some(n) => n,
+            None => return None,
+        };
+        let mut g = ArgGroup::with_name(name);
+
+        for &&(key, ref v) in &settings {
+            if let Node::Null = *v {
+                continue;
+            }
+            let p = child(path, key);
+            match key {
+                "required" => {
+                    if let Some(b) = self.bool(&*p, v) {
+                        g = g.required(b);
+                    }
+                }
+                "multiple" => {
+                    if let Some(b) = self.bool(&*p, v) {
+                        g = g.multiple(b);
+                    }
+                }
+                "min_args" => {
+                    if let Some(n) = self.usize(&*p, v) {
+                        g = g.min_args(n);
+                    }
+                }
+                "max_args" => {
+                    if let Some(n) = self.usize(&*p, v) {
+                        g = g.max_args(n);
+                    }
+                }
+                "number_of_args" => {
+                    if let Some(n) = self.usize(&*p, v) {
+                        g = g.number_of_args(n);
+                    }
+                }
+                "arg" => {
+                    if let Some(s) = self.string(&*p, v) {
+                        g = g.arg(s);
+                    }
+                }
+                "args" => {
+                    for s in self.strings(&*p, v) {
+                        g = g.arg(s);
+                    }
+                }
+                "requires" => {
+                    for s in self.strings(&*p, v) {
+                        g = g.requires(s);
+                    }
+                }
+                "conflicts_with" => {
+                    for s in self.strings(&*p, v) {
+                        g = g.conflicts_with(s);
+                    }
+                }
+                k => self.unknown_key(path, k),
+            }
+        }
+
+        Some(g)
+    }
+
+    // Splits a definition into its name and its settings. The settings are either nested under
+    // the name (i.e. `{"verbose": {"short": "v"}}`), or next to a `name` key (i.e.
+    // `{"name": "verbose", "short": "v"}`).
+    fn named<'a, 'n>(&mut self,
+                     path: &str,
+                     def: &'n Node<'a>)
+                     -> Option<(&'a str, Vec<&'n (&'a str, Node<'a>)>)> {
+        let table = match *def {
+            Node::Table(ref t) => t,
+            _ => {
+                self.expected(path, "a table", def);
+                return None;
+            }
+        };
+        if let Some(&(_, Node::Str(name))) = table.iter().find(|&&(k, _)| k == "name") {
+            return Some((name, table.iter().filter(|&&(k, _)| k != "name").collect()));
+        }
+        if table.len() == 1 {
+            let (name, ref settings) = table[0];
+            return match *settings {
+                Node::Table(ref t) => Some((name, t.iter().collect())),
+                Node::Null => Some((name, vec![])),
+                _ => {
+                    self.expected(&*child(path, name), "a table", settings);
+                    None
+                }
+            };
+        }
+        match table.iter().find(|&&(k, _)| k == "name") {
+            Some(&(_, ref n)) => self.expected(&*child(path, "name"), "a string", n),
+            None => {
+                self.problem(path,
+                             "expected a 'name' key, or a table with the name as its only key")
+            }
+        }
+        None
+    }
+
+    fn string<'a>(&mut self, path: &str, v: &Node<'a>) -> Option<&'a str> {
+        match *v {
+            Node::Str(s) => Some(s),
+            _ => {
+                self.expected(path, "a string", v);
+                None
+            }
+        }
+    }
+
+    fn bool(&mut self, path: &str, v: &Node) -> Option<bool> {
+        match *v {
+            Node::Bool(b) => Some(b),
+            _ => {
+                self.expected(path, "a boolean", v);
+                None
+            }
+        }
+    }
+
+    fn u64(&mut self, path: &str, v: &Node) -> Option<u64> {
+        match *v {
+            Node::Int(i) if i >= 0 => Some(i as u64),
+            _ => {
+                self.expected(path, "a non-negative integer", v);
+                None
+            }
+        }
+    }
+
+    fn usize(&mut self, path: &str, v: &Node) -> Option<usize> {
+        self.u64(path, v).map(|i| i as usize)
+    }
+
+    fn value_hint(&mut self, path: &str, v: &Node) -> Option<ValueHint> {
+        match self.string(path, v).map(|s| (s, s.parse())) {
+            Some((_, Ok(hint))) => Some(hint),
+            Some((s, Err(_))) => {
+                self.problem(path, format!("unknown value hint '{}'", s));
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn array<'n, 'a>(&mut self, path: &str, v: &'n Node<'a>) -> &'n [Node<'a>] {
+        match *v {
+            Node::Array(ref a) => a,
+            _ => {
+                self.expected(path, "an array", v);
+                &[]
+            }
+        }
+    }
+
+    // Either a single string or an array of strings
+    fn strings<'a>(&mut self, path: &str, v: &Node<'a>) -> Vec<&'a str> {
+        match *v {
+            Node::Str(s) => vec![s],
+            Node::Array(ref a) => {
+                a.iter()
+                    .enumerate()
+                    .filter_map(|(i, v)| self.string(&*index(path, i), v))
+                    .collect()
+            }
+            _ => {
+                self.expected(path, "a string or an array of strings", v);
+                vec![]
+            }
+        }
+    }
+
+    fn app_settings(&mut self, path: &str, v: &Node) -> Vec<AppSettings> {
+        let mut settings = vec![];
+        let names = match *v {
+            Node::Array(ref a) => a.iter().enumerate().map(|(i, n)| (index(path, i), n)).collect(),
+            _ => vec![(path.to_owned(), v)],
+        };
+        for (p, n) in names {
+            if let Some(s) = self.string(&*p, n) {
+                match s.parse() {
+                    Ok(setting) => settings.push(setting),
+                    Err(_) => self.problem(&*p, format!("unknown setting '{}'", s)),
+                }
+            }
+        }
+        settings
+    }
+
+    fn arg_settings(&mut self, path: &str, v: &Node) -> Vec<ArgSettings> {
+        let mut settings = vec![];
+        for (i, n) in self.array(path, v).iter().enumerate() {
+            let p = index(path, i);
+            if let Some(s) = self.string(&*p, n) {
+                match s.parse() {
+                    Ok(setting) => settings.push(setting),
+                    Err(_) => self.problem(&*p, format!("unknown setting '{}'", s)),
+                }
+            }
+        }
+        settings
+    }
+
+    // Either a single name or an array of names or `{"name": string, "visible": bool}` tables,
+    // along with whether the alias is visible
+    fn aliases<'a>(&mut self, path: &str, v: &Node<'a>) -> Vec<(&'a str, bool)> {
+        let elems = match *v {
+            Node::Str(s) => return vec![(s, false)],
+            Node::Array(ref a) => a,
+            _ => {
+                self.expected(path, "a string or an array", v);
+                return vec![];
+            }
+        };
+        let mut aliases = vec![];
+        for (i, e) in elems.iter().enumerate() {
+            let p = index(path, i);
+            match *e {
+                Node::Str(s) => aliases.push((s, false)),
+                Node::Table(ref t) => {
+                    let mut alias = (None, false);
+                    for &(key, ref v) in t {
+                        let k_p = child(&*p, key);
+                        match key {
+                            "name" => alias.0 = self.string(&*k_p, v),
+                            "visible" => alias.1 = self.bool(&*k_p, v).unwrap_or(false),
+                            k => self.unknown_key(&*p, k),
+                        }
+                    }
+                    match alias {
+                        (Some(name), visible) => aliases.push((name, visible)),
+                        (None, _) => self.problem(&*p, "expected a 'name' key"),
+                    }
+                }
+                _ => self.expected(&*p, "a string or a table", e),
+            }
+        }
+        aliases
+    }
+
+    // Either a single name or an array of names or `{"arg": string, "value": string or null}`
+    // tables, as (value, arg) where `value` is the value which makes `arg` required
+    fn requirements<'a>(&mut self, path: &str, v: &Node<'a>) -> Vec<(Option<&'a str>, &'a str)> {
+        let elems = match *v {
+            Node::Str(s) => return vec![(None, s)],
+            Node::Array(ref a) => a,
+            _ => {
+                self.expected(path, "a string or an array", v);
+                return vec![];
+            }
+        };
+        let mut reqs = vec![];
+        for (i, e) in elems.iter().enumerate() {
+            let p = index(path, i);
+            match *e {
+                Node::Str(s) => reqs.push((None, s)),
+                Node::Table(ref t) => {
+                    let r = self.table_tuple(&*p, t, &["value", "arg"], Some(0));
+                    if let (val, Some(arg)) = (r[0], r[1]) {
+                        reqs.push((val, arg));
+                    }
+                }
+                _ => self.expected(&*p, "a string or a table", e),
+            }
+        }
+        reqs
+    }
+
+    // An array of tuples of strings, which are either arrays with one string for each of `keys`,
+    // or tables with those keys. The string at index `nullable` may also be null (i.e. the value
+    // in `default_value_if`).
+    fn tuples<'a>(&mut self,
+                  path: &str,
+                  v: &Node<'a>,
+                  keys: &[&str],
+                  nullable: Option<usize>)
+                  -> Vec<Vec<Option<&'a str>>> {
+        let mut tuples = vec![];
+        for (i, t) in self.array(path, v).iter().enumerate() {
+            let p = index(path, i);
+            match *t {
+                Node::Array(ref t) if t.len() == keys.len() => {
+                    let mut tuple = vec![];
+                    for (j, s) in t.iter().enumerate() {
+                        tuple.push(match *s {
+                                       Node::Null if nullable == Some(j) => None,
+                                       _ => self.string(&*index(&*p, j), s),
+                                   });
+                    }
+                    tuples.push(tuple);
+                }
+                Node::Table(ref t) => tuples.push(self.table_tuple(&*p, t, keys, nullable)),
+                _ => {
+                    self.expected(&*p, &*format!("an array of {} strings", keys.len()), t);
+                }
+            }
+        }
+        tuples
+    }
+
+    // The strings of `keys` in a table, where the key at index `nullable` may also be null or
+    // missing
+    fn table_tuple<'a>(&mut self,
+                       path: &str,
+                       t: &[(&'a str, Node<'a>)],
+                       keys: &[&str],
+                       nullable: Option<usize>)
+                       -> Vec<Option<&'a str>> {
+        for &(key, _) in t {
+            if !keys.contains(&key) {
+                self.unknown_key(path, key);
+            }
+        }
+        let mut tuple = vec![];
+        for (j, &key) in keys.iter().enumerate() {
+            tuple.push(match t.iter().find(|&&(k, _)| k == key) {
+                           Some(&(_, Node::Null)) | None if nullable == Some(j) => None,
+                           Some(&(_, ref s)) => self.string(&*child(path, key), s),
+                           None => {
+                               self.problem(path, format!("expected a '{}' key", key));
+                               None
+                           }
+                       });
+        }
+        tuple
+    }
+
+    fn unknown_key(&mut self, path: &str, key: &str) {
+        self.problem_at(path, &*child(path, key), format!("unknown key '{}'", key));
+    }
+
+    fn expected(&mut self, path: &str, expected: &str, found: &Node) {
+        self.problem(path,
+                     format!("expected {}, found {}", expected, found.type_name()));
+    }
+
+    fn problem<S: Into<String>>(&mut self, path: &str, reason: S) {
+        self.problem_at(path, path, reason);
+    }
+
+    // A problem at `path` whose position is the one of the key at `at`
+    fn problem_at<S: Into<String>>(&mut self, path: &str, at: &str, reason: S) {
+        let reason = reason.into();
+        debugln!("Builder::problem_at: path={}, at={}, reason={}", path, at, reason);
+        self.problems.push((path.to_owned(), reason));
+        self.located.push(at.to_owned());
+    }
+}
+
+fn child(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn index(path: &str, i: usize) -> String { format!("{}[{}]", path, i) }
+
+// Whether a definition is of an argument or subcommand which clap generates itself, which
+// `App::gen_json_to` marks with `"generated": true`
+fn is_generated(def: &Node) -> bool {
+    match *def {
+        Node::Table(ref t) => {
+            t.iter().any(|&(k, ref v)| match (k, v) {
+                             ("generated", &Node::Bool(true)) => true,
+                             _ => false,
+                         })
+        }
+        _ => false,
+    }
+}
@@ -1,14 +1,19 @@
 // Std
+use std::borrow::Cow;
 use std::cmp;
 use std::collections::BTreeMap;
 use std::fmt::Display;
-use std::io::{self, Cursor, Read, Write};
+use std::io::{self, Write};
+use std::iter;
+use std::sync::Arc;
 use std::usize;
 
 // Internal
 use app::{App, AppSettings};
 use app::parser::Parser;
+use app::template::{self, Node};
 use args::{AnyArg, ArgSettings, DispOrder};
+use catalog::{self, MessageCatalog};
 use errors::{Error, Result as ClapResult};
 use fmt::{Format, Colorizer, ColorizerOption};
 use app::usage;
@@ -76,8 +81,14 @@ pub struct Help<'a> {
     color: bool,
     cizer: Colorizer,
     longest: usize,
+    // Whether positional arguments are padded like the arguments with switches, so that their
+    // help is aligned in sections which mix them (see `Arg::help_heading`)
+    pad_positionals: bool,
     force_next_line: bool,
     use_long: bool,
+    catalog: Option<Arc<MessageCatalog>>,
+    arg_template: Option<String>,
+    row_widths: BTreeMap<String, usize>,
 }
 
 // Public Functions
@@ -91,7 +102,8 @@ impl<'a> Help<'a> {
                cizer: Colorizer,
                term_w: Option<usize>,
                max_w: Option<usize>,
-               use_long: bool)
+               use_long: bool,
+               catalog: Option<Arc<MessageCatalog>>)
                -> Self {
         debugln!("Help::new;");
         Help {
@@ -111,8 +123,12 @@ impl<'a> Help<'a> {
             color: color,
             cizer: cizer,
             longest: 0,
+            pad_positionals: false,
             force_next_line: false,
             use_long: use_long,
+            catalog: catalog,
+            arg_template: None,
+            row_widths: BTreeMap::new(),
         }
     }
 
@@ -155,13 +171,15 @@ impl<'a> Help<'a> {
                   cizer,
                   parser.meta.term_w,
                   parser.meta.max_w,
-                  use_long)
+                  use_long,
+                  parser.catalog.clone())
                 .write_help(parser)
     }
 
     /// Writes the parser help to the wrapped stream.
     pub fn write_help(&mut self, parser: &Parser) -> ClapResult<()> {
         debugln!("Help::write_help;");
+        self.arg_template = parser.meta.arg_template.map(String::from);
         if let Some(h) = parser.meta.help_str {
             try!(write!(self.writer, "{}", h).map_err(Error::from));
         } else if let Some(tmpl) = parser.meta.template {
@@ -192,6 +210,9 @@ impl<'a> Help<'a> {
             }
             arg_v.push(arg)
         }
+        self.pad_positionals = arg_v.iter().any(|a| a.has_switch()) &&
+                               arg_v.iter().any(|a| !a.has_switch());
+        self.measure_rows(&arg_v.iter().map(|a| a.as_base()).collect::<Vec<_>>());
         let mut first = true;
         for arg in arg_v {
             if first {
@@ -227,6 +248,16 @@ impl<'a> Help<'a> {
             let btm = ord_m.entry(arg.disp_ord()).or_insert(BTreeMap::new());
             btm.insert(arg.name(), arg);
         }
+        {
+            let mut args = ord_m.values().flat_map(|btm| btm.values());
+            self.pad_positionals = args.clone().any(|a| a.has_switch()) &&
+                                   args.any(|a| !a.has_switch());
+        }
+        self.measure_rows(&ord_m
+                               .values()
+                               .flat_map(|btm| btm.values())
+                               .map(|a| a.as_base())
+                               .collect::<Vec<_>>());
         let mut first = true;
         for btm in ord_m.values() {
             for arg in btm.values() {
@@ -244,6 +275,9 @@ impl<'a> Help<'a> {
     /// Writes help for an argument to the wrapped stream.
     fn write_arg<'b, 'c>(&mut self, arg: &ArgWithDisplay<'b, 'c>) -> io::Result<()> {
         debugln!("Help::write_arg;");
+        if let Some(tmpl) = self.arg_template.clone() {
+            return self.write_arg_row(arg, &tmpl);
+        }
         try!(self.short(arg));
         try!(self.long(arg));
         let spec_vals = try!(self.val(arg));
@@ -330,7 +364,7 @@ impl<'a> Help<'a> {
             }
         }
 
-        let spec_vals = self.spec_vals(arg);
+        let spec_vals = self.spec_vals(arg, self.color);
         let h = arg.help().unwrap_or("");
         let h_w = str_width(h) + str_width(&*spec_vals);
         let nlh = self.next_line_help || arg.is_set(ArgSettings::NextLineHelp);
@@ -372,7 +406,11 @@ impl<'a> Help<'a> {
             }
         } else if !(nlh || self.force_next_line) {
             sdebugln!("No, and not next_line");
-            write_nspaces!(self.writer, self.longest + 4 - (str_width(arg.to_string().as_str())));
+            // In sections which mix them, the help starts where the help of the args with
+            // switches does
+            let spcs = if self.pad_positionals { 8 } else { 4 };
+            write_nspaces!(self.writer,
+                           self.longest + spcs - (str_width(arg.to_string().as_str())));
         } else {
             sdebugln!("No");
         }
@@ -448,7 +486,7 @@ impl<'a> Help<'a> {
             try!(write!(self.writer, "\n"));
             if nlh || self.force_next_line {
                 try!(write!(self.writer, "{}{}{}", TAB, TAB, TAB));
-            } else if arg.has_switch() {
+            } else if arg.has_switch() || self.pad_positionals {
                 write_nspaces!(self.writer, self.longest + 12);
             } else {
                 write_nspaces!(self.writer, self.longest + 8);
@@ -461,14 +499,164 @@ impl<'a> Help<'a> {
         Ok(())
     }
 
-    fn spec_vals(&self, a: &ArgWithDisplay) -> String {
+    /// Writes help for an argument to the wrapped stream in the format defined by the
+    /// template given to `App::arg_template`.
+    fn write_arg_row<'b, 'c>(&mut self,
+                             arg: &ArgWithDisplay<'b, 'c>,
+                             template: &str)
+                             -> io::Result<()> {
+        debugln!("Help::write_arg_row: arg={}", arg);
+        let mut row = String::new();
+        let mut col = 0;
+        self.render_row(arg, &template::parse(template), &mut row, &mut col);
+        // Padding the last column would only leave trailing spaces
+        let len = row.trim_right_matches(' ').len();
+        self.writer.write_all(row[..len].as_bytes())
+    }
+
+    fn render_row<'b, 'c>(&self,
+                          arg: &ArgWithDisplay<'b, 'c>,
+                          nodes: &[Node],
+                          row: &mut String,
+                          col: &mut usize) {
+        for node in nodes {
+            match *node {
+                Node::Text(text) => {
+                    row.push_str(text);
+                    *col = advance(*col, text);
+                }
+                Node::If(ref cond) => {
+                    let holds = self.row_value(arg, cond.name).map_or(false, |v| !v.is_empty());
+                    self.render_row(arg, cond.branch(holds), row, col);
+                }
+                Node::Tag(tag) => {
+                    let (name, width) = template::split_tag(tag);
+                    let value = match self.row_value(arg, name) {
+                        Some(value) => value,
+                        None => {
+                            // Unknown tag, write it back.
+                            row.push_str(&format!("{{{}}}", tag));
+                            *col += str_width(tag) + 2;
+                            continue;
+                        }
+                    };
+                    let width = match width {
+                        Some("auto") => self.row_widths.get(name).cloned().unwrap_or(0),
+                        Some(w) => w.parse().unwrap_or(0),
+                        None => 0,
+                    };
+                    let value = if name == "help" {
+                        self.wrap_row_help(&value, *col)
+                    } else {
+                        value
+                    };
+                    match name {
+                        "short" | "long" | "value" if self.color => {
+                            row.push_str(&format!("{}", self.cizer.good(&*value)))
+                        }
+                        _ => row.push_str(&value),
+                    }
+                    *col = advance(*col, &value);
+                    let last_w = str_width(value.lines().last().unwrap_or(""));
+                    for _ in last_w..width {
+                        row.push(' ');
+                        *col += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Wraps the help of an argument starting at the given column of a row, indenting the lines
+    /// following the first one up to that column.
+    fn wrap_row_help(&self, help: &str, col: usize) -> String {
+        let help = help.replace("{n}", "\n");
+        let avail = self.term_w.saturating_sub(col);
+        let help = if str_width(&help) > avail && avail > 0 {
+            wrap_help(&help, avail)
+        } else {
+            help
+        };
+        let indent = format!("\n{}", iter::repeat(' ').take(col).collect::<String>());
+        help.lines().collect::<Vec<_>>().join(&indent)
+    }
+
+    /// Calculates the widths used by the `{<tag>:auto}` tags of `App::arg_template` for the
+    /// given block of arguments.
+    fn measure_rows<'b, 'c>(&mut self, args: &[&ArgWithDisplay<'b, 'c>]) {
+        debugln!("Help::measure_rows;");
+        self.row_widths.clear();
+        let tmpl = match self.arg_template.clone() {
+            Some(tmpl) => tmpl,
+            None => return,
+        };
+        let mut names = vec![];
+        auto_width_tags(&template::parse(&tmpl), &mut names);
+        for name in names {
+            let width = args.iter()
+                .filter_map(|a| self.row_value(*a, name))
+                .map(|v| str_width(&v))
+                .max()
+                .unwrap_or(0);
+            self.row_widths.insert(name.to_owned(), width);
+        }
+    }
+
+    /// The value of a tag of `App::arg_template` for an argument, or `None` for unknown tags.
+    fn row_value<'b, 'c>(&self, arg: &ArgWithDisplay<'b, 'c>, tag: &str) -> Option<String> {
+        let value = match tag {
+            "name" => {
+                // The generated help and version flags use internal names to sort them last
+                match arg.name() {
+                        "hclap_help" => "help",
+                        "vclap_version" => "version",
+                        n => n,
+                    }
+                    .to_owned()
+            }
+            "short" => arg.short().map_or(String::new(), |s| format!("-{}", s)),
+            "long" => arg.long().map_or(String::new(), |l| format!("--{}", l)),
+            "value" => val_str(arg),
+            "help" => {
+                if self.use_long {
+                    arg.long_help().unwrap_or_else(|| arg.help().unwrap_or(""))
+                } else {
+                    arg.help().unwrap_or_else(|| arg.long_help().unwrap_or(""))
+                }
+                .to_owned()
+            }
+            "spec" => self.spec_vals(arg, false).trim().to_owned(),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn catalog(&self) -> &MessageCatalog {
+        self.catalog.as_ref().map_or(catalog::default_catalog(), |c| &**c)
+    }
+
+    fn spec_vals(&self, a: &ArgWithDisplay, color: bool) -> String {
         debugln!("Help::spec_vals: a={}", a);
         let mut spec_vals = vec![];
+        if let Some((env, val)) = a.env() {
+            debugln!("Help::spec_vals: Found environment variable...[{:?}:{:?}]", env, val);
+            let env_val = if !a.is_set(ArgSettings::HideEnvValues) {
+                format!("={}",
+                        val.map_or(Cow::Borrowed(""), |val| val.to_string_lossy()))
+            } else {
+                String::new()
+            };
+            spec_vals.push(format!(" [{}: {}{}]",
+                                   self.catalog().env_label(),
+                                   env.to_string_lossy(),
+                                   env_val));
+        }
         if !a.is_set(ArgSettings::HideDefaultValue) {
             if let Some(pv) = a.default_val() {
                 debugln!("Help::spec_vals: Found default value...[{:?}]", pv);
-                spec_vals.push(format!(" [default: {}]",
-                                       if self.color {
+                spec_vals.push(format!(" [{}: {}]",
+                                       self.catalog().default_label(),
+                                       if color {
                                            self.cizer.good(pv.to_string_lossy())
                                        } else {
                                            Format::None(pv.to_string_lossy())
@@ -477,8 +665,9 @@ impl<'a> Help<'a> {
         }
         if let Some(ref aliases) = a.aliases() {
             debugln!("Help::spec_vals: Found aliases...{:?}", aliases);
-            spec_vals.push(format!(" [aliases: {}]",
-                                   if self.color {
+            spec_vals.push(format!(" [{}: {}]",
+                                   self.catalog().aliases_label(),
+                                   if color {
                                        aliases
                                            .iter()
                                            .map(|v| format!("{}", self.cizer.good(v)))
@@ -491,14 +680,17 @@ impl<'a> Help<'a> {
         if !self.hide_pv && !a.is_set(ArgSettings::HidePossibleValues) {
             if let Some(pv) = a.possible_vals() {
                 debugln!("Help::spec_vals: Found possible vals...{:?}", pv);
-                spec_vals.push(if self.color {
-                                   format!(" [values: {}]",
+                spec_vals.push(if color {
+                                   format!(" [{}: {}]",
+                                           self.catalog().values_label(),
                                            pv.iter()
                                                .map(|v| format!("{}", self.cizer.good(v)))
                                                .collect::<Vec<_>>()
                                                .join(", "))
                                } else {
-                                   format!(" [values: {}]", pv.join(", "))
+                                   format!(" [{}: {}]",
+                                           self.catalog().values_label(),
+                                           pv.join(", "))
                                });
             }
         }
@@ -515,38 +707,41 @@ impl<'a> Help<'a> {
     #[cfg_attr(feature = "cargo-clippy", allow(useless_let_if_seq))]
     pub fn write_all_args(&mut self, parser: &Parser) -> ClapResult<()> {
         debugln!("Help::write_all_args;");
-        let flags = parser.has_flags();
+        // Args with a heading are displayed in their own sections instead
+        let flags = parser.flags().any(|f| f.b.help_heading.is_none());
         let pos = parser
             .positionals()
-            .filter(|arg| !arg.is_set(ArgSettings::Hidden))
+            .filter(|arg| !arg.is_set(ArgSettings::Hidden) && arg.b.help_heading.is_none())
             .count() > 0;
-        let opts = parser.has_opts();
-        let subcmds = parser.has_subcommands();
+        let opts = parser.opts().any(|o| o.b.help_heading.is_none());
+        let subcmds = parser.subcommands.iter().any(|s| s.p.meta.help_heading.is_none());
 
         let unified_help = parser.is_set(AppSettings::UnifiedHelpMessage);
 
         let mut first = true;
 
         if unified_help && (flags || opts) {
-            let opts_flags = parser
-                .flags()
-                .map(as_arg_trait)
-                .chain(parser.opts().map(as_arg_trait));
-            try!(color!(self, "OPTIONS:\n", warning));
-            try!(self.write_args(opts_flags));
+            try!(color!(self, "{}\n", parser.catalog().options_heading(), warning));
+            try!(self.write_args(unified_args(parser).into_iter()));
             first = false;
         } else {
             if flags {
-                try!(color!(self, "FLAGS:\n", warning));
-                try!(self.write_args(parser.flags().map(as_arg_trait)));
+                try!(color!(self, "{}\n", parser.catalog().flags_heading(), warning));
+                try!(self.write_args(parser
+                                         .flags()
+                                         .filter(|f| f.b.help_heading.is_none())
+                                         .map(as_arg_trait)));
                 first = false;
             }
             if opts {
                 if !first {
                     try!(self.writer.write_all(b"\n\n"));
                 }
-                try!(color!(self, "OPTIONS:\n", warning));
-                try!(self.write_args(parser.opts().map(as_arg_trait)));
+                try!(color!(self, "{}\n", parser.catalog().options_heading(), warning));
+                try!(self.write_args(parser
+                                         .opts()
+                                         .filter(|o| o.b.help_heading.is_none())
+                                         .map(as_arg_trait)));
                 first = false;
             }
         }
@@ -555,8 +750,24 @@ impl<'a> Help<'a> {
             if !first {
                 try!(self.writer.write_all(b"\n\n"));
             }
-            try!(color!(self, "ARGS:\n", warning));
-            try!(self.write_args_unsorted(parser.positionals().map(as_arg_trait)));
+            try!(color!(self, "{}\n", parser.catalog().args_heading(), warning));
+            try!(self.write_args_unsorted(parser
+                                              .positionals()
+                                              .filter(|p| p.b.help_heading.is_none())
+                                              .map(as_arg_trait)));
+            first = false;
+        }
+
+        for heading in &parser.help_headings {
+            let args = section_args(parser, heading);
+            if args.iter().all(|a| a.is_set(ArgSettings::Hidden)) {
+                continue;
+            }
+            if !first {
+                try!(self.writer.write_all(b"\n\n"));
+            }
+            try!(color!(self, "{}:\n", heading, warning));
+            try!(self.write_args(args.into_iter()));
             first = false;
         }
 
@@ -564,23 +775,41 @@ impl<'a> Help<'a> {
             if !first {
                 try!(self.writer.write_all(b"\n\n"));
             }
-            try!(color!(self, "SUBCOMMANDS:\n", warning));
-            try!(self.write_subcommands(parser));
+            try!(color!(self, "{}\n", parser.catalog().subcommands_heading(), warning));
+            try!(self.write_subcommands(parser, None));
+            first = false;
+        }
+
+        for heading in subcommand_headings(parser) {
+            if parser
+                   .subcommands
+                   .iter()
+                   .filter(|s| s.p.meta.help_heading == Some(heading))
+                   .all(|s| s.p.is_set(AppSettings::Hidden)) {
+                continue;
+            }
+            if !first {
+                try!(self.writer.write_all(b"\n\n"));
+            }
+            try!(color!(self, "{}:\n", heading, warning));
+            try!(self.write_subcommands(parser, Some(heading)));
+            first = false;
         }
 
         Ok(())
     }
 
-    /// Writes help for subcommands of a Parser Object to the wrapped stream.
-    fn write_subcommands(&mut self, parser: &Parser) -> io::Result<()> {
-        debugln!("Help::write_subcommands;");
+    /// Writes help for the subcommands of a Parser Object with the given heading (or without a
+    /// heading) to the wrapped stream.
+    fn write_subcommands(&mut self, parser: &Parser, heading: Option<&str>) -> io::Result<()> {
+        debugln!("Help::write_subcommands: heading={:?}", heading);
         // The shortest an arg can legally be is 2 (i.e. '-x')
         self.longest = 2;
         let mut ord_m = VecMap::new();
         for sc in parser
                 .subcommands
                 .iter()
-                .filter(|s| !s.p.is_set(AppSettings::Hidden)) {
+                .filter(|s| !s.p.is_set(AppSettings::Hidden) && s.p.meta.help_heading == heading) {
             let btm = ord_m
                 .entry(sc.p.meta.disp_ord)
                 .or_insert(BTreeMap::new());
@@ -588,6 +817,11 @@ impl<'a> Help<'a> {
             //self.longest = cmp::max(self.longest, sc.p.meta.name.len());
             btm.insert(sc.p.meta.name.clone(), sc.clone());
         }
+        self.measure_rows(&ord_m
+                               .values()
+                               .flat_map(|btm| btm.values())
+                               .map(|sc| as_arg_trait(sc).as_base())
+                               .collect::<Vec<_>>());
 
         let mut first = true;
         for btm in ord_m.values() {
@@ -661,7 +895,7 @@ impl<'a> Help<'a> {
             write_thing!(about)
         }
 
-        try!(color!(self, "\nUSAGE:", warning));
+        try!(color!(self, "\n{}", parser.catalog().usage_heading(), warning));
         try!(write!(self.writer,
                     "\n{}{}\n\n",
                     TAB,
@@ -687,111 +921,104 @@ impl<'a> Help<'a> {
     }
 }
 
-/// Possible results for a copying function that stops when a given
-/// byte was found.
-enum CopyUntilResult {
-    DelimiterFound(usize),
-    DelimiterNotFound(usize),
-    ReaderEmpty,
-    ReadError(io::Error),
-    WriteError(io::Error),
-}
-
-/// Copies the contents of a reader into a writer until a delimiter byte is found.
-/// On success, the total number of bytes that were
-/// copied from reader to writer is returned.
-fn copy_until<R: Read, W: Write>(r: &mut R, w: &mut W, delimiter_byte: u8) -> CopyUntilResult {
-    debugln!("copy_until;");
-
-    let mut count = 0;
-    for wb in r.bytes() {
-        match wb {
-            Ok(b) => {
-                if b == delimiter_byte {
-                    return CopyUntilResult::DelimiterFound(count);
-                }
-                match w.write(&[b]) {
-                    Ok(c) => count += c,
-                    Err(e) => return CopyUntilResult::WriteError(e),
+// The names of the tags of a template which are padded to the widest value (i.e. `{long:auto}`)
+fn auto_width_tags<'t>(nodes: &[Node<'t>], names: &mut Vec<&'t str>) {
+    for node in nodes {
+        match *node {
+            Node::Tag(tag) => {
+                if let (name, Some("auto")) = template::split_tag(tag) {
+                    if !names.contains(&name) {
+                        names.push(name);
+                    }
                 }
             }
-            Err(e) => return CopyUntilResult::ReadError(e),
+            Node::If(ref cond) => {
+                auto_width_tags(&cond.then, names);
+                auto_width_tags(&cond.otherwise, names);
+            }
+            Node::Text(_) => (),
         }
     }
-    if count > 0 {
-        CopyUntilResult::DelimiterNotFound(count)
+}
+
+// The column at which a row continues after writing `s` at column `col`
+fn advance(col: usize, s: &str) -> usize {
+    match s.rfind('\n') {
+        Some(i) => str_width(&s[i + 1..]),
+        None => col + str_width(s),
+    }
+}
+
+// The values an argument takes as displayed in its help (i.e. `<FILE>...`)
+fn val_str<'b, 'c>(arg: &ArgWithDisplay<'b, 'c>) -> String {
+    if !arg.takes_value() {
+        return String::new();
+    }
+    let mult = arg.is_set(ArgSettings::Multiple);
+    if let Some(vec) = arg.val_names() {
+        let vals = vec.values().map(|v| format!("<{}>", v)).collect::<Vec<_>>().join(" ");
+        if mult && vec.len() == 1 {
+            vals + "..."
+        } else {
+            vals
+        }
+    } else if let Some(num) = arg.num_vals() {
+        let vals = (0..num).map(|_| format!("<{}>", arg.name())).collect::<Vec<_>>().join(" ");
+        if mult && num == 1 {
+            vals + "..."
+        } else {
+            vals
+        }
+    } else if arg.has_switch() {
+        format!("<{}>{}", arg.name(), if mult { "..." } else { "" })
     } else {
-        CopyUntilResult::ReaderEmpty
+        arg.to_string()
     }
 }
 
-/// Copies the contents of a reader into a writer until a {tag} is found,
-/// copying the tag content to a buffer and returning its size.
-/// In addition to errors, there are three possible outputs:
-///   - `None`: The reader was consumed.
-///   - `Some(Ok(0))`: No tag was captured but the reader still contains data.
-///   - `Some(Ok(length>0))`: a tag with `length` was captured to the `tag_buffer`.
-fn copy_and_capture<R: Read, W: Write>(r: &mut R,
-                                       w: &mut W,
-                                       tag_buffer: &mut Cursor<Vec<u8>>)
-                                       -> Option<io::Result<usize>> {
-    use self::CopyUntilResult::*;
-    debugln!("copy_and_capture;");
-
-    // Find the opening byte.
-    match copy_until(r, w, b'{') {
-
-        // The end of the reader was reached without finding the opening tag.
-        // (either with or without having copied data to the writer)
-        // Return None indicating that we are done.
-        ReaderEmpty |
-        DelimiterNotFound(_) => None,
-
-        // Something went wrong.
-        ReadError(e) | WriteError(e) => Some(Err(e)),
-
-        // The opening byte was found.
-        // (either with or without having copied data to the writer)
-        DelimiterFound(_) => {
-
-            // Lets reset the buffer first and find out how long it is.
-            tag_buffer.set_position(0);
-            let buffer_size = tag_buffer.get_ref().len();
-
-            // Find the closing byte,limiting the reader to the length of the buffer.
-            let mut rb = r.take(buffer_size as u64);
-            match copy_until(&mut rb, tag_buffer, b'}') {
-
-                // We were already at the end of the reader.
-                // Return None indicating that we are done.
-                ReaderEmpty => None,
-
-                // The closing tag was found.
-                // Return the tag_length.
-                DelimiterFound(tag_length) => Some(Ok(tag_length)),
-
-                // The end of the reader was found without finding the closing tag.
-                // Write the opening byte and captured text to the writer.
-                // Return 0 indicating that nothing was caputred but the reader still contains data.
-                DelimiterNotFound(not_tag_length) => {
-                    match w.write(b"{") {
-                        Err(e) => Some(Err(e)),
-                        _ => {
-                            match w.write(&tag_buffer.get_ref()[0..not_tag_length]) {
-                                Err(e) => Some(Err(e)),
-                                _ => Some(Ok(0)),
-                            }
-                        }
-                    }
-                }
+// The flags and options which aren't displayed in a section of their own
+fn unified_args<'p, 'a, 'b>(parser: &'p Parser<'a, 'b>) -> Vec<&'p ArgWithOrder<'a, 'b>> {
+    parser
+        .flags()
+        .filter(|f| f.b.help_heading.is_none())
+        .map(as_arg_trait)
+        .chain(parser
+                   .opts()
+                   .filter(|o| o.b.help_heading.is_none())
+                   .map(as_arg_trait))
+        .collect()
+}
 
-                ReadError(e) | WriteError(e) => Some(Err(e)),
-            }
+// The args displayed in the section with the given heading (see `Arg::help_heading`)
+fn section_args<'p, 'a, 'b>(parser: &'p Parser<'a, 'b>,
+                            heading: &str)
+                            -> Vec<&'p ArgWithOrder<'a, 'b>> {
+    parser
+        .flags()
+        .filter(|f| f.b.help_heading == Some(heading))
+        .map(as_arg_trait)
+        .chain(parser
+                   .opts()
+                   .filter(|o| o.b.help_heading == Some(heading))
+                   .map(as_arg_trait))
+        .chain(parser
+                   .positionals()
+                   .filter(|p| p.b.help_heading == Some(heading))
+                   .map(as_arg_trait))
+        .collect()
+}
+
+// The headings of the subcommands (see `App::help_heading`), in the order they were first used
+fn subcommand_headings<'a, 'b>(parser: &Parser<'a, 'b>) -> Vec<&'b str> {
+    let mut headings = vec![];
+    for h in parser.subcommands.iter().filter_map(|s| s.p.meta.help_heading) {
+        if !headings.contains(&h) {
+            headings.push(h);
         }
     }
+    headings
 }
 
-
 // Methods to write Parser help using templates.
 impl<'a> Help<'a> {
     /// Write help to stream for the parser in the format defined by the template.
@@ -799,8 +1026,11 @@ impl<'a> Help<'a> {
     /// Tags arg given inside curly brackets:
     /// Valid tags are:
     ///     * `{bin}`         - Binary name.
+    ///     * `{name}`        - Name of the app (or subcommand).
     ///     * `{version}`     - Version number.
     ///     * `{author}`      - Author information.
+    ///     * `{about}`       - Short description.
+    ///     * `{long-about}`  - Long description, or the short one if there is none.
     ///     * `{usage}`       - Automatically generated or given usage string.
     ///     * `{all-args}`    - Help for all arguments (options, flags, positionals arguments,
     ///                         and subcommands) including titles.
@@ -809,101 +1039,173 @@ impl<'a> Help<'a> {
     ///     * `{options}`     - Help for options.
     ///     * `{positionals}` - Help for positionals arguments.
     ///     * `{subcommands}` - Help for subcommands.
+    ///     * `{section:<heading>}` - Help for the arguments with the given heading.
     ///     * `{after-help}`  - Info to be displayed after the help message.
     ///     * `{before-help}` - Info to be displayed before the help message.
     ///
-    /// The template system is, on purpose, very simple. Therefore the tags have to writen
-    /// in the lowercase and without spacing.
+    /// `{#if <tag>}...{#else}...{/if}` only writes the first part when the tag would write
+    /// something (see `template_cond`), and `{#if !<tag>}` negates the condition.
     fn write_templated_help(&mut self, parser: &Parser, template: &str) -> ClapResult<()> {
         debugln!("Help::write_templated_help;");
-        let mut tmplr = Cursor::new(&template);
-        let mut tag_buf = Cursor::new(vec![0u8; 15]);
-
-        // The strategy is to copy the template from the the reader to wrapped stream
-        // until a tag is found. Depending on its value, the appropriate content is copied
-        // to the wrapped stream.
-        // The copy from template is then resumed, repeating this sequence until reading
-        // the complete template.
-
-        loop {
-            let tag_length = match copy_and_capture(&mut tmplr, &mut self.writer, &mut tag_buf) {
-                None => return Ok(()),
-                Some(Err(e)) => return Err(Error::from(e)),
-                Some(Ok(val)) if val > 0 => val,
-                _ => continue,
-            };
+        let nodes = template::parse(template);
+        self.write_template_nodes(parser, &nodes)
+    }
 
-            debugln!("Help::write_template_help:iter: tag_buf={};", unsafe {
-                String::from_utf8_unchecked(tag_buf.get_ref()[0..tag_length]
-                                                .iter()
-                                                .map(|&i| i)
-                                                .collect::<Vec<_>>())
-            });
-            match &tag_buf.get_ref()[0..tag_length] {
-                b"?" => {
-                    try!(self.writer.write_all(b"Could not decode tag name"));
-                }
-                b"bin" => {
-                    try!(self.write_bin_name(parser));
-                }
-                b"version" => {
-                    try!(write!(self.writer,
-                                "{}",
-                                parser.meta.version.unwrap_or("unknown version")));
-                }
-                b"author" => {
-                    try!(write!(self.writer,
-                                "{}",
-                                parser.meta.author.unwrap_or("unknown author")));
-                }
-                b"about" => {
-                    try!(write!(self.writer,
-                                "{}",
-                                parser.meta.about.unwrap_or("unknown about")));
-                }
-                b"usage" => {
-                    try!(write!(self.writer, "{}", usage::create_usage_no_title(parser, &[])));
-                }
-                b"all-args" => {
-                    try!(self.write_all_args(parser));
-                }
-                b"unified" => {
-                    let opts_flags = parser
-                        .flags()
-                        .map(as_arg_trait)
-                        .chain(parser.opts().map(as_arg_trait));
-                    try!(self.write_args(opts_flags));
-                }
-                b"flags" => {
-                    try!(self.write_args(parser.flags().map(as_arg_trait)));
-                }
-                b"options" => {
-                    try!(self.write_args(parser.opts().map(as_arg_trait)));
-                }
-                b"positionals" => {
-                    try!(self.write_args(parser.positionals().map(as_arg_trait)));
-                }
-                b"subcommands" => {
-                    try!(self.write_subcommands(parser));
+    fn write_template_nodes(&mut self, parser: &Parser, nodes: &[Node]) -> ClapResult<()> {
+        for node in nodes {
+            match *node {
+                Node::Text(text) => try!(self.writer.write_all(text.as_bytes())),
+                Node::Tag(tag) => try!(self.write_template_tag(parser, tag)),
+                Node::If(ref cond) => {
+                    let holds = template_cond(parser, cond.name);
+                    debugln!("Help::write_template_nodes: cond={}, holds={:?}", cond.name, holds);
+                    try!(self.write_template_nodes(parser, cond.branch(holds)));
                 }
-                b"after-help" => {
-                    try!(write!(self.writer,
-                                "{}",
-                                parser.meta.more_help.unwrap_or("unknown after-help")));
-                }
-                b"before-help" => {
-                    try!(write!(self.writer,
-                                "{}",
-                                parser.meta.pre_help.unwrap_or("unknown before-help")));
-                }
-                // Unknown tag, write it back.
-                r => {
-                    try!(self.writer.write_all(b"{"));
-                    try!(self.writer.write_all(r));
-                    try!(self.writer.write_all(b"}"));
+            }
+        }
+        Ok(())
+    }
+
+    fn write_template_tag(&mut self, parser: &Parser, tag: &str) -> ClapResult<()> {
+        debugln!("Help::write_template_tag: tag={}", tag);
+        match tag {
+            "bin" => {
+                try!(self.write_bin_name(parser));
+            }
+            "name" => {
+                try!(write!(self.writer, "{}", parser.meta.name));
+            }
+            "version" => {
+                try!(write!(self.writer,
+                            "{}",
+                            parser.meta.version.unwrap_or("unknown version")));
+            }
+            "author" => {
+                try!(write!(self.writer,
+                            "{}",
+                            parser.meta.author.unwrap_or("unknown author")));
+            }
+            "about" => {
+                try!(write!(self.writer,
+                            "{}",
+                            parser.meta.about.unwrap_or("unknown about")));
+            }
+            "long-about" => {
+                try!(write!(self.writer,
+                            "{}",
+                            parser
+                                .meta
+                                .long_about
+                                .or(parser.meta.about)
+                                .unwrap_or("unknown long-about")));
+            }
+            "usage" => {
+                try!(write!(self.writer, "{}", usage::create_usage_no_title(parser, &[])));
+            }
+            "all-args" => {
+                try!(self.write_all_args(parser));
+            }
+            "unified" => {
+                try!(self.write_args(unified_args(parser).into_iter()));
+            }
+            "flags" => {
+                try!(self.write_args(parser
+                                         .flags()
+                                         .filter(|f| f.b.help_heading.is_none())
+                                         .map(as_arg_trait)));
+            }
+            "options" => {
+                try!(self.write_args(parser
+                                         .opts()
+                                         .filter(|o| o.b.help_heading.is_none())
+                                         .map(as_arg_trait)));
+            }
+            "positionals" => {
+                try!(self.write_args(parser
+                                         .positionals()
+                                         .filter(|p| p.b.help_heading.is_none())
+                                         .map(as_arg_trait)));
+            }
+            t if t.starts_with("section:") => {
+                let heading = &t["section:".len()..];
+                let args = section_args(parser, heading);
+                let has_args = !args.is_empty();
+                try!(self.write_args(args.into_iter()));
+                if parser.subcommands.iter().any(|s| s.p.meta.help_heading == Some(heading)) {
+                    if has_args {
+                        try!(self.writer.write_all(b"\n"));
+                    }
+                    try!(self.write_subcommands(parser, Some(heading)));
                 }
             }
+            "subcommands" => {
+                try!(self.write_subcommands(parser, None));
+            }
+            "after-help" => {
+                try!(write!(self.writer,
+                            "{}",
+                            parser.meta.more_help.unwrap_or("unknown after-help")));
+            }
+            "before-help" => {
+                try!(write!(self.writer,
+                            "{}",
+                            parser.meta.pre_help.unwrap_or("unknown before-help")));
+            }
+            // Unknown tag, write it back.
+            t => {
+                try!(write!(self.writer, "{{{}}}", t));
+            }
+        }
+        Ok(())
+    }
+}
+
+// Whether the tag of a help template with the given name would write something, which is what
+// `{#if <tag>}` tests (unknown tags never hold)
+fn template_cond(parser: &Parser, tag: &str) -> bool {
+    let visible = |args: Vec<&ArgWithOrder>| args.iter().any(|a| !a.is_set(ArgSettings::Hidden));
+    let flags = visible(parser
+                            .flags()
+                            .filter(|f| f.b.help_heading.is_none())
+                            .map(as_arg_trait)
+                            .collect());
+    let opts = visible(parser
+                           .opts()
+                           .filter(|o| o.b.help_heading.is_none())
+                           .map(as_arg_trait)
+                           .collect());
+    let pos = visible(parser
+                          .positionals()
+                          .filter(|p| p.b.help_heading.is_none())
+                          .map(as_arg_trait)
+                          .collect());
+    let subcmds = |heading: Option<&str>| {
+        parser
+            .subcommands
+            .iter()
+            .any(|s| !s.p.is_set(AppSettings::Hidden) && s.p.meta.help_heading == heading)
+    };
+    let section = |heading: &str| visible(section_args(parser, heading)) || subcmds(Some(heading));
+    match tag {
+        "bin" | "name" | "usage" => true,
+        "version" => parser.meta.version.is_some(),
+        "author" => parser.meta.author.is_some(),
+        "about" => parser.meta.about.is_some(),
+        "long-about" => parser.meta.long_about.is_some() || parser.meta.about.is_some(),
+        "before-help" => parser.meta.pre_help.is_some(),
+        "after-help" => parser.meta.more_help.is_some(),
+        "flags" => flags,
+        "options" => opts,
+        "unified" => flags || opts,
+        "positionals" => pos,
+        "subcommands" => subcmds(None),
+        "all-args" => {
+            flags || opts || pos || subcmds(None) ||
+            parser.help_headings.iter().any(|h| section(h)) ||
+            subcommand_headings(parser).into_iter().any(|h| section(h))
         }
+        t if t.starts_with("section:") => section(&t["section:".len()..]),
+        _ => false,
     }
 }
 
@@ -16,9 +16,11 @@ pub struct AppMeta<'b> {
     pub usage: Option<String>,
     pub help_str: Option<&'b str>,
     pub disp_ord: usize,
+    pub help_heading: Option<&'b str>,
     pub term_w: Option<usize>,
     pub max_w: Option<usize>,
     pub template: Option<&'b str>,
+    pub arg_template: Option<&'b str>,
 }
 
 impl<'b> AppMeta<'b> {
@@ -1,11 +1,17 @@
 mod settings;
+mod checks;
+#[cfg(any(feature = "yaml", feature = "json", feature = "toml"))]
+pub mod definition;
+#[cfg(any(feature = "yaml", feature = "json", feature = "toml"))]
+mod positions;
 #[macro_use]
 mod macros;
 pub mod parser;
 mod meta;
 mod help;
+mod template;
 mod validator;
-mod usage;
+pub mod usage;
 
 // Std
 use std::env;
@@ -16,19 +22,26 @@ use std::path::Path;
 use std::process;
 use std::rc::Rc;
 use std::result::Result as StdResult;
+use std::sync::Arc;
 
 // Third Party
 use vec_map::{self, VecMap};
 #[cfg(feature = "yaml")]
 use yaml_rust::Yaml;
+#[cfg(feature = "json")]
+use serde_json;
+#[cfg(feature = "toml")]
+use toml;
 
 // Internal
 use app::help::Help;
 use app::parser::Parser;
-use args::{AnyArg, Arg, ArgGroup, ArgMatcher, ArgMatches, ArgSettings};
-use errors::Result as ClapResult;
+use args::{AnyArg, Arg, ArgGroup, ArgKey, ArgMatcher, ArgMatches, ArgSettings, ValueHint};
+use errors::{Error, ErrorKind, Result as ClapResult};
 pub use self::settings::AppSettings;
-use completions::Shell;
+use completions::{DynamicGen, Shell};
+use config::ConfigSource;
+use catalog::MessageCatalog;
 
 /// Used to create a representation of a command line program and all possible command line
 /// arguments. Application settings are set using the "builder pattern" with the
@@ -126,7 +139,8 @@ impl<'a, 'b> App<'a, 'b> {
     ///
     /// The YAML file must be properly formatted or this function will [`panic!`]. A good way to
     /// ensure this doesn't happen is to run your program with the `--help` switch. If this passes
-    /// without error, you needn't worry because the YAML is properly formatted.
+    /// without error, you needn't worry because the YAML is properly formatted. Use
+    /// [`App::try_from_yaml`] to get an error describing every problem instead.
     ///
     /// # Examples
     ///
@@ -148,9 +162,218 @@ impl<'a, 'b> App<'a, 'b> {
     /// [`examples/17_yaml.rs`]: https://github.com/kbknapp/clap-rs/blob/master/examples/17_yaml.rs
     /// [`examples/17_yaml.yml`]: https://github.com/kbknapp/clap-rs/blob/master/examples/17_yaml.yml
     /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    /// [`App::try_from_yaml`]: ./struct.App.html#method.try_from_yaml
     #[cfg(feature = "yaml")]
     pub fn from_yaml(yaml: &'a Yaml) -> App<'a, 'a> { App::from(yaml) }
 
+    /// Creates a new instance of an application from a YAML document in the same manner as
+    /// [`App::from_yaml`], but returns an error instead of panicking when the document isn't
+    /// valid.
+    ///
+    /// Every problem is reported in a single [`ErrorKind::InvalidDefinition`] error, along with
+    /// the path of the key which caused it (i.e. `subcommands[2].args[0].possible_values`) and
+    /// the type which was expected. Unlike [`App::from_yaml`], unknown keys (such as a misspelled
+    /// `requried: true`) are reported as well. The paths are stored as
+    /// [`ContextKind::KeyPath`] in the error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, ErrorKind, YamlLoader};
+    /// let docs = YamlLoader::load_from_str("
+    /// name: myprog
+    /// args:
+    ///     - input:
+    ///         requried: true
+    ///         index: one
+    /// ").unwrap();
+    ///
+    /// let err = App::try_from_yaml(&docs[0]).err().unwrap();
+    /// assert_eq!(err.kind, ErrorKind::InvalidDefinition);
+    /// assert!(err.message().contains("'args[0].index': expected a non-negative integer, \
+    ///                                 found a string"));
+    /// assert!(err.message().contains("'args[0]': unknown key 'requried'"));
+    /// ```
+    /// [`App::from_yaml`]: ./struct.App.html#method.from_yaml
+    /// [`ErrorKind::InvalidDefinition`]: ./enum.ErrorKind.html#variant.InvalidDefinition
+    /// [`ContextKind::KeyPath`]: ./enum.ContextKind.html#variant.KeyPath
+    #[cfg(feature = "yaml")]
+    pub fn try_from_yaml(yaml: &'a Yaml) -> ClapResult<App<'a, 'a>> {
+        definition::Builder::build(&definition::Node::from(yaml), None)
+    }
+
+    /// Creates a new instance of an application from a JSON document, such as one shared with
+    /// tools which aren't written in Rust. Requires the `json` cargo feature.
+    ///
+    /// The document uses the same keys as [`App::from_yaml`] (see [`examples/17_yaml.yml`]).
+    /// Arguments, subcommands and groups are either objects with a single key which is their name
+    /// (i.e. `{"verbose": {"short": "v"}}`), or objects with a `name` key (i.e.
+    /// `{"name": "verbose", "short": "v"}`).
+    ///
+    /// Unlike [`App::from_yaml`] this function doesn't panic. Every unknown key and every value of
+    /// the wrong type is reported in a single [`ErrorKind::InvalidDefinition`] error, along with
+    /// the path of the key (i.e. `subcommands[0].args[1].short`). [`Error`] can be converted from
+    /// the errors of `serde_json`, which reports the line and column of syntax errors, so `try!`
+    /// works for both. Only syntax errors have a line and column, because `serde_json::Value`
+    /// doesn't keep the positions of its values; use [`App::from_json_source`] to report the line
+    /// and column of every other problem as well.
+    ///
+    /// The documents written by [`App::gen_json_to`] can be read back as well. They're recognized
+    /// by their `schema_version` key, which must not be newer than the version this crate
+    /// writes. `null` is the same as leaving a key out, the generated help and version flags and
+    /// help subcommand are generated again (keeping the short and help message of the flags), and
+    /// global arguments aren't added again to the subcommands they're listed in. The `usage` of
+    /// such documents is ignored, because it's derived from the arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate clap;
+    /// # extern crate serde_json;
+    /// # use clap::App;
+    /// # fn main() {
+    /// let def: serde_json::Value = serde_json::from_str(r#"{
+    ///     "name": "myprog",
+    ///     "version": "1.0",
+    ///     "args": [
+    ///         {"verbose": {"short": "v", "multiple": true}},
+    ///         {"name": "input", "required": true, "index": 1}
+    ///     ]
+    /// }"#).unwrap();
+    ///
+    /// let m = App::from_json(&def)
+    ///     .unwrap()
+    ///     .get_matches_from(vec!["myprog", "-vv", "file.txt"]);
+    /// assert_eq!(m.occurrences_of("verbose"), 2);
+    /// assert_eq!(m.value_of("input"), Some("file.txt"));
+    /// # }
+    /// ```
+    /// [`App::from_yaml`]: ./struct.App.html#method.from_yaml
+    /// [`examples/17_yaml.yml`]: https://github.com/kbknapp/clap-rs/blob/master/examples/17_yaml.yml
+    /// [`ErrorKind::InvalidDefinition`]: ./enum.ErrorKind.html#variant.InvalidDefinition
+    /// [`Error`]: ./struct.Error.html
+    /// [`App::gen_json_to`]: ./struct.App.html#method.gen_json_to
+    /// [`App::from_json_source`]: ./struct.App.html#method.from_json_source
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &'a serde_json::Value) -> ClapResult<App<'a, 'a>> {
+        definition::Builder::build(&definition::Node::from(json), None)
+    }
+
+    /// Creates a new instance of an application from a JSON document in the same manner as
+    /// [`App::from_json`], where `src` is the text `json` was parsed from. Requires the `json`
+    /// cargo feature.
+    ///
+    /// Besides its key path, every problem is reported with the line and column of its key in
+    /// `src` (or of the closest key above it, when it's the value which isn't valid). They're
+    /// stored as [`ContextKind::Line`] and [`ContextKind::Column`] in the error, in the same order
+    /// as the [`ContextKind::KeyPath`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate clap;
+    /// # extern crate serde_json;
+    /// # use clap::{App, ContextKind, ContextValue};
+    /// # fn main() {
+    /// let src = r#"{
+    ///     "name": "myprog",
+    ///     "args": [
+    ///         {"name": "input", "index": "one"}
+    ///     ]
+    /// }"#;
+    /// let def = serde_json::from_str(src).unwrap();
+    ///
+    /// let err = App::from_json_source(&def, src).err().unwrap();
+    /// assert_eq!(err.get(ContextKind::Line), Some(&ContextValue::Numbers(vec![4])));
+    /// assert!(err.message().contains("'args[0].index' (line 4, column 27): expected a \
+    ///                                 non-negative integer, found a string"));
+    /// # }
+    /// ```
+    /// [`App::from_json`]: ./struct.App.html#method.from_json
+    /// [`ContextKind::Line`]: ./enum.ContextKind.html#variant.Line
+    /// [`ContextKind::Column`]: ./enum.ContextKind.html#variant.Column
+    /// [`ContextKind::KeyPath`]: ./enum.ContextKind.html#variant.KeyPath
+    #[cfg(feature = "json")]
+    pub fn from_json_source(json: &'a serde_json::Value, src: &str) -> ClapResult<App<'a, 'a>> {
+        definition::Builder::build(&definition::Node::from(json),
+                                   positions::Positions::json(src).as_ref())
+    }
+
+    /// Creates a new instance of an application from a TOML document, in the same manner as
+    /// [`App::from_json`]. Requires the `toml` cargo feature.
+    ///
+    /// Arguments, subcommands and groups are usually written as arrays of tables with a `name`
+    /// key, and [`Error`] can be converted from the errors of `toml`, which reports the line and
+    /// column of syntax errors. As with JSON, only syntax errors have a line and column, unless
+    /// the document is built with [`App::from_toml_source`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate clap;
+    /// # extern crate toml;
+    /// # use clap::App;
+    /// # fn main() {
+    /// let def: toml::Value = r#"
+    ///     name = "myprog"
+    ///
+    ///     [[args]]
+    ///     name = "verbose"
+    ///     short = "v"
+    ///
+    ///     [[subcommands]]
+    ///     name = "test"
+    ///     about = "Tests things"
+    /// "#.parse().unwrap();
+    ///
+    /// let m = App::from_toml(&def)
+    ///     .unwrap()
+    ///     .get_matches_from(vec!["myprog", "-v", "test"]);
+    /// assert!(m.is_present("verbose"));
+    /// assert_eq!(m.subcommand_name(), Some("test"));
+    /// # }
+    /// ```
+    /// [`App::from_json`]: ./struct.App.html#method.from_json
+    /// [`Error`]: ./struct.Error.html
+    /// [`App::from_toml_source`]: ./struct.App.html#method.from_toml_source
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &'a toml::Value) -> ClapResult<App<'a, 'a>> {
+        definition::Builder::build(&definition::Node::from(toml), None)
+    }
+
+    /// Creates a new instance of an application from a TOML document in the same manner as
+    /// [`App::from_toml`], where `src` is the text `toml` was parsed from, and reports the line and
+    /// column of every problem as [`App::from_json_source`] does. Requires the `toml` cargo
+    /// feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate clap;
+    /// # extern crate toml;
+    /// # use clap::App;
+    /// # fn main() {
+    /// let src = r#"
+    /// name = "myprog"
+    ///
+    /// [[args]]
+    /// name = "input"
+    /// requried = true
+    /// "#;
+    /// let def = src.parse().unwrap();
+    ///
+    /// let err = App::from_toml_source(&def, src).err().unwrap();
+    /// assert!(err.message().contains("'args[0]' (line 6, column 1): unknown key 'requried'"));
+    /// # }
+    /// ```
+    /// [`App::from_toml`]: ./struct.App.html#method.from_toml
+    /// [`App::from_json_source`]: ./struct.App.html#method.from_json_source
+    #[cfg(feature = "toml")]
+    pub fn from_toml_source(toml: &'a toml::Value, src: &str) -> ClapResult<App<'a, 'a>> {
+        definition::Builder::build(&definition::Node::from(toml),
+                                   positions::Positions::toml(src).as_ref())
+    }
+
     /// Sets a string of author(s) that will be displayed to the user when they
     /// request the help information with `--help` or `-h`.
     ///
@@ -531,9 +754,12 @@ impl<'a, 'b> App<'a, 'b> {
     /// Valid tags are:
     ///
     ///   * `{bin}`         - Binary name.
+    ///   * `{name}`        - Name of the app (or subcommand).
     ///   * `{version}`     - Version number.
     ///   * `{author}`      - Author information.
     ///   * `{about}`       - General description (from [`App::about`])
+    ///   * `{long-about}`  - Long description (from [`App::long_about`], or [`App::about`] if
+    ///                       there is none)
     ///   * `{usage}`       - Automatically generated or given usage string.
     ///   * `{all-args}`    - Help for all arguments (options, flags, positionals arguments,
     ///                       and subcommands) including titles.
@@ -543,10 +769,18 @@ impl<'a, 'b> App<'a, 'b> {
     ///   * `{flags}`       - Help for flags.
     ///   * `{options}`     - Help for options.
     ///   * `{positionals}` - Help for positionals arguments.
+    ///   * `{section:<heading>}` - Help for the arguments with the given [`Arg::help_heading`]
+    ///                       (which are left out of the other tags)
     ///   * `{subcommands}` - Help for subcommands.
     ///   * `{after-help}`  - Help from [`App::after_help`]
     ///   * `{before-help}`  - Help from [`App::before_help`]
     ///
+    /// A part of the template can be made conditional with `{#if <tag>}...{/if}`, which is only
+    /// written when the tag would write something (i.e. `{#if subcommands}` holds when there is
+    /// at least one visible subcommand without a heading). An `{#else}` part is written
+    /// otherwise, conditions can be negated with `{#if !<tag>}`, and conditionals can be nested.
+    /// The rows written for each argument can be changed with [`App::arg_template`].
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -556,17 +790,66 @@ impl<'a, 'b> App<'a, 'b> {
     ///     .template("{bin} ({version}) - {usage}")
     /// # ;
     /// ```
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// App::new("myprog")
+    ///     .template("{name}{#if about} - {about}{/if}\n\n\
+    ///                USAGE:\n    {usage}\n\
+    ///                {#if subcommands}\nCOMMANDS:\n{subcommands}\n{/if}")
+    /// # ;
+    /// ```
     /// **NOTE:**The template system is, on purpose, very simple. Therefore the tags have to writen
     /// in the lowercase and without spacing.
     /// [`App::about`]: ./struct.App.html#method.about
+    /// [`App::long_about`]: ./struct.App.html#method.long_about
     /// [`App::after_help`]: ./struct.App.html#method.after_help
     /// [`App::before_help`]: ./struct.App.html#method.before_help
+    /// [`App::arg_template`]: ./struct.App.html#method.arg_template
     /// [`AppSettings::UnifiedHelpMessage`]: ./enum.AppSettings.html#variant.UnifiedHelpMessage
+    /// [`Arg::help_heading`]: ./struct.Arg.html#method.help_heading
     pub fn template<S: Into<&'b str>>(mut self, s: S) -> Self {
         self.p.meta.template = Some(s.into());
         self
     }
 
+    /// Sets the template used to write the help of each argument and subcommand, overriding
+    /// the default layout of the rows (but not the titles of the sections).
+    ///
+    /// Valid tags are:
+    ///
+    ///   * `{name}`  - Name of the argument or subcommand.
+    ///   * `{short}` - Short switch (i.e. `-v`), if any.
+    ///   * `{long}`  - Long switch (i.e. `--verbose`), if any.
+    ///   * `{value}` - Value names of an option (i.e. `<FILE>...`). Positional arguments and
+    ///                 subcommands are displayed as in the default help (i.e. `<input>`).
+    ///   * `{help}`  - Help message (or [`App::about`] for subcommands), which is wrapped to the
+    ///                 width of the terminal with the lines following the first one indented up
+    ///                 to the column of the tag.
+    ///   * `{spec}`  - Special values, such as `[default: auto] [values: auto, never]`.
+    ///
+    /// A tag can be padded with spaces to a width, either fixed (i.e. `{short:4}`) or that of the
+    /// widest value in the section (i.e. `{long:auto}`), which lines up the columns following
+    /// it. `{#if <tag>}...{#else}...{/if}` tests whether a tag has a value for the argument.
+    ///
+    /// **NOTE:** Settings such as [`AppSettings::NextLineHelp`] don't apply to the rows written
+    /// with a template.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// App::new("myprog")
+    ///     .arg_template("  {#if short}{short},{/if}\t{long:auto}  {help}{#if spec} {spec}{/if}")
+    /// # ;
+    /// ```
+    /// [`App::about`]: ./struct.App.html#method.about
+    /// [`AppSettings::NextLineHelp`]: ./enum.AppSettings.html#variant.NextLineHelp
+    pub fn arg_template<S: Into<&'b str>>(mut self, s: S) -> Self {
+        self.p.meta.arg_template = Some(s.into());
+        self
+    }
+
     /// Enables a single command, or [`SubCommand`], level settings.
     ///
     /// See [`AppSettings`] for a full list of possibilities and examples.
@@ -1034,6 +1317,231 @@ impl<'a, 'b> App<'a, 'b> {
         self
     }
 
+    /// Adds a [`ConfigSource`], such as a configuration file, whose values are used for arguments
+    /// which weren't given on the command line or through the environment (see [`Arg::env`]).
+    /// Config sources take priority over [default values], and sources added later take priority
+    /// over those added before them. So to layer a system wide configuration file, a user's
+    /// configuration file, the environment and the command line, add the system wide file first.
+    ///
+    /// The values of the arguments of subcommands are taken from the subcommand's section of the
+    /// config source (see [`ConfigSource`]). Values are validated as if they were given on the
+    /// command line, and conflicts and requirements are checked after all sources have been merged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ConfigSource};
+    /// let cfg = ConfigSource::from_ini("/etc/tool.conf", "jobs = 2").unwrap();
+    ///
+    /// let m = App::new("tool")
+    ///     .arg(Arg::with_name("jobs")
+    ///         .long("jobs")
+    ///         .takes_value(true)
+    ///         .default_value("1"))
+    ///     .config(cfg.clone())
+    ///     .get_matches_from(vec!["tool"]);
+    /// assert_eq!(m.value_of("jobs"), Some("2"));
+    ///
+    /// let m = App::new("tool")
+    ///     .arg(Arg::with_name("jobs")
+    ///         .long("jobs")
+    ///         .takes_value(true)
+    ///         .default_value("1"))
+    ///     .config(cfg)
+    ///     .get_matches_from(vec!["tool", "--jobs", "8"]);
+    /// assert_eq!(m.value_of("jobs"), Some("8"));
+    /// ```
+    /// [`ConfigSource`]: ./struct.ConfigSource.html
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    /// [default values]: ./struct.Arg.html#method.default_value
+    pub fn config(mut self, source: ConfigSource) -> Self {
+        self.p.configs.push(source);
+        self
+    }
+
+    /// Declares the [`ArgKey`]s of this `App`, which are checked when the command line is parsed:
+    /// every argument of the `App` must be one of the keys, every key must name an argument or
+    /// [`ArgGroup`], and every argument or group an argument refers to (i.e. with
+    /// [`Arg::requires`], [`Arg::conflicts_with`] or [`ArgGroup::arg`]) must exist. A mistake
+    /// [`panic!`]s, in release builds as well.
+    ///
+    /// The [`ArgMatches`] of the `App` also [`panic!`] when they're queried for a name which isn't
+    /// one of the keys, instead of returning `None`. The keys only apply to this `App`, not to its
+    /// subcommands, and the [global] arguments of the apps above it don't need to be keys.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,should_panic
+    /// # #[macro_use]
+    /// # extern crate clap;
+    /// # use clap::{App, Arg, ArgKey};
+    /// arg_keys!{
+    ///     enum Key {
+    ///         Input => "input",
+    ///         Output => "output",
+    ///     }
+    /// }
+    ///
+    /// # fn main() {
+    /// // Panics, because "ouptut" isn't an argument
+    /// App::new("app")
+    ///     .arg_keys(Key::all())
+    ///     .arg(Arg::with_name(Key::Input.name()).requires("ouptut"))
+    ///     .arg(Arg::with_name(Key::Output.name()).long("output").takes_value(true))
+    ///     .get_matches_from(vec!["app", "in.txt"]);
+    /// # }
+    /// ```
+    /// [`ArgKey`]: ./trait.ArgKey.html
+    /// [`ArgGroup`]: ./struct.ArgGroup.html
+    /// [`Arg::requires`]: ./struct.Arg.html#method.requires
+    /// [`Arg::conflicts_with`]: ./struct.Arg.html#method.conflicts_with
+    /// [`ArgGroup::arg`]: ./struct.ArgGroup.html#method.arg
+    /// [`ArgMatches`]: ./struct.ArgMatches.html
+    /// [global]: ./struct.Arg.html#method.global
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    pub fn arg_keys<K: ArgKey>(mut self, keys: &[K]) -> Self {
+        self.p.keys = Some(keys.iter().map(|k| k.name()).collect());
+        self
+    }
+
+    /// Adds a validator which is given all the arguments of this `App` at once, after they've
+    /// been parsed and passed all the other checks (including the [validators] of the arguments
+    /// themselves). Unlike the validators of an argument, it can check how the arguments relate
+    /// to each other, such as that the value of `--end` is greater than the value of `--start`.
+    ///
+    /// When the validator returns an [`Err(String)`], parsing fails with an
+    /// [`ErrorKind::MatchesValidation`] error, which is printed with the message of the validator
+    /// and the usage of the `App`. The validators of an `App` are run in the order they were
+    /// added, and only for the arguments of that `App`, not the ones of its subcommands.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgMatches, ErrorKind};
+    /// fn ordered(m: &ArgMatches) -> Result<(), String> {
+    ///     let start = try!(m.value_of_t::<u32, _>("start").map_err(|e| e.to_string()));
+    ///     let end = try!(m.value_of_t::<u32, _>("end").map_err(|e| e.to_string()));
+    ///     if end > start {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(format!("--end ({}) must be greater than --start ({})", end, start))
+    ///     }
+    /// }
+    ///
+    /// let res = App::new("prog")
+    ///     .arg(Arg::with_name("start").long("start").default_value("0"))
+    ///     .arg(Arg::with_name("end").long("end").takes_value(true).required(true))
+    ///     .validator(ordered)
+    ///     .get_matches_from_safe(vec!["prog", "--start", "10", "--end", "5"]);
+    ///
+    /// let err = res.unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::MatchesValidation);
+    /// assert!(err.message().contains("--end (5) must be greater than --start (10)"));
+    /// ```
+    /// [validators]: ./struct.Arg.html#method.validator
+    /// [`Err(String)`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    /// [`ErrorKind::MatchesValidation`]: ./enum.ErrorKind.html#variant.MatchesValidation
+    pub fn validator<F>(mut self, f: F) -> Self
+        where F: Fn(&ArgMatches) -> StdResult<(), String> + 'static
+    {
+        self.p.validators.push(Rc::new(f));
+        self
+    }
+
+    /// Checks the definition of this `App` and of all its subcommands, returning every problem
+    /// which is found instead of stopping at the first one. The checks are:
+    ///
+    /// * Shorts and longs used by more than one argument (including the [global] arguments of the
+    ///   `App`s above a subcommand)
+    /// * Relations such as [`Arg::requires`], [`Arg::conflicts_with`] or [`ArgGroup::arg`] which
+    ///   refer to an argument or group which doesn't exist
+    /// * Arguments which can never be used (even though they may be required), because the
+    ///   arguments they require conflict with them or with each other
+    /// * Gaps between the [indices] of the positional arguments
+    /// * [Default values] which aren't one of the [possible values] of their argument
+    /// * [Argument aliases] which are the long or alias of another argument, and [subcommand
+    ///   aliases] which are the name or alias of another subcommand
+    ///
+    /// The problems are returned as an [`ErrorKind::InvalidDefinition`] error, whose
+    /// [`ContextKind::DefinitionPath`]s are the paths of the arguments, groups or subcommands with
+    /// a problem (i.e. `subcommands.build.args.jobs`). Calling this method in a unit test catches
+    /// mistakes in the definition without having to run the program with every argument.
+    ///
+    /// **NOTE:** In debug builds, the names, shorts and longs of the arguments of an `App` are
+    /// also checked when each argument is added, which [`panic!`]s on the first duplicate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::with_name("mode")
+    ///         .long("mode")
+    ///         .possible_values(&["fast", "slow"])
+    ///         .default_value("quick"))
+    ///     .arg(Arg::with_name("force")
+    ///         .long("force")
+    ///         .conflicts_with("dry_run"));
+    ///
+    /// let err = app.validate_definition().unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::InvalidDefinition);
+    /// assert!(err.message().contains("'args.mode': its default value 'quick' isn't one of"));
+    /// assert!(err.message().contains("'args.force': it conflicts with 'dry_run', which isn't"));
+    /// ```
+    /// [global]: ./struct.Arg.html#method.global
+    /// [`Arg::requires`]: ./struct.Arg.html#method.requires
+    /// [`Arg::conflicts_with`]: ./struct.Arg.html#method.conflicts_with
+    /// [`ArgGroup::arg`]: ./struct.ArgGroup.html#method.arg
+    /// [indices]: ./struct.Arg.html#method.index
+    /// [Default values]: ./struct.Arg.html#method.default_value
+    /// [possible values]: ./struct.Arg.html#method.possible_values
+    /// [Argument aliases]: ./struct.Arg.html#method.alias
+    /// [subcommand aliases]: ./struct.App.html#method.alias
+    /// [`ErrorKind::InvalidDefinition`]: ./enum.ErrorKind.html#variant.InvalidDefinition
+    /// [`ContextKind::DefinitionPath`]: ./enum.ContextKind.html#variant.DefinitionPath
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    pub fn validate_definition(&self) -> ClapResult<()> {
+        let mut problems = vec![];
+        checks::definition_problems(&self.p, "", &[], &mut problems);
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::definition_problems(problems))
+        }
+    }
+
+    /// Sets the [`MessageCatalog`] used for the error messages, help headings and generated help
+    /// descriptions of this `App`, and of all its subcommands which don't have a catalog of their
+    /// own. Without a catalog, `clap` uses its built-in English messages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, MessageCatalog};
+    /// struct Japanese;
+    ///
+    /// impl MessageCatalog for Japanese {
+    ///     fn error_label(&self) -> &str { "エラー:" }
+    ///     fn usage_heading(&self) -> &str { "使い方:" }
+    ///     fn flags_heading(&self) -> &str { "フラグ:" }
+    ///     fn help_description(&self) -> &'static str { "ヘルプを表示します" }
+    ///     fn version_description(&self) -> &'static str { "バージョンを表示します" }
+    /// }
+    ///
+    /// let res = App::new("myprog")
+    ///     .catalog(Japanese)
+    ///     .get_matches_from_safe(vec!["myprog", "--help"]);
+    /// let help = res.unwrap_err().message();
+    ///
+    /// assert!(help.contains("使い方:"));
+    /// assert!(help.contains("-h, --help       ヘルプを表示します"));
+    /// ```
+    /// [`MessageCatalog`]: ./trait.MessageCatalog.html
+    pub fn catalog<C: MessageCatalog + 'static>(mut self, catalog: C) -> Self {
+        self.p.catalog = Some(Arc::new(catalog));
+        self
+    }
+
     /// Adds a [`SubCommand`] to the list of valid possibilities. Subcommands are effectively
     /// sub-[`App`]s, because they can contain their own arguments, subcommands, version, usage,
     /// etc. They also function just like [`App`]s, in that they get their own auto generated help,
@@ -1133,6 +1641,46 @@ impl<'a, 'b> App<'a, 'b> {
         self
     }
 
+    /// Sets the heading of the section of the parent's help message which this [`SubCommand`]
+    /// is displayed in, instead of the default `SUBCOMMANDS` section. This allows putting
+    /// subcommands into categories, such as "PORCELAIN COMMANDS" and "PLUMBING COMMANDS". Each
+    /// heading becomes its own section, and sections are displayed in the order their first
+    /// subcommand was added, after the default section. Hidden subcommands and
+    /// [`App::display_order`] are respected within each section.
+    ///
+    /// Like [`Arg::help_heading`], the subcommands of a section can be placed in a
+    /// [help template] with the `{section:<heading>}` tag.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, SubCommand};
+    /// let res = App::new("git")
+    ///     .subcommand(SubCommand::with_name("commit")
+    ///         .help_heading("PORCELAIN COMMANDS")
+    ///         .about("Record changes"))
+    ///     .subcommand(SubCommand::with_name("cat-file")
+    ///         .help_heading("PLUMBING COMMANDS")
+    ///         .about("Show object contents"))
+    ///     .subcommand(SubCommand::with_name("add")
+    ///         .help_heading("PORCELAIN COMMANDS")
+    ///         .about("Add file contents"))
+    ///     .get_matches_from_safe(vec!["git", "--help"]);
+    ///
+    /// let help = res.unwrap_err().message();
+    /// assert!(help.contains("PORCELAIN COMMANDS:\n    add       Add file contents\n    \
+    ///                        commit    Record changes"));
+    /// assert!(help.contains("PLUMBING COMMANDS:\n    cat-file    Show object contents"));
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`App::display_order`]: ./struct.App.html#method.display_order
+    /// [`Arg::help_heading`]: ./struct.Arg.html#method.help_heading
+    /// [help template]: ./struct.App.html#method.template
+    pub fn help_heading(mut self, heading: &'b str) -> Self {
+        self.p.meta.help_heading = Some(heading);
+        self
+    }
+
     /// Prints the full help message to [`io::stdout()`] using a [`BufWriter`] using the same
     /// method as if someone ran `-h` to request the help message
     ///
@@ -1379,7 +1927,8 @@ impl<'a, 'b> App<'a, 'b> {
     /// Assuming we compiled with debug mode, it would be somewhere similar to
     /// `<project>/target/debug/build/myapp-<hash>/out/myapp.bash-completion`.
     ///
-    /// Fish shell completions will use the file format `{bin_name}.fish`
+    /// Fish shell completions will use the file format `{bin_name}.fish`, and Elvish completions
+    /// will use `{bin_name}.elv`
     pub fn gen_completions<T: Into<OsString>, S: Into<String>>(&mut self,
                                                                bin_name: S,
                                                                for_shell: Shell,
@@ -1429,6 +1978,245 @@ impl<'a, 'b> App<'a, 'b> {
         self.p.gen_completions_to(for_shell, buf);
     }
 
+    /// Generate a roff `man(1)` page for the application at compile time, in the same manner as
+    /// [`App::gen_completions`]. The page is written to `{bin_name}.1` in `out_dir`.
+    ///
+    /// The page is built from the application's name, version, author, [`App::about`] (or
+    /// [`App::long_about`]), every non-hidden argument's [`Arg::long_help`] (or [`Arg::help`]),
+    /// possible values and default values, along with one section per subcommand.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// // build.rs
+    /// extern crate clap;
+    ///
+    /// include!("src/cli.rs");
+    ///
+    /// fn main() {
+    ///     let outdir = match env::var_os("OUT_DIR") {
+    ///         None => return,
+    ///         Some(outdir) => outdir,
+    ///     };
+    ///     let mut app = build_cli();
+    ///     app.gen_manpage("myapp", outdir);
+    /// }
+    /// ```
+    /// [`App::gen_completions`]: ./struct.App.html#method.gen_completions
+    /// [`App::about`]: ./struct.App.html#method.about
+    /// [`App::long_about`]: ./struct.App.html#method.long_about
+    /// [`Arg::long_help`]: ./struct.Arg.html#method.long_help
+    /// [`Arg::help`]: ./struct.Arg.html#method.help
+    pub fn gen_manpage<T: Into<OsString>, S: Into<String>>(&mut self, bin_name: S, out_dir: T) {
+        self.p.meta.bin_name = Some(bin_name.into());
+        self.p.gen_manpage(out_dir.into());
+    }
+
+    /// Generate a roff `man(1)` page for the application and write it to `buf`. See
+    /// [`App::gen_manpage`] for details on what the page contains.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let mut buf = Vec::new();
+    /// App::new("myapp")
+    ///     .version("1.0")
+    ///     .about("Does awesome things")
+    ///     .arg(Arg::with_name("config")
+    ///         .long("config")
+    ///         .takes_value(true)
+    ///         .help("Sets a custom config file"))
+    ///     .gen_manpage_to("myapp", &mut buf);
+    ///
+    /// let page = String::from_utf8(buf).unwrap();
+    /// assert!(page.starts_with(".TH \"MYAPP\" \"1\""));
+    /// assert!(page.contains("\\fB\\-\\-config\\fR"));
+    /// ```
+    /// [`App::gen_manpage`]: ./struct.App.html#method.gen_manpage
+    pub fn gen_manpage_to<W: Write, S: Into<String>>(&mut self, bin_name: S, buf: &mut W) {
+        self.p.meta.bin_name = Some(bin_name.into());
+        self.p.gen_manpage_to(buf);
+    }
+
+    /// Generate a Markdown reference for the application and all of its subcommands, and write
+    /// it to `buf`, i.e. to publish it along with the rest of the documentation of a project.
+    ///
+    /// Each (non-hidden) subcommand gets its own section, preceded by an anchor named after its
+    /// full path (i.e. `myapp-test` for `myapp test`) which the tables of subcommands link to.
+    /// The sections contain the [`App::long_about`] (or [`App::about`]), the usage and tables of
+    /// the non-hidden arguments with their [`Arg::long_help`] (or [`Arg::help`]), default
+    /// values and possible values. Arguments with an [`Arg::help_heading`] get a table of their
+    /// own.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, SubCommand};
+    /// let mut buf = Vec::new();
+    /// App::new("myapp")
+    ///     .version("1.0")
+    ///     .arg(Arg::with_name("color")
+    ///         .long("color")
+    ///         .possible_values(&["always", "never"])
+    ///         .default_value("always")
+    ///         .help("Colors the output"))
+    ///     .subcommand(SubCommand::with_name("test").about("Tests things"))
+    ///     .gen_markdown_to("myapp", &mut buf);
+    ///
+    /// let doc = String::from_utf8(buf).unwrap();
+    /// assert!(doc.starts_with("<a id=\"myapp\"></a>\n# myapp 1.0\n"));
+    /// assert!(doc.contains("| `--color <color>` | Colors the output | `always` | `always`, \
+    ///                       `never` |"));
+    /// assert!(doc.contains("| [`test`](#myapp-test) | Tests things |"));
+    /// ```
+    /// [`App::about`]: ./struct.App.html#method.about
+    /// [`App::long_about`]: ./struct.App.html#method.long_about
+    /// [`Arg::long_help`]: ./struct.Arg.html#method.long_help
+    /// [`Arg::help`]: ./struct.Arg.html#method.help
+    /// [`Arg::help_heading`]: ./struct.Arg.html#method.help_heading
+    pub fn gen_markdown_to<W: Write, S: Into<String>>(&mut self, bin_name: S, buf: &mut W) {
+        self.p.meta.bin_name = Some(bin_name.into());
+        self.p.gen_markdown_to(buf);
+    }
+
+    /// Generate an HTML reference for the application and all of its subcommands, and write it to
+    /// `buf`. The reference is a fragment (nested `<section>` elements) meant to be embedded in a
+    /// page, and has the same contents as the one written by [`App::gen_markdown_to`], with the
+    /// `id` of each section being its anchor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, SubCommand};
+    /// let mut buf = Vec::new();
+    /// App::new("myapp")
+    ///     .arg(Arg::with_name("input").help("The <input> file"))
+    ///     .subcommand(SubCommand::with_name("test").about("Tests things"))
+    ///     .gen_html_to("myapp", &mut buf);
+    ///
+    /// let doc = String::from_utf8(buf).unwrap();
+    /// assert!(doc.starts_with("<section id=\"myapp\">\n<h1>myapp</h1>\n"));
+    /// assert!(doc.contains("<td><code>&lt;input&gt;</code></td><td>The &lt;input&gt; file</td>"));
+    /// assert!(doc.contains("<section id=\"myapp-test\">\n<h2>myapp test</h2>\n"));
+    /// ```
+    /// [`App::gen_markdown_to`]: ./struct.App.html#method.gen_markdown_to
+    pub fn gen_html_to<W: Write, S: Into<String>>(&mut self, bin_name: S, buf: &mut W) {
+        self.p.meta.bin_name = Some(bin_name.into());
+        self.p.gen_html_to(buf);
+    }
+
+    /// Writes the definition of the application as a JSON document to `buf`, so that tools which
+    /// aren't written in Rust (such as documentation generators, GUI front-ends or completion
+    /// engines) can use the shape of the CLI. The document can be read back with
+    /// [`App::from_json`]. Requires the `json` cargo feature.
+    ///
+    /// The document is an object describing the application, which has the following keys:
+    ///
+    /// * `schema_version`: The version of the schema, currently `1`. It only changes when keys are
+    /// removed or change their meaning, new keys may be added at any time. Only present at the
+    /// top level.
+    /// * `name`, `bin_name`, `version`, `long_version`, `author`, `about`, `long_about`,
+    /// `before_help`, `after_help`, `help_heading`: Strings, or `null` if they weren't set
+    /// * `usage`: The usage string, without the `USAGE:` heading
+    /// * `aliases`: A list of `{"name": string, "visible": bool}` objects
+    /// * `settings`: The names of the [`AppSettings`] which are set
+    /// * `args`: A list of argument objects, flags first, then options and positional arguments
+    /// * `groups`: A list of `{"name", "args", "required", "multiple", "min_args", "max_args",
+    /// "requires", "conflicts_with"}` objects. `min_args` and `max_args` are numbers, or `null` if
+    /// they weren't set.
+    /// * `subcommands`: A list of objects describing each subcommand, with the same keys as the
+    /// application (except `schema_version`), and a `generated` key which is `true` for the
+    /// generated `help` subcommand
+    ///
+    /// Argument objects have the following keys:
+    ///
+    /// * `name`: The name of the argument. The generated help and version flags are called `help`
+    /// and `version`.
+    /// * `generated`: Whether the argument is the generated help or version flag
+    /// * `kind`: One of `"flag"`, `"option"` or `"positional"`
+    /// * `short`, `long`, `help`, `long_help`, `help_heading`, `default_value`, `env`,
+    /// `value_delimiter`, `value_terminator`: Strings, or `null` if they weren't set. `env` is the
+    /// name of the variable.
+    /// * `number_of_values`, `min_values`, `max_values`: Numbers, or `null` if they weren't set
+    /// * `index`: The index of a positional argument, only present for positional arguments
+    /// * `aliases`: A list of `{"name": string, "visible": bool}` objects
+    /// * `settings`: The names of the [`ArgSettings`] which are set
+    /// * `value_names`, `possible_values`, `required_unless`, `conflicts_with`, `overrides_with`:
+    /// Lists of strings
+    /// * `value_hint`: The name of the argument's [`ValueHint`]
+    /// * `requires`: A list of `{"arg": string, "value": string or null}` objects, where `value` is
+    /// the value of this argument which makes `arg` required (see [`Arg::requires_if`])
+    /// * `default_value_ifs`: A list of `{"arg": string, "value": string or null, "default":
+    /// string}` objects, see [`Arg::default_value_if`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let mut buf = Vec::new();
+    /// App::new("myapp")
+    ///     .version("1.0")
+    ///     .arg(Arg::with_name("config")
+    ///         .long("config")
+    ///         .takes_value(true)
+    ///         .help("Sets a custom config file"))
+    ///     .gen_json_to("myapp", &mut buf);
+    ///
+    /// let json = String::from_utf8(buf).unwrap();
+    /// assert!(json.contains(r#""schema_version": 1"#));
+    /// assert!(json.contains(r#""long": "config""#));
+    /// ```
+    /// [`AppSettings`]: ./enum.AppSettings.html
+    /// [`ArgSettings`]: ./enum.ArgSettings.html
+    /// [`ValueHint`]: ./enum.ValueHint.html
+    /// [`Arg::requires_if`]: ./struct.Arg.html#method.requires_if
+    /// [`Arg::default_value_if`]: ./struct.Arg.html#method.default_value_if
+    /// [`App::from_json`]: ./struct.App.html#method.from_json
+    #[cfg(feature = "json")]
+    pub fn gen_json_to<W: Write, S: Into<String>>(&mut self, bin_name: S, buf: &mut W) {
+        self.p.meta.bin_name = Some(bin_name.into());
+        self.p.gen_json_to(buf);
+    }
+
+    /// Generate a small completion script for the specified shell which completes the
+    /// application by calling the binary itself at runtime. Unlike the scripts from
+    /// [`App::gen_completions_to`] this allows completing values which are only known at runtime,
+    /// using [`Arg::completer`]. The application must use [`AppSettings::DynamicCompletion`].
+    ///
+    /// The script sets the `_CLAP_COMPLETE_INDEX` environment variable to the index of the word
+    /// being completed, and calls the binary with the words of the command line. When no
+    /// candidates are found, the shell falls back to completing file names (except for fish).
+    ///
+    /// **NOTE:** Only [`Shell::Bash`], [`Shell::Zsh`] and [`Shell::Fish`] are supported, this
+    /// method panics for any other shell.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, Shell};
+    /// let mut buf = Vec::new();
+    /// App::new("myapp")
+    ///     .setting(AppSettings::DynamicCompletion)
+    ///     .gen_dynamic_completions_to("myapp", Shell::Bash, &mut buf);
+    ///
+    /// let script = String::from_utf8(buf).unwrap();
+    /// assert!(script.contains("complete -F _myapp"));
+    /// ```
+    /// [`App::gen_completions_to`]: ./struct.App.html#method.gen_completions_to
+    /// [`Arg::completer`]: ./struct.Arg.html#method.completer
+    /// [`AppSettings::DynamicCompletion`]: ./enum.AppSettings.html#variant.DynamicCompletion
+    /// [`Shell::Bash`]: ./enum.Shell.html#variant.Bash
+    /// [`Shell::Zsh`]: ./enum.Shell.html#variant.Zsh
+    /// [`Shell::Fish`]: ./enum.Shell.html#variant.Fish
+    pub fn gen_dynamic_completions_to<W: Write, S: Into<String>>(&mut self,
+                                                                 bin_name: S,
+                                                                 for_shell: Shell,
+                                                                 buf: &mut W) {
+        self.p.meta.bin_name = Some(bin_name.into());
+        DynamicGen::new(&self.p).generate(for_shell, buf);
+    }
+
     /// Starts the parsing process, upon a failed parse an error will be displayed to the user and
     /// the process will exit with the appropriate error code. By default this method gets all user
     /// provided arguments from [`env::args_os`] in order to allow for invalid UTF-8 code points,
@@ -1503,7 +2291,7 @@ impl<'a, 'b> App<'a, 'b> {
         self.get_matches_from_safe_borrow(itr).unwrap_or_else(|e| {
             // Otherwise, write to stderr and exit
             if e.use_stderr() {
-                wlnerr!("{}", e.message);
+                wlnerr!("{}", e.message());
                 if self.p.is_set(AppSettings::WaitOnError) {
                     wlnerr!("\nPress [ENTER] / [RETURN] to continue...");
                     let mut s = String::new();
@@ -1584,6 +2372,15 @@ impl<'a, 'b> App<'a, 'b> {
         where I: IntoIterator<Item = T>,
               T: Into<OsString> + Clone
     {
+        if self.p.is_set(AppSettings::DynamicCompletion) {
+            if let Some(index) = env::var("_CLAP_COMPLETE_INDEX")
+                   .ok()
+                   .and_then(|i| i.parse().ok()) {
+                let candidates = self.get_dynamic_completions_from(itr, index);
+                return Err(Error::completions_displayed(candidates));
+            }
+        }
+
         // If there are global arguments, or settings we need to propgate them down to subcommands
         // before parsing incase we run into a subcommand
         self.p.propogate_globals();
@@ -1616,7 +2413,7 @@ impl<'a, 'b> App<'a, 'b> {
 
         // do the real parsing
         if let Err(e) = self.p.get_matches_with(&mut matcher, &mut it.peekable()) {
-            return Err(e);
+            return Err(e.render_message(self.p.color(), self.p.catalog.as_ref()));
         }
 
         if self.p.is_set(AppSettings::PropagateGlobalValuesDown) {
@@ -1627,6 +2424,43 @@ impl<'a, 'b> App<'a, 'b> {
 
         Ok(matcher.into())
     }
+
+    /// Gets the completion candidates for the word at `index` in `itr` (where the binary name is
+    /// index `0`), in the same manner as [`AppSettings::DynamicCompletion`]. Only the words before
+    /// `index` are parsed, so the candidates depend on the subcommand and any option waiting for a
+    /// value. If `index` is past the end of `itr` an empty word is completed. If the words before
+    /// `index` can't be parsed, no candidates are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, SubCommand};
+    /// let mut app = App::new("myprog")
+    ///     .arg(Arg::with_name("verbose").long("verbose"))
+    ///     .subcommand(SubCommand::with_name("test"));
+    ///
+    /// assert_eq!(app.get_dynamic_completions_from(vec!["myprog", "--verb"], 1), &["--verbose"]);
+    /// assert_eq!(app.get_dynamic_completions_from(vec!["myprog"], 1), &["test", "help"]);
+    /// ```
+    /// [`AppSettings::DynamicCompletion`]: ./enum.AppSettings.html#variant.DynamicCompletion
+    pub fn get_dynamic_completions_from<I, T>(&mut self, itr: I, index: usize) -> Vec<String>
+        where I: IntoIterator<Item = T>,
+              T: Into<OsString> + Clone
+    {
+        let mut args: Vec<OsString> = itr.into_iter().map(|a| a.into()).collect();
+        let current = args.get(index).cloned().unwrap_or_else(OsString::new);
+        args.truncate(index);
+
+        let mut app = self.clone();
+        app.p.unset(AppSettings::DynamicCompletion);
+        app.p.completing = Some(current);
+        match app.get_matches_from_safe_borrow(args) {
+            Err(Error { kind: ErrorKind::CompletionsDisplayed, info: Some(candidates), .. }) => {
+                candidates
+            }
+            _ => vec![],
+        }
+    }
 }
 
 #[cfg(feature = "yaml")]
@@ -1662,6 +2496,7 @@ impl<'a> From<&'a Yaml> for App<'a, 'a> {
         yaml_str!(a, yaml, before_help);
         yaml_str!(a, yaml, after_help);
         yaml_str!(a, yaml, template);
+        yaml_str!(a, yaml, arg_template);
         yaml_str!(a, yaml, usage);
         yaml_str!(a, yaml, help);
         yaml_str!(a, yaml, help_short);
@@ -1670,6 +2505,7 @@ impl<'a> From<&'a Yaml> for App<'a, 'a> {
         yaml_str!(a, yaml, version_message);
         yaml_str!(a, yaml, alias);
         yaml_str!(a, yaml, visible_alias);
+        yaml_str!(a, yaml, help_heading);
 
         if let Some(v) = yaml["display_order"].as_i64() {
             a = a.display_order(v as usize);
@@ -1796,6 +2632,8 @@ impl<'n, 'e> AnyArg<'n, 'e> for App<'n, 'e> {
     fn default_vals_ifs(&self) -> Option<vec_map::Values<(&'n str, Option<&'e OsStr>, &'e OsStr)>> {
         None
     }
+    fn env<'s>(&'s self) -> Option<(&'n OsStr, Option<&'s OsString>)> { None }
+    fn value_hint(&self) -> ValueHint { ValueHint::Unknown }
     fn longest_filter(&self) -> bool { true }
     fn aliases(&self) -> Option<Vec<&'e str>> {
         if let Some(ref aliases) = self.p.meta.aliases {
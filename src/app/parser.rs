@@ -1,4 +1,6 @@
 // Std
+use std::ascii::AsciiExt;
+use std::cell::Cell;
 use std::ffi::{OsStr, OsString};
 use std::fmt::Display;
 use std::fs::File;
@@ -6,8 +8,10 @@ use std::io::{self, BufWriter, Write};
 #[cfg(feature = "debug")]
 use std::os::unix::ffi::OsStrExt;
 use std::path::PathBuf;
+use std::rc::Rc;
 use std::slice::Iter;
 use std::iter::Peekable;
+use std::sync::Arc;
 
 // Third Party
 use vec_map::{self, VecMap};
@@ -20,9 +24,14 @@ use app::App;
 use app::help::Help;
 use app::meta::AppMeta;
 use app::settings::AppFlags;
-use args::{AnyArg, ArgMatcher, Base, Switched, Arg, ArgGroup, FlagBuilder, OptBuilder, PosBuilder};
+use args::{AnyArg, ArgMatcher, ArgMatches, Base, Switched, Arg, ArgGroup, FlagBuilder,
+           OptBuilder, PosBuilder, ValueSource};
 use args::settings::ArgSettings;
-use completions::ComplGen;
+use catalog::{self, MessageCatalog};
+use completions::{dynamic, ComplGen, HtmlGen, ManGen, MarkdownGen};
+#[cfg(feature = "json")]
+use completions::JsonGen;
+use config::ConfigSource;
 use errors::{Error, ErrorKind};
 use errors::Result as ClapResult;
 use fmt::ColorWhen;
@@ -59,6 +68,7 @@ pub struct Parser<'a, 'b>
     pub positionals: VecMap<PosBuilder<'a, 'b>>,
     pub subcommands: Vec<App<'a, 'b>>,
     pub groups: Vec<ArgGroup<'a>>,
+    pub help_headings: Vec<&'b str>,
     pub global_args: Vec<Arg<'a, 'b>>,
     pub required: Vec<&'a str>,
     pub r_ifs: Vec<(&'a str, &'b str, &'a str)>,
@@ -69,6 +79,13 @@ pub struct Parser<'a, 'b>
     cache: Option<&'a str>,
     pub help_message: Option<&'a str>,
     pub version_message: Option<&'a str>,
+    cur_idx: Cell<usize>,
+    pub completing: Option<OsString>,
+    pub configs: Vec<ConfigSource>,
+    inherited_configs: Vec<ConfigSource>,
+    pub catalog: Option<Arc<MessageCatalog>>,
+    pub keys: Option<Vec<&'a str>>,
+    pub validators: Vec<Rc<Fn(&ArgMatches) -> Result<(), String>>>,
 }
 
 impl<'a, 'b> Parser<'a, 'b>
@@ -98,14 +115,7 @@ impl<'a, 'b> Parser<'a, 'b>
     }
 
     pub fn gen_completions_to<W: Write>(&mut self, for_shell: Shell, buf: &mut W) {
-        if !self.is_set(AS::Propogated) {
-            self.propogate_help_version();
-            self.build_bin_names();
-            self.propogate_globals();
-            self.propogate_settings();
-            self.set(AS::Propogated);
-        }
-
+        self.propogate_all();
         ComplGen::new(self).generate(for_shell, buf)
     }
 
@@ -119,6 +129,7 @@ impl<'a, 'b> Parser<'a, 'b>
             Shell::Fish => format!("{}.fish", name),
             Shell::Zsh => format!("_{}", name),
             Shell::PowerShell => format!("_{}.ps1", name),
+            Shell::Elvish => format!("{}.elv", name),
         };
 
         let mut file = match File::create(out_dir.join(file_name)) {
@@ -128,6 +139,40 @@ impl<'a, 'b> Parser<'a, 'b>
         self.gen_completions_to(for_shell, &mut file)
     }
 
+    pub fn gen_manpage_to<W: Write>(&mut self, buf: &mut W) {
+        self.propogate_all();
+        ManGen::new(self).generate_to(buf)
+    }
+
+    pub fn gen_markdown_to<W: Write>(&mut self, buf: &mut W) {
+        self.propogate_all();
+        MarkdownGen::new(self).generate_to(buf)
+    }
+
+    pub fn gen_html_to<W: Write>(&mut self, buf: &mut W) {
+        self.propogate_all();
+        HtmlGen::new(self).generate_to(buf)
+    }
+
+    #[cfg(feature = "json")]
+    pub fn gen_json_to<W: Write>(&mut self, buf: &mut W) {
+        self.propogate_all();
+        JsonGen::new(self).generate_to(buf)
+    }
+
+    pub fn gen_manpage(&mut self, od: OsString) {
+        use std::error::Error;
+
+        let out_dir = PathBuf::from(od);
+        let file_name = format!("{}.1", self.meta.bin_name.as_ref().unwrap());
+
+        let mut file = match File::create(out_dir.join(file_name)) {
+            Err(why) => panic!("couldn't create man page: {}", why.description()),
+            Ok(file) => file,
+        };
+        self.gen_manpage_to(&mut file)
+    }
+
     #[inline]
     fn app_debug_asserts(&mut self) -> bool {
         assert!(self.verify_positionals());
@@ -171,6 +216,86 @@ impl<'a, 'b> Parser<'a, 'b>
         true
     }
 
+    // Checks the arguments against the keys declared with `App::arg_keys`, if any. Unlike the
+    // debug asserts this also runs in release builds, as it's opted into.
+    fn verify_keys(&self) {
+        let keys = match self.keys {
+            Some(ref k) => k,
+            None => return,
+        };
+        debugln!("Parser::verify_keys: keys={:?}", keys);
+        let exists = |n: &str| {
+            arg_names!(self).any(|a| a == n) || self.groups.iter().any(|g| g.name == n)
+        };
+        for k in keys {
+            assert!(exists(k),
+                    "The key '{}' isn't the name of an argument or group",
+                    k);
+        }
+        for name in arg_names!(self) {
+            assert!(keys.contains(&name),
+                    "The argument '{}' isn't one of the keys of the app",
+                    name);
+        }
+
+        for (name, relation, other) in self.relations() {
+            assert!(exists(other),
+                    "'{}' {} '{}', which isn't an argument or group",
+                    name,
+                    relation,
+                    other);
+        }
+    }
+
+    // Every relation between the arguments and groups as (name, relation, other name), i.e.
+    // ("force", "conflicts with", "dry-run")
+    pub fn relations(&self) -> Vec<(&'a str, &'static str, &'a str)> {
+        let mut rels = vec![];
+        let bases = self.flags
+            .iter()
+            .map(|f| &f.b)
+            .chain(self.opts.iter().map(|o| &o.b))
+            .chain(self.positionals.values().map(|p| &p.b));
+        for b in bases {
+            for &n in b.blacklist.iter().flat_map(|v| v) {
+                rels.push((b.name, "conflicts with", n));
+            }
+            for &n in b.overrides.iter().flat_map(|v| v) {
+                rels.push((b.name, "overrides", n));
+            }
+            for &n in b.r_unless.iter().flat_map(|v| v) {
+                rels.push((b.name, "is required unless", n));
+            }
+            for &(_, n) in b.requires.iter().flat_map(|v| v) {
+                rels.push((b.name, "requires", n));
+            }
+        }
+        let valued = self.opts
+            .iter()
+            .map(|o| (o.b.name, &o.v))
+            .chain(self.positionals.values().map(|p| (p.b.name, &p.v)));
+        for (name, v) in valued {
+            for &(n, _, _) in v.default_vals_ifs.iter().flat_map(|vm| vm.values()) {
+                rels.push((name, "has a default value depending on", n));
+            }
+        }
+        for &(n, _, name) in &self.r_ifs {
+            rels.push((name, "is required if", n));
+        }
+        for g in &self.groups {
+            for &n in &g.args {
+                rels.push((g.name, "contains", n));
+            }
+            for &n in g.requires.iter().flat_map(|v| v) {
+                rels.push((g.name, "requires", n));
+            }
+            for &n in g.conflicts.iter().flat_map(|v| v) {
+                rels.push((g.name, "conflicts with", n));
+            }
+        }
+        rels
+    }
+
     #[inline]
     fn debug_asserts(&self, a: &Arg) -> bool {
         assert!(!arg_names!(self).any(|name| name == a.b.name),
@@ -195,6 +320,12 @@ impl<'a, 'b> Parser<'a, 'b>
                     argument\n\n\tPerhaps try .multiple(true) to allow one positional argument \
                     to take multiple values",
                 a.b.name);
+        let takes_value = a.index.is_some() || (a.s.short.is_none() && a.s.long.is_none()) ||
+                          a.is_set(ArgSettings::TakesValue);
+        assert!(takes_value || a.v.value_parser.is_none(),
+                "Argument '{}' has a value parser but doesn't take a value\n\n\tPerhaps try \
+                 .takes_value(true)",
+                a.b.name);
         assert!(!(a.is_set(ArgSettings::Required) && a.is_set(ArgSettings::Global)),
                 "Global arguments cannot be required.\n\n\t'{}' is marked as \
                           global and required",
@@ -257,6 +388,15 @@ impl<'a, 'b> Parser<'a, 'b>
         }
     }
 
+    #[inline]
+    fn add_help_heading(&mut self, a: &Arg<'a, 'b>) {
+        if let Some(h) = a.b.help_heading {
+            if !self.help_headings.contains(&h) {
+                self.help_headings.push(h);
+            }
+        }
+    }
+
     #[inline]
     fn implied_settings(&mut self, a: &Arg<'a, 'b>) {
         if a.is_set(ArgSettings::Last) {
@@ -284,6 +424,7 @@ impl<'a, 'b> Parser<'a, 'b>
         self.add_conditional_reqs(&a);
         self.add_arg_groups(&a);
         self.add_reqs(&a);
+        self.add_help_heading(&a);
         self.implied_settings(&a);
         if a.index.is_some() || (a.s.short.is_none() && a.s.long.is_none()) {
             let i = if a.index.is_none() {
@@ -309,6 +450,7 @@ impl<'a, 'b> Parser<'a, 'b>
         self.add_conditional_reqs(a);
         self.add_arg_groups(a);
         self.add_reqs(a);
+        self.add_help_heading(a);
         self.implied_settings(a);
         if a.index.is_some() || (a.s.short.is_none() && a.s.long.is_none()) {
             let i = if a.index.is_none() {
@@ -351,6 +493,8 @@ impl<'a, 'b> Parser<'a, 'b>
             grp.requires = group.requires.clone();
             grp.conflicts = group.conflicts.clone();
             grp.required = group.required;
+            grp.min_args = group.min_args;
+            grp.max_args = group.max_args;
         } else {
             self.groups.push(group);
         }
@@ -394,6 +538,9 @@ impl<'a, 'b> Parser<'a, 'b>
                 sc.p.g_settings = sc.p.g_settings | self.g_settings;
                 sc.p.meta.term_w = self.meta.term_w;
                 sc.p.meta.max_w = self.meta.max_w;
+                if sc.p.catalog.is_none() {
+                    sc.p.catalog = self.catalog.clone();
+                }
             }
             sc.p.propogate_settings();
         }
@@ -619,6 +766,13 @@ impl<'a, 'b> Parser<'a, 'b>
             {
                 for a in &self.global_args {
                     sc.p.add_arg_ref(a);
+                    // Global args aren't part of the keys of the subcommands they're
+                    // propagated to
+                    if let Some(ref mut keys) = sc.p.keys {
+                        if !keys.contains(&a.b.name) {
+                            keys.push(a.b.name);
+                        }
+                    }
                 }
             }
             sc.p.propogate_globals();
@@ -716,8 +870,7 @@ impl<'a, 'b> Parser<'a, 'b>
                                                               self.meta
                                                                   .bin_name
                                                                   .as_ref()
-                                                                  .unwrap_or(&self.meta.name),
-                                                              self.color()));
+                                                                  .unwrap_or(&self.meta.name)));
                 }
                 bin_name = format!("{} {}", bin_name, &*sc.meta.name);
             }
@@ -725,7 +878,7 @@ impl<'a, 'b> Parser<'a, 'b>
         };
         if help_help {
             let mut pb = PosBuilder::new("subcommand", 1);
-            pb.b.help = Some("The subcommand whose help message to display");
+            pb.b.help = Some(self.catalog().help_subcommand_arg_description());
             pb.set(ArgSettings::Multiple);
             sc.positionals.insert(1, pb);
             sc.settings = sc.settings | self.g_settings;
@@ -813,6 +966,7 @@ impl<'a, 'b> Parser<'a, 'b>
         debugln!("Parser::get_matches_with;");
         // Verify all positional assertions pass
         debug_assert!(self.app_debug_asserts());
+        self.verify_keys();
         if self.positionals
                .values()
                .any(|a| {
@@ -902,9 +1056,8 @@ impl<'a, 'b> Parser<'a, 'b>
                             if !(arg_os.to_string_lossy().parse::<i64>().is_ok() ||
                                  arg_os.to_string_lossy().parse::<f64>().is_ok()) {
                                 return Err(Error::unknown_argument(&*arg_os.to_string_lossy(),
-                                "",
-                                &*usage::create_error_usage(self, matcher, None),
-                                self.color()));
+                                None,
+                                &*usage::create_error_usage(self, matcher, None)));
                             }
                         }
                         ParseResult::Opt(..) |
@@ -928,8 +1081,7 @@ impl<'a, 'b> Parser<'a, 'b>
                                                                  .unwrap_or(&self.meta.name),
                                                              &*usage::create_error_usage(self,
                                                                                          matcher,
-                                                                                         None),
-                                                             self.color()));
+                                                                                         None)));
                     }
                 }
             }
@@ -975,11 +1127,10 @@ impl<'a, 'b> Parser<'a, 'b>
             if let Some(p) = self.positionals.get(pos_counter) {
                 if p.is_set(ArgSettings::Last) && !self.is_set(AS::TrailingValues) {
                     return Err(Error::unknown_argument(&*arg_os.to_string_lossy(),
-                                                       "",
+                                                       None,
                                                        &*usage::create_error_usage(self,
                                                                                    matcher,
-                                                                                   None),
-                                                       self.color()));
+                                                                                   None)));
                 }
                 parse_positional!(self, p, arg_os, pos_counter, matcher);
                 self.settings.set(AS::ValidArgFound);
@@ -991,8 +1142,7 @@ impl<'a, 'b> Parser<'a, 'b>
                         if !self.is_set(AS::StrictUtf8) {
                             return Err(Error::invalid_utf8(&*usage::create_error_usage(self,
                                                                                        matcher,
-                                                                                       None),
-                                                           self.color()));
+                                                                                       None)));
                         }
                         arg_os.to_string_lossy().into_owned()
                     }
@@ -1005,8 +1155,7 @@ impl<'a, 'b> Parser<'a, 'b>
                     if a.to_str().is_none() && !self.is_set(AS::StrictUtf8) {
                         return Err(Error::invalid_utf8(&*usage::create_error_usage(self,
                                                                                    matcher,
-                                                                                   None),
-                                                       self.color()));
+                                                                                   None)));
                     }
                     sc_m.add_val_to("", &a);
                 }
@@ -1019,11 +1168,10 @@ impl<'a, 'b> Parser<'a, 'b>
                         self.is_set(AS::AllowNegativeNumbers)) &&
                       !self.is_set(AS::InferSubcommands) {
                 return Err(Error::unknown_argument(&*arg_os.to_string_lossy(),
-                                                   "",
+                                                   None,
                                                    &*usage::create_error_usage(self,
                                                                                matcher,
-                                                                               None),
-                                                   self.color()));
+                                                                               None)));
             } else if !has_args || self.is_set(AS::InferSubcommands) && self.has_subcommands() {
                 if let Some(cdate) = suggestions::did_you_mean(&*arg_os.to_string_lossy(),
                                                                sc_names!(self)) {
@@ -1035,8 +1183,7 @@ impl<'a, 'b> Parser<'a, 'b>
                                                              .unwrap_or(&self.meta.name),
                                                          &*usage::create_error_usage(self,
                                                                                      matcher,
-                                                                                     None),
-                                                         self.color()));
+                                                                                     None)));
                 } else {
                     return Err(Error::unrecognized_subcommand(arg_os
                                                                   .to_string_lossy()
@@ -1044,12 +1191,22 @@ impl<'a, 'b> Parser<'a, 'b>
                                                               self.meta
                                                                   .bin_name
                                                                   .as_ref()
-                                                                  .unwrap_or(&self.meta.name),
-                                                              self.color()));
+                                                                  .unwrap_or(&self.meta.name)));
                 }
             }
         }
 
+        if subcmd_name.is_none() {
+            if let Some(ref current) = self.completing {
+                debugln!("Parser::get_matches_with: completing {:?}", current);
+                return Err(Error::completions_displayed(dynamic::candidates(self,
+                                                                            matcher,
+                                                                            needs_val_of,
+                                                                            pos_counter,
+                                                                            current)));
+            }
+        }
+
         if let Some(ref pos_sc_name) = subcmd_name {
             let sc_name = {
                 find_subcmd!(self, pos_sc_name)
@@ -1063,23 +1220,32 @@ impl<'a, 'b> Parser<'a, 'b>
         } else if self.is_set(AS::SubcommandRequired) {
             let bn = self.meta.bin_name.as_ref().unwrap_or(&self.meta.name);
             return Err(Error::missing_subcommand(bn,
-                                                 &usage::create_error_usage(self, matcher, None),
-                                                 self.color()));
+                                                 &usage::create_error_usage(self, matcher, None)));
         } else if self.is_set(AS::SubcommandRequiredElseHelp) {
             debugln!("Parser::get_matches_with: SubcommandRequiredElseHelp=true");
             let mut out = vec![];
             try!(self.write_help_err(&mut out));
-            return Err(Error {
-                           message: String::from_utf8_lossy(&*out).into_owned(),
-                           kind: ErrorKind::MissingArgumentOrSubcommand,
-                           info: None,
-                       });
+            return Err(Error::raw(ErrorKind::MissingArgumentOrSubcommand,
+                                  String::from_utf8_lossy(&*out)));
         }
 
         Validator::new(self).validate(needs_val_of, subcmd_name, matcher)
     }
 
 
+    // Does everything the generators need to be done to the app and its subcommands before they
+    // can be written out, unless it was already done
+    fn propogate_all(&mut self) {
+        debugln!("Parser::propogate_all;");
+        if !self.is_set(AS::Propogated) {
+            self.propogate_help_version();
+            self.build_bin_names();
+            self.propogate_globals();
+            self.propogate_settings();
+            self.set(AS::Propogated);
+        }
+    }
+
     fn propogate_help_version(&mut self) {
         debugln!("Parser::propogate_help_version;");
         self.create_help_and_version();
@@ -1167,7 +1333,16 @@ impl<'a, 'b> Parser<'a, 'b>
             debugln!("Parser::parse_subcommand: About to parse sc={}",
                      sc.p.meta.name);
             debugln!("Parser::parse_subcommand: sc settings={:#?}", sc.p.settings);
-            try!(sc.p.get_matches_with(&mut sc_matcher, it));
+            sc.p.completing = self.completing.clone();
+            sc.p.inherited_configs = self.inherited_configs
+                .iter()
+                .chain(self.configs.iter())
+                .filter_map(|c| c.subcommand_source(&*sc.p.meta.name))
+                .cloned()
+                .collect();
+            try!(sc.p
+                     .get_matches_with(&mut sc_matcher, it)
+                     .map_err(|e| e.render_message(sc.p.color(), sc.p.catalog.as_ref())));
             matcher.subcommand(SubCommand {
                                    name: sc.p.meta.name.clone(),
                                    matches: sc_matcher.into(),
@@ -1258,7 +1433,7 @@ impl<'a, 'b> Parser<'a, 'b>
             let arg = FlagBuilder {
                 b: Base {
                     name: "hclap_help",
-                    help: self.help_message.or(Some("Prints help information")),
+                    help: self.help_message.or(Some(self.catalog().help_description())),
                     ..Default::default()
                 },
                 s: Switched {
@@ -1278,7 +1453,7 @@ impl<'a, 'b> Parser<'a, 'b>
             let arg = FlagBuilder {
                 b: Base {
                     name: "vclap_version",
-                    help: self.version_message.or(Some("Prints version information")),
+                    help: self.version_message.or(Some(self.catalog().version_description())),
                     ..Default::default()
                 },
                 s: Switched {
@@ -1292,9 +1467,8 @@ impl<'a, 'b> Parser<'a, 'b>
         if !self.subcommands.is_empty() && !self.is_set(AS::DisableHelpSubcommand) &&
            self.is_set(AS::NeedsSubcommandHelp) {
             debugln!("Parser::create_help_and_version: Building help");
-            self.subcommands
-                .push(App::new("help")
-                          .about("Prints this message or the help of the given subcommand(s)"));
+            let about = self.catalog().help_subcommand_description();
+            self.subcommands.push(App::new("help").about(about));
         }
     }
 
@@ -1355,11 +1529,7 @@ impl<'a, 'b> Parser<'a, 'b>
         let mut buf = vec![];
         match Help::write_parser_help(&mut buf, self, use_long) {
             Err(e) => e,
-            _ => Error {
-                message: unsafe { String::from_utf8_unchecked(buf) },
-                kind: ErrorKind::HelpDisplayed,
-                info: None,
-            }
+            _ => Error::raw(ErrorKind::HelpDisplayed, unsafe { String::from_utf8_unchecked(buf) }),
         }
     }
 
@@ -1369,11 +1539,7 @@ impl<'a, 'b> Parser<'a, 'b>
         let mut buf_w = BufWriter::new(out.lock());
         match self.print_version(&mut buf_w, use_long) {
             Err(e) => e,
-            _ => Error {
-                message: String::new(),
-                kind: ErrorKind::VersionDisplayed,
-                info: None,
-            }
+            _ => Error::raw(ErrorKind::VersionDisplayed, String::new()),
         }
     }
 
@@ -1383,6 +1549,9 @@ impl<'a, 'b> Parser<'a, 'b>
                       -> ClapResult<ParseResult<'a>> {
         // maybe here lifetime should be 'a
         debugln!("Parser::parse_long_arg;");
+
+        // Update the current index
+        self.cur_idx.set(self.cur_idx.get() + 1);
         let mut val = None;
         debug!("Parser::parse_long_arg: Does it contain '='...");
         let arg = if full_arg.contains_byte(b'=') {
@@ -1461,6 +1630,10 @@ impl<'a, 'b> Parser<'a, 'b>
         let mut ret = ParseResult::NotFound;
         for c in arg.chars() {
             debugln!("Parser::parse_short_arg:iter:{}", c);
+
+            // update each index because `-abcd` is four indices to clap
+            self.cur_idx.set(self.cur_idx.get() + 1);
+
             // Check for matching short options, and return the name if there is no trailing
             // concatenated value: -oval
             // Option: -o
@@ -1510,11 +1683,10 @@ impl<'a, 'b> Parser<'a, 'b>
             } else {
                 let arg = format!("-{}", c);
                 return Err(Error::unknown_argument(&*arg,
-                                                   "",
+                                                   None,
                                                    &*usage::create_error_usage(self,
                                                                                matcher,
-                                                                               None),
-                                                   self.color()));
+                                                                               None)));
             }
         }
         Ok(ret)
@@ -1538,8 +1710,7 @@ impl<'a, 'b> Parser<'a, 'b>
                (v.len_() == 0 || (opt.is_set(ArgSettings::RequireEquals) && !has_eq)) {
                 sdebugln!("Found Empty - Error");
                 return Err(Error::empty_value(opt,
-                                              &*usage::create_error_usage(self, matcher, None),
-                                              self.color()));
+                                              &*usage::create_error_usage(self, matcher, None)));
             }
             sdebugln!("Found - {:?}, len: {}", v, v.len_());
             debugln!("Parser::parse_opt: {:?} contains '='...{:?}",
@@ -1549,8 +1720,7 @@ impl<'a, 'b> Parser<'a, 'b>
         } else if opt.is_set(ArgSettings::RequireEquals) && !opt.is_set(ArgSettings::EmptyValues) {
             sdebugln!("None, but requires equals...Error");
             return Err(Error::empty_value(opt,
-                                          &*usage::create_error_usage(self, matcher, None),
-                                          self.color()));
+                                          &*usage::create_error_usage(self, matcher, None)));
 
         } else {
             sdebugln!("None");
@@ -1616,17 +1786,33 @@ impl<'a, 'b> Parser<'a, 'b>
     {
         debugln!("Parser::add_single_val_to_arg;");
         debugln!("Parser::add_single_val_to_arg: adding val...{:?}", v);
+
+        // update the current index because each value is a distinct index to clap
+        self.cur_idx.set(self.cur_idx.get() + 1);
+
         if let Some(t) = arg.val_terminator() {
             if t == v {
                 return Ok(ParseResult::ValuesDone);
             }
         }
+
+        // Store the canonical spelling of case insensitive possible values
+        let v = if arg.is_set(ArgSettings::CaseInsensitive) {
+            let s = v.to_string_lossy();
+            arg.possible_vals()
+                .and_then(|pvs| pvs.iter().find(|pv| pv.eq_ignore_ascii_case(&*s)))
+                .map_or(v, |pv| OsStr::new(*pv))
+        } else {
+            v
+        };
         matcher.add_val_to(arg.name(), v);
+        matcher.add_index_to(arg.name(), self.cur_idx.get());
 
         // Increment or create the group "args"
         if let Some(grps) = self.groups_for_arg(arg.name()) {
             for grp in grps {
                 matcher.add_val_to(&*grp, v);
+                matcher.add_index_to(&*grp, self.cur_idx.get());
             }
         }
 
@@ -1644,9 +1830,14 @@ impl<'a, 'b> Parser<'a, 'b>
         debugln!("Parser::parse_flag;");
 
         matcher.inc_occurrence_of(flag.b.name);
+        matcher.add_index_to(flag.b.name, self.cur_idx.get());
         // Increment or create the group "args"
-        self.groups_for_arg(flag.b.name)
-            .and_then(|vec| Some(matcher.inc_occurrences_of(&*vec)));
+        if let Some(grps) = self.groups_for_arg(flag.b.name) {
+            for grp in grps {
+                matcher.inc_occurrence_of(grp);
+                matcher.add_index_to(grp, self.cur_idx.get());
+            }
+        }
 
         Ok(ParseResult::Flag)
     }
@@ -1654,11 +1845,10 @@ impl<'a, 'b> Parser<'a, 'b>
     fn did_you_mean_error(&self, arg: &str, matcher: &mut ArgMatcher<'a>) -> ClapResult<()> {
 
         // Didn't match a flag or option
-        let suffix =
-            suggestions::did_you_mean_flag_suffix(arg, longs!(self), &self.subcommands);
+        let did_you_mean = suggestions::did_you_mean_flag(arg, longs!(self), &self.subcommands);
 
         // Add the arg to the matches to build a proper usage string
-        if let Some(name) = suffix.1 {
+        if let Some((name, _)) = did_you_mean {
             if let Some(opt) = find_opt_by_long!(self, name) {
                 self.groups_for_arg(&*opt.b.name)
                     .and_then(|grps| Some(matcher.inc_occurrences_of(&*grps)));
@@ -1672,9 +1862,8 @@ impl<'a, 'b> Parser<'a, 'b>
 
         let used_arg = format!("--{}", arg);
         Err(Error::unknown_argument(&*used_arg,
-                                    &*suffix.0,
-                                    &*usage::create_error_usage(self, matcher, None),
-                                    self.color()))
+                                    did_you_mean,
+                                    &*usage::create_error_usage(self, matcher, None)))
     }
 
     // Prints the version to the user and exits if quit=true
@@ -1729,6 +1918,7 @@ impl<'a, 'b> Parser<'a, 'b>
                 if let Some(ref val) = $a.v.default_val {
                     if $m.get($a.b.name).is_none() {
                         try!($_self.add_val_to_arg($a, OsStr::new(val), $m));
+                        $m.set_source($a.b.name, ValueSource::DefaultValue);
 
                         if $_self.cache.map_or(true, |name| name != $a.name()) {
                             arg_post_processing!($_self, $a, $m);
@@ -1753,6 +1943,7 @@ impl<'a, 'b> Parser<'a, 'b>
                             };
                             if add {
                                 try!($_self.add_val_to_arg($a, OsStr::new(default), $m));
+                                $m.set_source($a.b.name, ValueSource::DefaultValueIf);
                                 if $_self.cache.map_or(true, |name| name != $a.name()) {
                                     arg_post_processing!($_self, $a, $m);
                                     $_self.cache = Some($a.name());
@@ -1780,6 +1971,127 @@ impl<'a, 'b> Parser<'a, 'b>
         Ok(())
     }
 
+    pub fn add_env(&mut self, matcher: &mut ArgMatcher<'a>) -> ClapResult<()> {
+        macro_rules! add_val {
+            ($_self:ident, $a:ident, $m:ident) => {
+                if let Some(ref val) = $a.v.env {
+                    // Only use the environment if the arg wasn't used at runtime
+                    if $m.get($a.b.name).map_or(true, |ma| ma.occurs == 0) {
+                        if let Some(ref val) = val.1 {
+                            debugln!("Parser::add_env: arg={}, val={:?}", $a.b.name, val);
+                            try!($_self.add_val_to_arg($a, OsStr::new(val), $m));
+                            $m.set_source($a.b.name, ValueSource::EnvVariable);
+
+                            if $_self.cache.map_or(true, |name| name != $a.name()) {
+                                arg_post_processing!($_self, $a, $m);
+                                $_self.cache = Some($a.name());
+                            }
+                        }
+                    }
+                }
+            };
+        }
+
+        for o in &self.opts {
+            add_val!(self, o, matcher);
+        }
+        for p in self.positionals.values() {
+            add_val!(self, p, matcher);
+        }
+        Ok(())
+    }
+
+    pub fn add_config(&mut self, matcher: &mut ArgMatcher<'a>) -> ClapResult<()> {
+        macro_rules! add_val {
+            ($_self:ident, $a:ident, $m:ident) => {
+                // Only use the config if the arg wasn't used at runtime or in the environment
+                if !$m.contains($a.b.name) {
+                    if let Some((origin, vals)) = $_self.config_vals($a.b.name) {
+                        debugln!("Parser::add_config: arg={}, origin={}, vals={:?}",
+                                 $a.b.name,
+                                 origin,
+                                 vals);
+                        for val in &vals {
+                            try!($_self.add_val_to_arg($a, OsStr::new(val), $m));
+                        }
+                        $m.set_origin($a.b.name, origin);
+
+                        if $_self.cache.map_or(true, |name| name != $a.name()) {
+                            arg_post_processing!($_self, $a, $m);
+                            $_self.cache = Some($a.name());
+                        }
+                    }
+                }
+            };
+        }
+
+        if self.configs.is_empty() && self.inherited_configs.is_empty() {
+            return Ok(());
+        }
+        for f in &self.flags {
+            if matcher.contains(f.b.name) {
+                continue;
+            }
+            if let Some((origin, vals)) = self.config_vals(f.b.name) {
+                debugln!("Parser::add_config: arg={}, origin={}, vals={:?}",
+                         f.b.name,
+                         origin,
+                         vals);
+                let val = vals.last().expect(INTERNAL_ERROR_MSG);
+                let occurs = match &*val.to_lowercase() {
+                    "true" | "yes" | "on" => 1,
+                    "false" | "no" | "off" => 0,
+                    v => {
+                        match v.parse::<u64>() {
+                            Ok(n) if n <= 1 || f.is_set(ArgSettings::Multiple) => n,
+                            _ => {
+                                let usage = usage::create_error_usage(self, matcher, None);
+                                return Err(Error::invalid_value(&**val,
+                                                                &["true", "false"],
+                                                                f,
+                                                                &*usage));
+                            }
+                        }
+                    }
+                };
+                // Config values have no position on the command line, so unlike `parse_flag`
+                // no index is recorded for them
+                for _ in 0..occurs {
+                    matcher.inc_occurrence_of(f.b.name);
+                    if let Some(grps) = self.groups_for_arg(f.b.name) {
+                        for grp in grps {
+                            matcher.inc_occurrence_of(grp);
+                        }
+                    }
+                }
+                if occurs > 0 {
+                    matcher.set_origin(f.b.name, origin);
+                    if self.cache.map_or(true, |name| name != f.name()) {
+                        arg_post_processing!(self, f, matcher);
+                        self.cache = Some(f.name());
+                    }
+                }
+            }
+        }
+        for o in &self.opts {
+            add_val!(self, o, matcher);
+        }
+        for p in self.positionals.values() {
+            add_val!(self, p, matcher);
+        }
+        Ok(())
+    }
+
+    // Gets the origin and values of the highest priority config source with values for `name`
+    fn config_vals(&self, name: &str) -> Option<(String, Vec<String>)> {
+        self.inherited_configs
+            .iter()
+            .chain(self.configs.iter())
+            .rev()
+            .filter_map(|c| c.get(name).map(|vals| (c.origin().to_owned(), vals.to_vec())))
+            .next()
+    }
+
     pub fn flags(&self) -> Iter<FlagBuilder<'a, 'b>> { self.flags.iter() }
 
     pub fn opts(&self) -> Iter<OptBuilder<'a, 'b>> { self.opts.iter() }
@@ -1788,6 +2100,13 @@ impl<'a, 'b> Parser<'a, 'b>
 
     pub fn subcommands(&self) -> Iter<App> { self.subcommands.iter() }
 
+    // The catalog of user facing messages, which is the built-in English one unless the app (or
+    // one of its parents) was given another
+    #[doc(hidden)]
+    pub fn catalog(&self) -> &MessageCatalog {
+        self.catalog.as_ref().map_or(catalog::default_catalog(), |c| &**c)
+    }
+
     // Should we color the output? None=determined by output location, true=yes, false=no
     #[doc(hidden)]
     pub fn color(&self) -> ColorWhen {
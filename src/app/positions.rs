@@ -0,0 +1,433 @@
+// Only the JSON and TOML loaders have the text of their documents
+#![cfg_attr(not(any(feature = "json", feature = "toml")), allow(dead_code))]
+
+// Std
+use std::iter::Peekable;
+use std::str::Chars;
+
+// `?` can't be used with `Option` in the supported versions of Rust
+macro_rules! try_opt {
+    ($e:expr) => {
+        match $e {
+            Some(v) => v,
+            None => return None,
+        }
+    };
+}
+
+// The line and column of every key and value of a JSON or TOML document describing an App, which
+// `serde_json::Value` and `toml::Value` don't keep. The documents have already been parsed, so
+// the scanners below only follow their structure, and stop at anything they don't expect.
+pub struct Positions {
+    pos: (usize, usize),
+    children: Children,
+}
+
+enum Children {
+    Scalar,
+    Array(Vec<Positions>),
+    Table(Vec<(String, Positions)>),
+}
+
+impl Positions {
+    fn new(pos: (usize, usize), children: Children) -> Self {
+        Positions {
+            pos: pos,
+            children: children,
+        }
+    }
+
+    #[cfg(feature = "json")]
+    pub fn json(src: &str) -> Option<Positions> { json_value(&mut Scanner::new(src)) }
+
+    #[cfg(feature = "toml")]
+    pub fn toml(src: &str) -> Option<Positions> {
+        let mut s = Scanner::new(src);
+        let mut root = Positions::new((1, 1), Children::Table(vec![]));
+        let mut table = vec![];
+        loop {
+            s.skip_toml_ws(true);
+            let pos = s.pos();
+            match s.peek() {
+                None => return Some(root),
+                Some('[') => {
+                    s.bump();
+                    let array = s.eat('[');
+                    table = try_opt!(toml_keys(&mut s));
+                    if !s.eat(']') || (array && !s.eat(']')) {
+                        return None;
+                    }
+                    let (last, parents) = try_opt!(table.split_last());
+                    let parent = root.table_at(parents, pos);
+                    if array {
+                        parent.entry(last, pos).push(Positions::new(pos, Children::Table(vec![])));
+                    } else {
+                        parent.entry(last, pos).pos = pos;
+                    }
+                }
+                Some(_) => {
+                    let keys = try_opt!(toml_keys(&mut s));
+                    s.skip_toml_ws(false);
+                    if !s.eat('=') {
+                        return None;
+                    }
+                    let mut v = try_opt!(toml_value(&mut s));
+                    v.pos = pos;
+                    let (last, parents) = try_opt!(keys.split_last());
+                    let t = root.table_at(&*table, pos).table_at(parents, pos);
+                    *t.entry(last, pos) = v;
+                }
+            }
+        }
+    }
+
+    // The position of the key at `path` (i.e. `subcommands[2].args[0].short`), or of the closest
+    // key above it which was found. As in the paths of the problems, the name of an argument,
+    // group or subcommand which is the only key of its table may be left out.
+    pub fn find(&self, path: &str) -> (usize, usize) {
+        let mut cur = self;
+        for seg in path.split('.').filter(|s| !s.is_empty()) {
+            let mut parts = seg.split('[');
+            match parts.next().and_then(|key| cur.child(key)) {
+                Some(c) => cur = c,
+                None => return cur.pos,
+            }
+            for i in parts.filter_map(|i| i.trim_right_matches(']').parse::<usize>().ok()) {
+                match cur.children {
+                    Children::Array(ref a) if i < a.len() => cur = &a[i],
+                    _ => return cur.pos,
+                }
+            }
+        }
+        cur.pos
+    }
+
+    fn child(&self, key: &str) -> Option<&Positions> {
+        let t = match self.children {
+            Children::Table(ref t) => t,
+            _ => return None,
+        };
+        // Later keys replace earlier ones when a JSON document is parsed
+        match t.iter().rev().find(|&&(ref k, _)| k == key) {
+            Some(&(_, ref p)) => Some(p),
+            None if t.len() == 1 => t[0].1.child(key),
+            None => None,
+        }
+    }
+
+    // The table at `keys` below this one, where an array of tables stands for its last table
+    #[cfg(feature = "toml")]
+    fn table_at(&mut self, keys: &[String], pos: (usize, usize)) -> &mut Positions {
+        match keys.split_first() {
+            None => self,
+            Some((key, rest)) => self.entry(key, pos).last().table_at(rest, pos),
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    fn entry(&mut self, key: &str, pos: (usize, usize)) -> &mut Positions {
+        if let Children::Scalar = self.children {
+            self.children = Children::Table(vec![]);
+        }
+        match self.children {
+            Children::Table(ref mut t) => {
+                let i = match t.iter().position(|&(ref k, _)| k == key) {
+                    Some(i) => i,
+                    None => {
+                        t.push((key.to_owned(), Positions::new(pos, Children::Table(vec![]))));
+                        t.len() - 1
+                    }
+                };
+                &mut t[i].1
+            }
+            _ => self,
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    fn push(&mut self, p: Positions) {
+        match self.children {
+            Children::Array(ref mut a) => a.push(p),
+            _ => self.children = Children::Array(vec![p]),
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    fn last(&mut self) -> &mut Positions {
+        let len = match self.children {
+            Children::Array(ref a) => a.len(),
+            _ => 0,
+        };
+        if len == 0 {
+            return self;
+        }
+        match self.children {
+            Children::Array(ref mut a) => &mut a[len - 1],
+            _ => unreachable!(),
+        }
+    }
+}
+
+struct Scanner<'s> {
+    chars: Peekable<Chars<'s>>,
+    line: usize,
+    col: usize,
+}
+
+impl<'s> Scanner<'s> {
+    fn new(src: &'s str) -> Self {
+        Scanner {
+            chars: src.chars().peekable(),
+            line: 1,
+            col: 1,
+        }
+    }
+
+    fn pos(&self) -> (usize, usize) { (self.line, self.col) }
+
+    fn peek(&mut self) -> Option<char> { self.chars.peek().cloned() }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        if c == Some('\n') {
+            self.line += 1;
+            self.col = 1;
+        } else if c.is_some() {
+            self.col += 1;
+        }
+        c
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_while<F: Fn(char) -> bool>(&mut self, f: F) {
+        while self.peek().map_or(false, &f) {
+            self.bump();
+        }
+    }
+
+    // A quoted string after its opening quote, where `escapes` is false for TOML literal strings
+    fn string(&mut self, quote: char, escapes: bool) -> Option<String> {
+        let mut s = String::new();
+        loop {
+            match try_opt!(self.bump()) {
+                c if c == quote => return Some(s),
+                '\\' if escapes => {
+                    let c = match try_opt!(self.bump()) {
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        u @ 'u' | u @ 'U' => {
+                            let len = if u == 'u' { 4 } else { 8 };
+                            let hex: String = (0..len).filter_map(|_| self.bump()).collect();
+                            u32::from_str_radix(&*hex, 16)
+                                .ok()
+                                .and_then(::std::char::from_u32)
+                                .unwrap_or('\u{fffd}')
+                        }
+                        c => c,
+                    };
+                    s.push(c);
+                }
+                c => s.push(c),
+            }
+        }
+    }
+
+    // Whitespace and comments, and newlines as well when `lines` is true
+    #[cfg(feature = "toml")]
+    fn skip_toml_ws(&mut self, lines: bool) {
+        loop {
+            match self.peek() {
+                Some(' ') | Some('\t') | Some('\r') => {}
+                Some('\n') if lines => {}
+                Some('#') => {
+                    self.skip_while(|c| c != '\n');
+                    continue;
+                }
+                _ => return,
+            }
+            self.bump();
+        }
+    }
+
+    // Skips a TOML multi-line string after its opening quotes
+    #[cfg(feature = "toml")]
+    fn skip_multiline(&mut self, quote: char) -> Option<()> {
+        let mut quotes = 0;
+        while quotes < 3 {
+            match try_opt!(self.bump()) {
+                c if c == quote => quotes += 1,
+                '\\' if quote == '"' => {
+                    self.bump();
+                    quotes = 0;
+                }
+                _ => quotes = 0,
+            }
+        }
+        // Up to two more quotes may be a part of the string
+        self.eat(quote);
+        self.eat(quote);
+        Some(())
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_value(s: &mut Scanner) -> Option<Positions> {
+    s.skip_while(char::is_whitespace);
+    let pos = s.pos();
+    let children = match try_opt!(s.peek()) {
+        '{' => {
+            s.bump();
+            let mut t = vec![];
+            loop {
+                s.skip_while(|c| c.is_whitespace() || c == ',');
+                if s.eat('}') {
+                    break;
+                }
+                let key_pos = s.pos();
+                if !s.eat('"') {
+                    return None;
+                }
+                let key = try_opt!(s.string('"', true));
+                s.skip_while(char::is_whitespace);
+                if !s.eat(':') {
+                    return None;
+                }
+                let mut v = try_opt!(json_value(s));
+                v.pos = key_pos;
+                t.push((key, v));
+            }
+            Children::Table(t)
+        }
+        '[' => {
+            s.bump();
+            let mut a = vec![];
+            loop {
+                s.skip_while(|c| c.is_whitespace() || c == ',');
+                if s.eat(']') {
+                    break;
+                }
+                a.push(try_opt!(json_value(s)));
+            }
+            Children::Array(a)
+        }
+        '"' => {
+            s.bump();
+            try_opt!(s.string('"', true));
+            Children::Scalar
+        }
+        _ => {
+            s.skip_while(|c| !c.is_whitespace() && c != ',' && c != ']' && c != '}');
+            Children::Scalar
+        }
+    };
+    Some(Positions::new(pos, children))
+}
+
+// The keys of a header or of a key/value pair, which may be dotted (i.e. `a."b".c`)
+#[cfg(feature = "toml")]
+fn toml_keys(s: &mut Scanner) -> Option<Vec<String>> {
+    let mut keys = vec![];
+    loop {
+        s.skip_toml_ws(false);
+        let key = match try_opt!(s.peek()) {
+            q @ '"' | q @ '\'' => {
+                s.bump();
+                try_opt!(s.string(q, q == '"'))
+            }
+            _ => {
+                let mut key = String::new();
+                while let Some(c) = s.peek() {
+                    if !(c.is_alphanumeric() || c == '_' || c == '-') {
+                        break;
+                    }
+                    key.push(c);
+                    s.bump();
+                }
+                if key.is_empty() {
+                    return None;
+                }
+                key
+            }
+        };
+        keys.push(key);
+        s.skip_toml_ws(false);
+        if !s.eat('.') {
+            return Some(keys);
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+fn toml_value(s: &mut Scanner) -> Option<Positions> {
+    s.skip_toml_ws(false);
+    let pos = s.pos();
+    let children = match try_opt!(s.peek()) {
+        q @ '"' | q @ '\'' => {
+            s.bump();
+            if s.eat(q) {
+                // Either an empty string or the start of a multi-line one
+                if s.eat(q) {
+                    try_opt!(s.skip_multiline(q));
+                }
+            } else {
+                try_opt!(s.string(q, q == '"'));
+            }
+            Children::Scalar
+        }
+        '[' => {
+            s.bump();
+            let mut a = vec![];
+            loop {
+                s.skip_toml_ws(true);
+                if s.eat(',') {
+                    continue;
+                }
+                if s.eat(']') {
+                    break;
+                }
+                a.push(try_opt!(toml_value(s)));
+            }
+            Children::Array(a)
+        }
+        '{' => {
+            s.bump();
+            let mut t = Positions::new(pos, Children::Table(vec![]));
+            loop {
+                s.skip_toml_ws(false);
+                if s.eat(',') {
+                    continue;
+                }
+                if s.eat('}') {
+                    break;
+                }
+                let key_pos = s.pos();
+                let keys = try_opt!(toml_keys(s));
+                if !s.eat('=') {
+                    return None;
+                }
+                let mut v = try_opt!(toml_value(s));
+                v.pos = key_pos;
+                let (last, parents) = try_opt!(keys.split_last());
+                *t.table_at(parents, key_pos).entry(last, key_pos) = v;
+            }
+            return Some(t);
+        }
+        _ => {
+            // Dates may contain a space, so only the delimiters end a value
+            s.skip_while(|c| !"\n#,]}".contains(c));
+            Children::Scalar
+        }
+    };
+    Some(Positions::new(pos, children))
+}
@@ -45,6 +45,7 @@ bitflags! {
         const VALID_ARG_FOUND      = 1 << 37;
         const INFER_SUBCOMMANDS    = 1 << 38;
         const CONTAINS_LAST        = 1 << 39;
+        const DYNAMIC_COMPLETION   = 1 << 40;
     }
 }
 
@@ -83,6 +84,7 @@ impl AppFlags {
         DeriveDisplayOrder => DERIVE_DISP_ORDER,
         DisableHelpSubcommand => DISABLE_HELP_SC,
         DisableVersion => DISABLE_VERSION,
+        DynamicCompletion => DYNAMIC_COMPLETION,
         GlobalVersion => GLOBAL_VERSION,
         HidePossibleValuesInHelp => NO_POS_VALUES,
         Hidden => HIDDEN,
@@ -443,6 +445,39 @@ pub enum AppSettings {
     /// [`SubCommand`]: ./struct.SubCommand.html
     DisableHelpSubcommand,
 
+    /// Enables dynamic (runtime) completion. When the `_CLAP_COMPLETE_INDEX` environment variable
+    /// is set, instead of parsing the arguments normally, clap parses the command line up to the
+    /// word at that index (where the binary name is index `0`) and prints the candidates for that
+    /// word to `stdout`, one per line, and exits.
+    ///
+    /// Candidates are switches, subcommands, [possible values], or the values returned from an
+    /// argument's [`Arg::completer`]. This allows completing values which are only known at
+    /// runtime, such as branch or profile names. The shell scripts which drive this protocol are
+    /// generated with [`App::gen_dynamic_completions_to`].
+    ///
+    /// **NOTE:** When using one of the `*_safe` methods, the candidates are returned as an
+    /// [`Error`] of kind [`ErrorKind::CompletionsDisplayed`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings, SubCommand};
+    /// let mut app = App::new("myprog")
+    ///     .setting(AppSettings::DynamicCompletion)
+    ///     .subcommand(SubCommand::with_name("checkout")
+    ///         .arg(Arg::with_name("branch")
+    ///             .completer(|_| vec!["master".into(), "develop".into()])));
+    ///
+    /// let candidates = app.get_dynamic_completions_from(vec!["myprog", "checkout", "d"], 2);
+    /// assert_eq!(candidates, &["develop"]);
+    /// ```
+    /// [possible values]: ./struct.Arg.html#method.possible_values
+    /// [`Arg::completer`]: ./struct.Arg.html#method.completer
+    /// [`App::gen_dynamic_completions_to`]: ./struct.App.html#method.gen_dynamic_completions_to
+    /// [`Error`]: ./struct.Error.html
+    /// [`ErrorKind::CompletionsDisplayed`]: ./enum.ErrorKind.html#variant.CompletionsDisplayed
+    DynamicCompletion,
+
     /// Disables `-V` and `--version` [`App`] without affecting any of the [`SubCommand`]s
     /// (Defaults to `false`; application *does* have a version flag)
     ///
@@ -883,6 +918,7 @@ impl FromStr for AppSettings {
             "dontcollapseargsinusage" => Ok(AppSettings::DontCollapseArgsInUsage),
             "dontdelimittrailingvalues" => Ok(AppSettings::DontDelimitTrailingValues),
             "disablehelpsubcommand" => Ok(AppSettings::DisableHelpSubcommand),
+            "dynamiccompletion" => Ok(AppSettings::DynamicCompletion),
             "disableversion" => Ok(AppSettings::DisableVersion),
             "globalversion" => Ok(AppSettings::GlobalVersion),
             "hidden" => Ok(AppSettings::Hidden),
@@ -936,6 +972,8 @@ mod test {
                    AppSettings::ColorNever);
         assert_eq!("disablehelpsubcommand".parse::<AppSettings>().unwrap(),
                    AppSettings::DisableHelpSubcommand);
+        assert_eq!("dynamiccompletion".parse::<AppSettings>().unwrap(),
+                   AppSettings::DynamicCompletion);
         assert_eq!("disableversion".parse::<AppSettings>().unwrap(),
                    AppSettings::DisableVersion);
         assert_eq!("dontcollapseargsinusage".parse::<AppSettings>().unwrap(),
@@ -0,0 +1,167 @@
+// A parser for the template language used by `App::template` and `App::arg_template`.
+//
+// A template is plain text with tags inside curly brackets. Besides the tags which are replaced
+// by content (such as `{usage}`), a part of a template can be made conditional:
+//
+//     {#if subcommands}SUBCOMMANDS:\n{subcommands}{#else}No subcommands{/if}
+//
+// The condition can be negated with `!` (i.e. `{#if !author}`), and conditionals can be nested.
+// What the tags and conditions mean is up to the code rendering the template.
+
+#[derive(Debug, PartialEq)]
+pub enum Node<'t> {
+    // Text which is copied as is
+    Text(&'t str),
+    // The content of a tag, without the curly brackets
+    Tag(&'t str),
+    If(Cond<'t>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Cond<'t> {
+    pub name: &'t str,
+    pub negated: bool,
+    pub then: Vec<Node<'t>>,
+    pub otherwise: Vec<Node<'t>>,
+}
+
+impl<'t> Cond<'t> {
+    // The nodes to render depending on whether the condition holds
+    pub fn branch(&self, holds: bool) -> &[Node<'t>] {
+        if holds != self.negated {
+            &self.then
+        } else {
+            &self.otherwise
+        }
+    }
+}
+
+// A conditional which hasn't been closed yet
+struct Open<'t> {
+    cond: Cond<'t>,
+    in_else: bool,
+}
+
+impl<'t> Open<'t> {
+    fn nodes(&mut self) -> &mut Vec<Node<'t>> {
+        if self.in_else {
+            &mut self.cond.otherwise
+        } else {
+            &mut self.cond.then
+        }
+    }
+}
+
+/// Parses a template into its nodes.
+///
+/// Parsing never fails: a `{` without a matching `}`, and an `{#else}` or `{/if}` outside of a
+/// conditional are kept as text, while conditionals which aren't closed end with the template.
+pub fn parse(template: &str) -> Vec<Node> {
+    debugln!("template::parse;");
+    let mut root = vec![];
+    let mut open: Vec<Open> = vec![];
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        let end = match rest[start..].find('}') {
+            Some(len) => start + len,
+            None => break,
+        };
+        if start > 0 {
+            push(&mut root, &mut open, Node::Text(&rest[..start]));
+        }
+        let tag = &rest[start + 1..end];
+        if tag.starts_with("#if ") {
+            let name = tag["#if ".len()..].trim();
+            let negated = name.starts_with('!');
+            open.push(Open {
+                          cond: Cond {
+                              name: if negated { name[1..].trim() } else { name },
+                              negated: negated,
+                              then: vec![],
+                              otherwise: vec![],
+                          },
+                          in_else: false,
+                      });
+        } else if tag == "#else" && open.last().map_or(false, |o| !o.in_else) {
+            open.last_mut().unwrap().in_else = true;
+        } else if tag == "/if" && !open.is_empty() {
+            close(&mut root, &mut open);
+        } else if tag == "#else" || tag == "/if" {
+            push(&mut root, &mut open, Node::Text(&rest[start..end + 1]));
+        } else {
+            push(&mut root, &mut open, Node::Tag(tag));
+        }
+        rest = &rest[end + 1..];
+    }
+    if !rest.is_empty() {
+        push(&mut root, &mut open, Node::Text(rest));
+    }
+    while !open.is_empty() {
+        close(&mut root, &mut open);
+    }
+    root
+}
+
+/// Splits a tag into its name and the argument following a `:`, if any (i.e. `section:Misc`).
+pub fn split_tag(tag: &str) -> (&str, Option<&str>) {
+    match tag.find(':') {
+        Some(i) => (&tag[..i], Some(&tag[i + 1..])),
+        None => (tag, None),
+    }
+}
+
+fn push<'t>(root: &mut Vec<Node<'t>>, open: &mut Vec<Open<'t>>, node: Node<'t>) {
+    match open.last_mut() {
+        Some(o) => o.nodes().push(node),
+        None => root.push(node),
+    }
+}
+
+fn close<'t>(root: &mut Vec<Node<'t>>, open: &mut Vec<Open<'t>>) {
+    let o = open.pop().expect("template::close called without an open conditional");
+    push(root, open, Node::If(o.cond));
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse, Cond, Node};
+
+    #[test]
+    fn tags_and_text() {
+        assert_eq!(parse("{bin} - {usage}\n{"),
+                   vec![Node::Tag("bin"),
+                        Node::Text(" - "),
+                        Node::Tag("usage"),
+                        Node::Text("\n{")]);
+    }
+
+    #[test]
+    fn nested_conditionals() {
+        assert_eq!(parse("{#if flags}F{#if !options}O{#else}{options}{/if}{/if}{/if}"),
+                   vec![Node::If(Cond {
+                                     name: "flags",
+                                     negated: false,
+                                     then: vec![Node::Text("F"),
+                                                Node::If(Cond {
+                                                             name: "options",
+                                                             negated: true,
+                                                             then: vec![Node::Text("O")],
+                                                             otherwise: vec![Node::Tag("options")],
+                                                         })],
+                                     otherwise: vec![],
+                                 }),
+                        Node::Text("{/if}")]);
+    }
+
+    #[test]
+    fn unclosed_conditional() {
+        assert_eq!(parse("{#if about}{about}"),
+                   vec![Node::If(Cond {
+                                     name: "about",
+                                     negated: false,
+                                     then: vec![Node::Tag("about")],
+                                     otherwise: vec![],
+                                 })]);
+    }
+}
@@ -3,7 +3,7 @@ use std::collections::{BTreeMap, VecDeque};
 
 // Internal
 use INTERNAL_ERROR_MSG;
-use args::{AnyArg, ArgMatcher, PosBuilder};
+use args::{AnyArg, ArgGroup, ArgMatcher, PosBuilder};
 use args::settings::ArgSettings;
 use app::settings::AppSettings as AS;
 use app::parser::Parser;
@@ -13,7 +13,8 @@ use app::parser::Parser;
 pub fn create_usage_with_title(p: &Parser, used: &[&str]) -> String {
     debugln!("usage::create_usage_with_title;");
     let mut usage = String::with_capacity(75);
-    usage.push_str("USAGE:\n    ");
+    usage.push_str(p.catalog().usage_heading());
+    usage.push_str("\n    ");
     usage.push_str(&*create_usage_no_title(p, used));
     usage
 }
@@ -424,9 +425,9 @@ pub fn get_required_usage_from<'a, 'b>(p: &Parser<'a, 'b>,
         ret_val.push_back(arg);
     }
     let mut g_vec: Vec<String> = vec![];
-    for g in desc_reqs.iter().filter(|n| p.groups.iter().any(|g| &&g.name == n)) {
-        let g_string = p.args_in_group(g).join("|");
-        let elem = format!("<{}>", &g_string[..g_string.len()]);
+    for g in desc_reqs.iter().filter_map(|n| p.groups.iter().find(|g| g.name == *n)) {
+        let g_string = p.args_in_group(g.name).join("|");
+        let elem = format!("<{}>{}", &g_string[..g_string.len()], group_bounds(g));
         if !g_vec.contains(&elem) {
             g_vec.push(elem);
         }
@@ -437,3 +438,14 @@ pub fn get_required_usage_from<'a, 'b>(p: &Parser<'a, 'b>,
 
     ret_val
 }
+
+// The number of arguments a group takes, i.e. "{2,}" for at least two, or nothing if unbounded
+fn group_bounds(g: &ArgGroup) -> String {
+    match (g.min_args, g.max_args) {
+        (Some(min), Some(max)) if min == max => format!("{{{}}}", min),
+        (Some(min), Some(max)) => format!("{{{},{}}}", min, max),
+        (Some(min), None) if min > 1 => format!("{{{},}}", min),
+        (_, Some(max)) => format!("{{,{}}}", max),
+        _ => String::new(),
+    }
+}
@@ -1,17 +1,17 @@
 // std
+use std::ascii::AsciiExt;
 use std::fmt::Display;
 
 // Internal
 use INTERNAL_ERROR_MSG;
 use INVALID_UTF8;
-use args::{AnyArg, ArgMatcher, MatchedArg};
+use args::{AnyArg, ArgGroup, ArgMatcher, MatchedArg, ValueSource};
 use args::settings::ArgSettings;
 use errors::{Error, ErrorKind};
 use errors::Result as ClapResult;
 use osstringext::OsStrExt2;
 use app::settings::AppSettings as AS;
 use app::parser::{Parser, ParseResult};
-use fmt::{Colorizer, ColorizerOption};
 use app::usage;
 
 pub struct Validator<'a, 'b, 'z>(&'z mut Parser<'a, 'b>)
@@ -28,6 +28,8 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
                     -> ClapResult<()> {
         debugln!("Validator::validate;");
         let mut reqs_validated = false;
+        try!(self.0.add_env(matcher));
+        try!(self.0.add_config(matcher));
         try!(self.0.add_defaults(matcher));
         if let ParseResult::Opt(a) = needs_val_of {
             debugln!("Validator::validate: needs_val_of={:?}", a);
@@ -45,8 +47,7 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
             };
             if should_err {
                 return Err(Error::empty_value(o,
-                                              &*usage::create_error_usage(self.0, matcher, None),
-                                              self.0.color()));
+                                              &*usage::create_error_usage(self.0, matcher, None)));
             }
         }
 
@@ -54,19 +55,77 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
            self.0.is_set(AS::ArgRequiredElseHelp) {
             let mut out = vec![];
             try!(self.0.write_help_err(&mut out));
-            return Err(Error {
-                           message: String::from_utf8_lossy(&*out).into_owned(),
-                           kind: ErrorKind::MissingArgumentOrSubcommand,
-                           info: None,
-                       });
+            return Err(Error::raw(ErrorKind::MissingArgumentOrSubcommand,
+                                  String::from_utf8_lossy(&*out)));
         }
         try!(self.validate_blacklist(matcher));
         if !(self.0.is_set(AS::SubcommandsNegateReqs) && subcmd_name.is_some()) && !reqs_validated {
             try!(self.validate_required(matcher));
         }
         try!(self.validate_matched_args(matcher));
+        try!(self.parse_values(matcher));
+        if subcmd_name.is_some() {
+            for a in &self.0.global_args {
+                matcher.propagate_config(a.b.name);
+            }
+        }
         matcher.usage(usage::create_usage_with_title(self.0, &[]));
+        if let Some(ref keys) = self.0.keys {
+            matcher.keys(keys.clone());
+        }
+        self.validate_matches(matcher)
+    }
+
+    // Turns the values of the arguments which have a value parser into typed values
+    fn parse_values(&self, matcher: &mut ArgMatcher<'a>) -> ClapResult<()> {
+        debugln!("Validator::parse_values;");
+        let mut parsed = vec![];
+        for (name, ma) in matcher.iter() {
+            let (arg, disp, parser) = if let Some(o) = find_by_name!(self.0, *name, opts, iter) {
+                (&o.b, o.to_string(), o.v.value_parser.as_ref())
+            } else if let Some(p) = find_by_name!(self.0, *name, positionals, values) {
+                (&p.b, p.to_string(), p.v.value_parser.as_ref())
+            } else {
+                continue;
+            };
+            if let Some(parser) = parser {
+                let mut vals = Vec::with_capacity(ma.vals.len());
+                for val in &ma.vals {
+                    match parser(val) {
+                        Ok(v) => vals.push(v),
+                        Err(e) => {
+                            return Err(Error::value_parse(disp,
+                                                          val.to_string_lossy(),
+                                                          e,
+                                                          usage::create_error_usage(self.0,
+                                                                                    matcher,
+                                                                                    None)))
+                        }
+                    }
+                }
+                parsed.push((arg.name, vals));
+            }
+        }
+        for (name, vals) in parsed {
+            if let Some(ma) = matcher.get_mut(name) {
+                ma.parsed = Some(vals);
+            }
+        }
+        Ok(())
+    }
 
+    // Runs the validators of the app, which see all the arguments at once
+    fn validate_matches(&self, matcher: &ArgMatcher) -> ClapResult<()> {
+        debugln!("Validator::validate_matches: validators={}",
+                 self.0.validators.len());
+        for v in &self.0.validators {
+            if let Err(e) = v(&matcher.0) {
+                return Err(Error::matches_validation(e,
+                                                     &*usage::create_error_usage(self.0,
+                                                                                 matcher,
+                                                                                 None)));
+            }
+        }
         Ok(())
     }
 
@@ -82,34 +141,38 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
             if self.0.is_set(AS::StrictUtf8) && val.to_str().is_none() {
                 debugln!("Validator::validate_values: invalid UTF-8 found in val {:?}",
                          val);
-                return Err(Error::invalid_utf8(&*usage::create_error_usage(self.0, matcher, None),
-                                               self.0.color()));
+                return Err(Error::invalid_utf8(&*usage::create_error_usage(self.0, matcher, None)));
             }
             if let Some(p_vals) = arg.possible_vals() {
                 debugln!("Validator::validate_values: possible_vals={:?}", p_vals);
                 let val_str = val.to_string_lossy();
-                if !p_vals.contains(&&*val_str) {
+                let ok = if arg.is_set(ArgSettings::CaseInsensitive) {
+                    p_vals.iter().any(|pv| pv.eq_ignore_ascii_case(&*val_str))
+                } else {
+                    p_vals.contains(&&*val_str)
+                };
+                if !ok {
                     return Err(Error::invalid_value(val_str,
                                                     p_vals,
                                                     arg,
                                                     &*usage::create_error_usage(self.0,
                                                                                 matcher,
-                                                                                None),
-                                                    self.0.color()));
+                                                                                None)));
                 }
             }
             if !arg.is_set(ArgSettings::EmptyValues) && val.is_empty_() &&
                matcher.contains(&*arg.name()) {
                 debugln!("Validator::validate_values: illegal empty val found");
                 return Err(Error::empty_value(arg,
-                                              &*usage::create_error_usage(self.0, matcher, None),
-                                              self.0.color()));
+                                              &*usage::create_error_usage(self.0, matcher, None)));
             }
             if let Some(vtor) = arg.validator() {
                 debug!("Validator::validate_values: checking validator...");
                 if let Err(e) = vtor(val.to_string_lossy().into_owned()) {
                     sdebugln!("error");
-                    return Err(Error::value_validation(Some(arg), e, self.0.color()));
+                    return Err(Error::value_validation(Some(arg),
+                                                       Some(val.to_string_lossy().into_owned()),
+                                                       e));
                 } else {
                     sdebugln!("good");
                 }
@@ -119,8 +182,8 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
                 if let Err(e) = vtor(val) {
                     sdebugln!("error");
                     return Err(Error::value_validation(Some(arg),
-                                                       (*e).to_string_lossy().to_string(),
-                                                       self.0.color()));
+                                                       Some(val.to_string_lossy().into_owned()),
+                                                       (*e).to_string_lossy().to_string()));
                 } else {
                     sdebugln!("good");
                 }
@@ -148,15 +211,15 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
                 let usg = usage::create_error_usage($p, $matcher, None);
                 if let Some(f) = find_by_name!($p, $name, flags, iter) {
                     debugln!("build_err!: It was a flag...");
-                    Error::argument_conflict(f, c_with, &*usg, self.0.color())
+                    Error::argument_conflict(f, c_with, &*usg)
                 } else if let Some(o) = find_by_name!($p, $name, opts, iter) {
                    debugln!("build_err!: It was an option...");
-                    Error::argument_conflict(o, c_with, &*usg, self.0.color())
+                    Error::argument_conflict(o, c_with, &*usg)
                 } else {
                     match find_by_name!($p, $name, positionals, values) {
                         Some(p) => {
                             debugln!("build_err!: It was a positional...");
-                            Error::argument_conflict(p, c_with, &*usg, self.0.color())
+                            Error::argument_conflict(p, c_with, &*usg)
                         },
                         None    => panic!(INTERNAL_ERROR_MSG)
                     }
@@ -182,7 +245,7 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
                 return Err(build_err!(self.0, *name, matcher));
             }
         }
-        Ok(())
+        self.validate_group_args(matcher, |given, g| g.max_args.map_or(false, |max| given > max))
     }
 
     fn validate_matched_args(&self, matcher: &mut ArgMatcher<'a>) -> ClapResult<()> {
@@ -233,8 +296,7 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
             return Err(Error::unexpected_multiple_usage(a,
                                                         &*usage::create_error_usage(self.0,
                                                                                     matcher,
-                                                                                    None),
-                                                        self.0.color()));
+                                                                                    None)));
         }
         Ok(())
     }
@@ -263,18 +325,9 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
                                                          } else {
                                                              ma.vals.len()
                                                          },
-                                                         if ma.vals.len() == 1 ||
-                                                            (a.is_set(ArgSettings::Multiple) &&
-                                                             (ma.vals.len() % num as usize) ==
-                                                             1) {
-                                                             "as"
-                                                         } else {
-                                                             "ere"
-                                                         },
                                                          &*usage::create_error_usage(self.0,
                                                                                      matcher,
-                                                                                     None),
-                                                         self.0.color()));
+                                                                                     None)));
             }
         }
         if let Some(num) = a.max_vals() {
@@ -290,8 +343,7 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
                                                   a,
                                                   &*usage::create_error_usage(self.0,
                                                                               matcher,
-                                                                              None),
-                                                  self.0.color()));
+                                                                              None)));
             }
         }
         if let Some(num) = a.min_vals() {
@@ -303,15 +355,13 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
                                                  ma.vals.len(),
                                                  &*usage::create_error_usage(self.0,
                                                                              matcher,
-                                                                             None),
-                                                 self.0.color()));
+                                                                             None)));
             }
         }
         // Issue 665 (https://github.com/kbknapp/clap-rs/issues/665)
         if a.takes_value() && !a.is_set(ArgSettings::EmptyValues) && ma.vals.is_empty() {
             return Err(Error::empty_value(a,
-                                          &*usage::create_error_usage(self.0, matcher, None),
-                                          self.0.color()));
+                                          &*usage::create_error_usage(self.0, matcher, None)));
         }
         Ok(())
     }
@@ -368,6 +418,46 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
                 }
             }
         }
+        self.validate_group_args(matcher, |given, g| g.min_args.map_or(false, |min| given < min))
+    }
+
+    // Errors for the first group for which `too_many_or_few` holds given the number of its
+    // arguments used on the command line, which doesn't include default values
+    fn validate_group_args<F>(&self, matcher: &ArgMatcher, too_many_or_few: F) -> ClapResult<()>
+        where F: Fn(usize, &ArgGroup) -> bool
+    {
+        for g in &self.0.groups {
+            let names = self.0.arg_names_in_group(g.name);
+            let given = names
+                .iter()
+                .filter(|n| match matcher.get(n) {
+                            Some(ma) => {
+                                ma.source != ValueSource::DefaultValue &&
+                                ma.source != ValueSource::DefaultValueIf
+                            }
+                            None => false,
+                        })
+                .collect::<Vec<_>>();
+            debugln!("Validator::validate_group_args: group={}, given={:?}",
+                     g.name,
+                     given);
+            if !too_many_or_few(given.len(), g) {
+                continue;
+            }
+            let display = |n: &&str| {
+                self.0
+                    .find_any_arg(n)
+                    .map_or(n.to_string(), |a| a.to_string())
+            };
+            return Err(Error::wrong_number_of_group_args(g.name,
+                                                         names.iter().map(&display).collect(),
+                                                         given.into_iter().map(&display).collect(),
+                                                         g.min_args,
+                                                         g.max_args,
+                                                         &*usage::create_error_usage(self.0,
+                                                                                     matcher,
+                                                                                     None)));
+        }
         Ok(())
     }
 
@@ -417,10 +507,6 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
 
     fn missing_required_error(&self, matcher: &ArgMatcher, extra: Option<&str>) -> ClapResult<()> {
         debugln!("Validator::missing_required_error: extra={:?}", extra);
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: self.0.color(),
-        });
         let mut reqs = self.0
             .required
             .iter()
@@ -434,14 +520,12 @@ impl<'a, 'b, 'z> Validator<'a, 'b, 'z> {
         debugln!("Validator::missing_required_error: reqs={:#?}", reqs);
         let req_args =
             usage::get_required_usage_from(self.0, &reqs[..], Some(matcher), extra, true)
-                .iter()
-                .fold(String::new(),
-                      |acc, s| acc + &format!("\n    {}", c.error(s))[..]);
+                .into_iter()
+                .collect::<Vec<_>>();
         debugln!("Validator::missing_required_error: req_args={:#?}",
                  req_args);
-        Err(Error::missing_required_argument(&*req_args,
-                                             &*usage::create_error_usage(self.0, matcher, extra),
-                                             self.0.color()))
+        Err(Error::missing_required_argument(req_args,
+                                             &*usage::create_error_usage(self.0, matcher, extra)))
     }
 
     #[inline]
@@ -1,6 +1,9 @@
 #[cfg(feature = "yaml")]
 use std::collections::BTreeMap;
+use std::any::Any;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::env;
 use std::ffi::{OsString, OsStr};
 #[cfg(target_os="windows")]
 use osstringext::OsStrExt3;
@@ -14,6 +17,7 @@ use vec_map::VecMap;
 
 use usage_parser::UsageParser;
 use args::settings::ArgSettings;
+use args::{ValueHint, ValueParser};
 use args::arg_builder::{Base, Valued, Switched};
 
 /// The abstract representation of a command line argument. Used to set all the options and
@@ -125,9 +129,17 @@ impl<'a, 'b> Arg<'a, 'b> {
                 "value_delimiter" => yaml_to_str!(a, v, value_delimiter),
                 "required_unless" => yaml_to_str!(a, v, required_unless),
                 "display_order" => yaml_to_usize!(a, v, display_order),
+                "help_heading" => yaml_to_str!(a, v, help_heading),
                 "default_value" => yaml_to_str!(a, v, default_value),
                 "default_value_if" => yaml_tuple3!(a, v, default_value_if),
                 "default_value_ifs" => yaml_tuple3!(a, v, default_value_if),
+                "env" => yaml_to_str!(a, v, env),
+                "hide_env_values" => yaml_to_bool!(a, v, hide_env_values),
+                "value_hint" => {
+                    a.value_hint(yaml_str!(v)
+                        .parse()
+                        .unwrap_or_else(|e| panic!("failed to convert YAML {:?} value: {}", v, e)))
+                }
                 "value_names" => yaml_vec_or_str!(v, a, value_name),
                 "groups" => yaml_vec_or_str!(v, a, group),
                 "requires" => yaml_vec_or_str!(v, a, requires),
@@ -136,6 +148,7 @@ impl<'a, 'b> Arg<'a, 'b> {
                 "conflicts_with" => yaml_vec_or_str!(v, a, conflicts_with),
                 "overrides_with" => yaml_vec_or_str!(v, a, overrides_with),
                 "possible_values" => yaml_vec_or_str!(v, a, possible_value),
+                "case_insensitive" => yaml_to_bool!(a, v, case_insensitive),
                 "required_unless_one" => yaml_vec_or_str!(v, a, required_unless),
                 "required_unless_all" => {
                     a = yaml_vec_or_str!(v, a, required_unless);
@@ -2360,6 +2373,42 @@ impl<'a, 'b> Arg<'a, 'b> {
         self
     }
 
+    /// When set to `true` the [possible values] of this argument are matched regardless of
+    /// (ASCII) case, i.e. `--color=Always` is accepted when `always` is a possible value. The
+    /// value is always stored using the spelling given to [`Arg::possible_values`], so
+    /// [`ArgMatches::value_of`] returns the canonical value.
+    ///
+    /// **NOTE:** Case insensitivity is supported for ASCII characters only, in the same manner
+    /// as the enums generated by [`arg_enum!`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("color")
+    ///         .long("color")
+    ///         .takes_value(true)
+    ///         .possible_values(&["always", "auto", "never"])
+    ///         .case_insensitive(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--color=Always"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("color"), Some("always"));
+    /// ```
+    /// [possible values]: ./struct.Arg.html#method.possible_values
+    /// [`Arg::possible_values`]: ./struct.Arg.html#method.possible_values
+    /// [`ArgMatches::value_of`]: ./struct.ArgMatches.html#method.value_of
+    /// [`arg_enum!`]: ./macro.arg_enum.html
+    pub fn case_insensitive(self, ci: bool) -> Self {
+        if ci {
+            self.set(ArgSettings::CaseInsensitive)
+        } else {
+            self.unset(ArgSettings::CaseInsensitive)
+        }
+    }
+
     /// Specifies the name of the [`ArgGroup`] the argument belongs to.
     ///
     /// # Examples
@@ -2563,6 +2612,108 @@ impl<'a, 'b> Arg<'a, 'b> {
         self
     }
 
+    /// Parses each value of this argument into a typed value while the arguments are parsed, using
+    /// a [`ValueParser`] such as one of the built-in [`IntParser`], [`PathParser`],
+    /// [`BoolParser`], [`DurationParser`] or [`EnumParser`], or a function taking an `&OsStr` and
+    /// returning a `Result<T, String>`. The typed values are retrieved with
+    /// [`ArgMatches::value_of_parsed`] and [`ArgMatches::values_of_parsed`], so that they don't
+    /// have to be parsed again from strings.
+    ///
+    /// The values are parsed after all the other checks of the argument (such as its
+    /// [possible values] and [validators]) have passed, and before the [validators of the app] run,
+    /// which can use the typed values. [Default values] and values from the [environment] are
+    /// parsed as well. A value which fails to parse produces an error of the kind
+    /// [`ErrorKind::ValueValidation`], which includes the reason returned by the parser.
+    ///
+    /// **NOTE:** The argument must take a value, flags with a value parser [`panic!`] when they're
+    /// added to an [`App`] in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind, IntParser};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::with_name("jobs")
+    ///         .short("j")
+    ///         .takes_value(true)
+    ///         .default_value("1")
+    ///         .value_parser(IntParser::<u8>::range(1, 64)));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["prog", "-j", "8"]);
+    /// assert_eq!(m.value_of_parsed::<u8, _>("jobs"), Some(&8));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["prog"]);
+    /// assert_eq!(m.value_of_parsed::<u8, _>("jobs"), Some(&1));
+    ///
+    /// let res = app.get_matches_from_safe(vec!["prog", "-j", "100"]);
+    /// let err = res.unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::ValueValidation);
+    /// assert!(err.message().contains("Invalid value '100' for '-j <jobs>': must be between 1 \
+    ///                                 and 64"));
+    /// ```
+    /// [`ValueParser`]: ./trait.ValueParser.html
+    /// [`IntParser`]: ./struct.IntParser.html
+    /// [`PathParser`]: ./struct.PathParser.html
+    /// [`BoolParser`]: ./struct.BoolParser.html
+    /// [`DurationParser`]: ./struct.DurationParser.html
+    /// [`EnumParser`]: ./struct.EnumParser.html
+    /// [`ArgMatches::value_of_parsed`]: ./struct.ArgMatches.html#method.value_of_parsed
+    /// [`ArgMatches::values_of_parsed`]: ./struct.ArgMatches.html#method.values_of_parsed
+    /// [possible values]: ./struct.Arg.html#method.possible_values
+    /// [validators]: ./struct.Arg.html#method.validator
+    /// [validators of the app]: ./struct.App.html#method.validator
+    /// [Default values]: ./struct.Arg.html#method.default_value
+    /// [environment]: ./struct.Arg.html#method.env
+    /// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    /// [`App`]: ./struct.App.html
+    pub fn value_parser<P: ValueParser>(mut self, parser: P) -> Self {
+        self.v.value_parser = Some(Rc::new(move |val: &OsStr| {
+            let v: Arc<Any + Send + Sync> = Arc::new(try!(parser.parse(val)));
+            Ok(v)
+        }));
+        self
+    }
+
+    /// Registers a function which provides the candidates when completing this argument's value
+    /// at runtime with [`AppSettings::DynamicCompletion`]. The function is passed the part of the
+    /// value the user has typed so far, and returns the possible values. Only those candidates
+    /// which start with the typed value are offered to the shell.
+    ///
+    /// This is useful for values which can't be known when the completion script is generated,
+    /// such as the names of branches or profiles.
+    ///
+    /// **NOTE:** A completer takes precedence over the argument's [possible values] when
+    /// completing dynamically.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings};
+    /// fn profiles(_typed: &str) -> Vec<String> {
+    ///     // i.e. read the profile names from a config file
+    ///     vec!["dev".into(), "default".into(), "release".into()]
+    /// }
+    ///
+    /// let mut app = App::new("prog")
+    ///     .setting(AppSettings::DynamicCompletion)
+    ///     .arg(Arg::with_name("profile")
+    ///         .long("profile")
+    ///         .takes_value(true)
+    ///         .completer(profiles));
+    ///
+    /// let candidates = app.get_dynamic_completions_from(vec!["prog", "--profile", "de"], 2);
+    /// assert_eq!(candidates, &["dev", "default"]);
+    /// ```
+    /// [`AppSettings::DynamicCompletion`]: ./enum.AppSettings.html#variant.DynamicCompletion
+    /// [possible values]: ./struct.Arg.html#method.possible_values
+    pub fn completer<F>(mut self, f: F) -> Self
+        where F: Fn(&str) -> Vec<String> + 'static
+    {
+        self.v.completer = Some(Rc::new(f));
+        self
+    }
+
     /// Specifies the *maximum* number of values are for this argument. For example, if you had a
     /// `-f <file>` argument where you wanted up to 3 'files' you would set `.max_values(3)`, and
     /// this argument would be satisfied if the user provided, 1, 2, or 3 values.
@@ -3029,6 +3180,9 @@ impl<'a, 'b> Arg<'a, 'b> {
     /// **NOTE:** This setting effectively disables `AppSettings::ArgRequiredElseHelp` if used in
     /// conjuction as it ensures that some argument will always be present.
     ///
+    /// **NOTE:** Use [`ArgMatches::value_source`] to tell whether the value was provided at
+    /// runtime or is the default value.
+    ///
     /// # Examples
     ///
     /// First we use the default value without providing any value at runtime.
@@ -3069,6 +3223,7 @@ impl<'a, 'b> Arg<'a, 'b> {
     /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
     /// [`ArgMatches::is_present`]: ./struct.ArgMatches.html#method.is_present
     /// [`Arg::default_value_if`]: ./struct.Arg.html#method.default_value_if
+    /// [`ArgMatches::value_source`]: ./struct.ArgMatches.html#method.value_source
     pub fn default_value(self, val: &'a str) -> Self {
         self.default_value_os(OsStr::from_bytes(val.as_bytes()))
     }
@@ -3311,6 +3466,166 @@ impl<'a, 'b> Arg<'a, 'b> {
         self
     }
 
+    /// Specifies that if the value is not passed in as an argument, that it should be retrieved
+    /// from the environment, if available. If it is not present in the environment, then default
+    /// rules will apply.
+    ///
+    /// The value read from the environment is treated exactly like one passed at runtime, i.e. it
+    /// is split on the [value delimiter], and checked against any [possible values] and
+    /// [validators].
+    ///
+    /// **NOTE:** The order of precedence is the value passed at runtime, then the environment
+    /// variable, then [`Arg::default_value_if`], and finally [`Arg::default_value`].
+    ///
+    /// **NOTE:** If the value is taken from the environment, [`ArgMatches::occurrences_of`] will
+    /// return `0` even though [`ArgMatches::value_of`] will return the value found.
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// In this example, we show the variable coming from the environment:
+    ///
+    /// ```rust
+    /// # use std::env;
+    /// # use clap::{App, Arg};
+    ///
+    /// env::set_var("MY_FLAG", "env");
+    ///
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag")
+    ///         .env("MY_FLAG"))
+    ///     .get_matches_from(vec![
+    ///         "prog"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("flag"), Some("env"));
+    /// ```
+    ///
+    /// In this example, we show the variable coming from an option on the CLI:
+    ///
+    /// ```rust
+    /// # use std::env;
+    /// # use clap::{App, Arg};
+    ///
+    /// env::set_var("MY_FLAG_OPT", "env");
+    ///
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag")
+    ///         .env("MY_FLAG_OPT"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--flag", "opt"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("flag"), Some("opt"));
+    /// ```
+    ///
+    /// In this example, we show the variable coming from the environment even with the
+    /// presence of a default:
+    ///
+    /// ```rust
+    /// # use std::env;
+    /// # use clap::{App, Arg};
+    ///
+    /// env::set_var("MY_FLAG_DEFAULT", "env");
+    ///
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag")
+    ///         .env("MY_FLAG_DEFAULT")
+    ///         .default_value("default"))
+    ///     .get_matches_from(vec![
+    ///         "prog"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("flag"), Some("env"));
+    /// ```
+    /// [value delimiter]: ./struct.Arg.html#method.use_delimiter
+    /// [possible values]: ./struct.Arg.html#method.possible_values
+    /// [validators]: ./struct.Arg.html#method.validator
+    /// [`Arg::default_value_if`]: ./struct.Arg.html#method.default_value_if
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`ArgMatches::occurrences_of`]: ./struct.ArgMatches.html#method.occurrences_of
+    /// [`ArgMatches::value_of`]: ./struct.ArgMatches.html#method.value_of
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn env(self, name: &'a str) -> Self {
+        self.env_os(OsStr::new(name))
+    }
+
+    /// Specifies that if the value is not passed in as an argument, that it should be retrieved
+    /// from the environment if available in the exact same manner as [`Arg::env`] only using
+    /// [`OsStr`]s instead.
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    /// [`OsStr`]: https://doc.rust-lang.org/std/ffi/struct.OsStr.html
+    pub fn env_os(mut self, name: &'a OsStr) -> Self {
+        self.setb(ArgSettings::TakesValue);
+        self.v.env = Some((name, env::var_os(name)));
+        self
+    }
+
+    /// Hides the value of the environment variable read by [`Arg::env`] from the help message.
+    /// The name of the variable is still displayed. This is useful for variables which hold
+    /// secrets such as passwords or tokens.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::env;
+    /// # use clap::{App, Arg};
+    /// env::set_var("MY_TOKEN", "s3cr3t");
+    ///
+    /// App::new("prog")
+    ///     .arg(Arg::with_name("token")
+    ///         .long("token")
+    ///         .env("MY_TOKEN")
+    ///         .hide_env_values(true));
+    /// ```
+    ///
+    /// The help message for `--token` will then contain `[env: MY_TOKEN]` instead of
+    /// `[env: MY_TOKEN=s3cr3t]`.
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    pub fn hide_env_values(self, hide: bool) -> Self {
+        if hide {
+            self.set(ArgSettings::HideEnvValues)
+        } else {
+            self.unset(ArgSettings::HideEnvValues)
+        }
+    }
+
+    /// Provides a hint about the kind of value this argument takes, which is used by the shell
+    /// completion scripts generated with [`App::gen_completions`] to complete the value natively.
+    /// For example, an argument with [`ValueHint::DirPath`] will only complete directories, and
+    /// one with [`ValueHint::Hostname`] will complete known hosts.
+    ///
+    /// [Possible values] always take precedence over a value hint.
+    ///
+    /// **NOTE:** This setting implies [`Arg::takes_value(true)`].
+    ///
+    /// **NOTE:** Value hints are currently used when completing the values of options. PowerShell
+    /// completions ignore them, and fall back to PowerShell's default path completion.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ValueHint};
+    /// App::new("prog")
+    ///     .arg(Arg::with_name("output")
+    ///         .long("output-dir")
+    ///         .value_hint(ValueHint::DirPath));
+    /// ```
+    /// [`App::gen_completions`]: ./struct.App.html#method.gen_completions
+    /// [`ValueHint::DirPath`]: ./enum.ValueHint.html#variant.DirPath
+    /// [`ValueHint::Hostname`]: ./enum.ValueHint.html#variant.Hostname
+    /// [Possible values]: ./struct.Arg.html#method.possible_values
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn value_hint(mut self, hint: ValueHint) -> Self {
+        self.setb(ArgSettings::TakesValue);
+        self.v.value_hint = hint;
+        self
+    }
+
     /// When set to `true` the help string will be displayed on the line after the argument and
     /// indented once. This can be helpful for arguments with very long or complex help messages.
     /// This can also be helpful for arguments with very long flag names, or many/long value names.
@@ -3425,6 +3740,41 @@ impl<'a, 'b> Arg<'a, 'b> {
         self
     }
 
+    /// Sets the heading of the section of the help message which the argument is displayed in,
+    /// instead of the default `FLAGS`, `OPTIONS` or `ARGS` sections. Each heading becomes its
+    /// own section, and sections are displayed in the order their first argument was added to the
+    /// [`App`], after the default sections.
+    ///
+    /// The arguments of a section can also be placed in a [help template] with the
+    /// `{section:<heading>}` tag.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::with_name("proxy")
+    ///         .long("proxy")
+    ///         .takes_value(true)
+    ///         .help_heading("NETWORK OPTIONS")
+    ///         .help("Connect through a proxy"))
+    ///     .arg(Arg::with_name("offline")
+    ///         .long("offline")
+    ///         .help_heading("NETWORK OPTIONS")
+    ///         .help("Don't connect at all"))
+    ///     .get_matches_from_safe(vec!["prog", "--help"]);
+    ///
+    /// let help = res.unwrap_err().message();
+    /// assert!(help.contains("NETWORK OPTIONS:\n        --offline          Don't connect at all\n\
+    ///                        \x20       --proxy <proxy>    Connect through a proxy"));
+    /// ```
+    /// [`App`]: ./struct.App.html
+    /// [help template]: ./struct.App.html#method.template
+    pub fn help_heading(mut self, heading: &'b str) -> Self {
+        self.b.help_heading = Some(heading);
+        self
+    }
+
     /// Checks if one of the [`ArgSettings`] settings is set for the argument
     /// [`ArgSettings`]: ./enum.ArgSettings.html
     pub fn is_set(&self, s: ArgSettings) -> bool { self.b.is_set(s) }
@@ -14,6 +14,7 @@ pub struct Base<'a, 'b>
     pub overrides: Option<Vec<&'a str>>,
     pub groups: Option<Vec<&'a str>>,
     pub requires: Option<Vec<(Option<&'b str>, &'a str)>>,
+    pub help_heading: Option<&'b str>,
 }
 
 impl<'n, 'e> Base<'n, 'e> {
@@ -9,7 +9,7 @@ use std::mem;
 use vec_map::{self, VecMap};
 
 // Internal
-use args::{ArgSettings, AnyArg, Base, Switched, Valued, Arg, DispOrder};
+use args::{ArgSettings, AnyArg, Base, Switched, Valued, Arg, DispOrder, ValueHint};
 
 #[allow(missing_debug_implementations)]
 #[doc(hidden)]
@@ -134,6 +134,10 @@ impl<'n, 'e> AnyArg<'n, 'e> for OptBuilder<'n, 'e> {
     fn default_vals_ifs(&self) -> Option<vec_map::Values<(&'n str, Option<&'e OsStr>, &'e OsStr)>> {
         self.v.default_vals_ifs.as_ref().map(|vm| vm.values())
     }
+    fn env<'s>(&'s self) -> Option<(&'n OsStr, Option<&'s OsString>)> {
+        self.v.env.as_ref().map(|&(key, ref value)| (key, value.as_ref()))
+    }
+    fn value_hint(&self) -> ValueHint { self.v.value_hint }
     fn longest_filter(&self) -> bool { true }
     fn aliases(&self) -> Option<Vec<&'e str>> {
         if let Some(ref aliases) = self.s.aliases {
@@ -11,7 +11,7 @@ use vec_map::{self, VecMap};
 
 // Internal
 use Arg;
-use args::{ArgSettings, Base, Valued, AnyArg, DispOrder};
+use args::{ArgSettings, Base, Valued, AnyArg, DispOrder, ValueHint};
 use INTERNAL_ERROR_MSG;
 
 #[allow(missing_debug_implementations)]
@@ -144,6 +144,10 @@ impl<'n, 'e> AnyArg<'n, 'e> for PosBuilder<'n, 'e> {
     fn default_vals_ifs(&self) -> Option<vec_map::Values<(&'n str, Option<&'e OsStr>, &'e OsStr)>> {
         self.v.default_vals_ifs.as_ref().map(|vm| vm.values())
     }
+    fn env<'s>(&'s self) -> Option<(&'n OsStr, Option<&'s OsString>)> {
+        self.v.env.as_ref().map(|&(key, ref value)| (key, value.as_ref()))
+    }
+    fn value_hint(&self) -> ValueHint { self.v.value_hint }
     fn default_val(&self) -> Option<&'e OsStr> { self.v.default_val }
     fn longest_filter(&self) -> bool { true }
     fn aliases(&self) -> Option<Vec<&'e str>> { None }
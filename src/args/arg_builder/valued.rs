@@ -1,9 +1,12 @@
+use std::any::Any;
 use std::rc::Rc;
+use std::sync::Arc;
 use std::ffi::{OsStr, OsString};
 
 use vec_map::VecMap;
 
 use Arg;
+use args::ValueHint;
 
 #[allow(missing_debug_implementations)]
 #[derive(Clone)]
@@ -17,10 +20,14 @@ pub struct Valued<'a, 'b>
     pub min_vals: Option<u64>,
     pub validator: Option<Rc<Fn(String) -> Result<(), String>>>,
     pub validator_os: Option<Rc<Fn(&OsStr) -> Result<(), OsString>>>,
+    pub value_parser: Option<Rc<Fn(&OsStr) -> Result<Arc<Any + Send + Sync>, String>>>,
     pub val_delim: Option<char>,
     pub default_val: Option<&'b OsStr>,
     pub default_vals_ifs: Option<VecMap<(&'a str, Option<&'b OsStr>, &'b OsStr)>>,
+    pub env: Option<(&'a OsStr, Option<OsString>)>,
     pub terminator: Option<&'b str>,
+    pub value_hint: ValueHint,
+    pub completer: Option<Rc<Fn(&str) -> Vec<String>>>,
 }
 
 impl<'n, 'e> Default for Valued<'n, 'e> {
@@ -33,10 +40,14 @@ impl<'n, 'e> Default for Valued<'n, 'e> {
             val_names: None,
             validator: None,
             validator_os: None,
+            value_parser: None,
             val_delim: None,
             default_val: None,
             default_vals_ifs: None,
+            env: None,
             terminator: None,
+            value_hint: ValueHint::Unknown,
+            completer: None,
         }
     }
 }
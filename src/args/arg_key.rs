@@ -0,0 +1,28 @@
+/// A typed name of an argument or group, so that arguments are referred to by a value the
+/// compiler checks, instead of by a string which may contain a typo. Keys are usually declared
+/// with the [`arg_keys!`] macro, which generates an enum implementing this trait.
+///
+/// Apps which declare their keys with [`App::arg_keys`] are checked when the command line is
+/// parsed: every argument must be one of the keys, every key must name an argument or group, and
+/// every relation between arguments (such as [`Arg::requires`] or [`ArgGroup::arg`]) must refer
+/// to an argument or group which exists. The [`ArgMatches`] of such an app [`panic!`] when they're
+/// queried for a name which isn't one of the keys, instead of quietly returning `None` or
+/// `false`.
+///
+/// [`arg_keys!`]: ./macro.arg_keys.html
+/// [`App::arg_keys`]: ./struct.App.html#method.arg_keys
+/// [`Arg::requires`]: ./struct.Arg.html#method.requires
+/// [`ArgGroup::arg`]: ./struct.ArgGroup.html#method.arg
+/// [`ArgMatches`]: ./struct.ArgMatches.html
+/// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+pub trait ArgKey: Copy + 'static {
+    /// The name of the argument or group, as passed to [`Arg::with_name`] or
+    /// [`ArgGroup::with_name`].
+    ///
+    /// [`Arg::with_name`]: ./struct.Arg.html#method.with_name
+    /// [`ArgGroup::with_name`]: ./struct.ArgGroup.html#method.with_name
+    fn name(self) -> &'static str;
+
+    /// All the keys of this type.
+    fn all() -> &'static [Self];
+}
@@ -5,7 +5,7 @@ use std::ops::Deref;
 use std::mem;
 
 // Internal
-use args::{ArgMatches, MatchedArg, SubCommand};
+use args::{ArgMatches, MatchedArg, SubCommand, ValueSource};
 use args::AnyArg;
 use args::settings::ArgSettings;
 
@@ -22,8 +22,8 @@ impl<'a> ArgMatcher<'a> {
 
     pub fn propagate(&mut self, arg: &'a str) {
         debugln!("ArgMatcher::propagate: arg={}", arg);
-        let vals: Vec<_> = if let Some(ma) = self.get(arg) {
-            ma.vals.clone()
+        let (vals, parsed, indices, source, origin) = if let Some(ma) = self.get(arg) {
+            (ma.vals.clone(), ma.parsed.clone(), ma.indices.clone(), ma.source, ma.origin.clone())
         } else {
             debugln!("ArgMatcher::propagate: arg wasn't used");
             return;
@@ -34,10 +34,18 @@ impl<'a> ArgMatcher<'a> {
                     let mut gma = MatchedArg::new();
                     gma.occurs += 1;
                     gma.vals = vals.clone();
+                    gma.parsed = parsed.clone();
+                    gma.indices = indices.clone();
+                    gma.source = source;
+                    gma.origin = origin.clone();
                     gma
                 });
                 if sma.vals.is_empty() {
                     sma.vals = vals.clone();
+                    sma.parsed = parsed.clone();
+                    sma.indices = indices.clone();
+                    sma.source = source;
+                    sma.origin = origin.clone();
                 }
             }
             let mut am = ArgMatcher(mem::replace(&mut sc.matches, ArgMatches::new()));
@@ -48,6 +56,31 @@ impl<'a> ArgMatcher<'a> {
         }
     }
 
+    // Global args read from a config are passed down even without `PropagateGlobalValuesDown`,
+    // because the subcommand only reads its own section of the config. They replace the
+    // subcommand's default values, but not the values it was given directly.
+    pub fn propagate_config(&mut self, arg: &'a str) {
+        debugln!("ArgMatcher::propagate_config: arg={}", arg);
+        let ma = match self.get(arg) {
+            Some(ma) if ma.origin.is_some() => ma.clone(),
+            _ => return,
+        };
+        if let Some(ref mut sc) = self.0.subcommand {
+            let replace = match sc.matches.args.get(arg).map(|sma| sma.source) {
+                None |
+                Some(ValueSource::DefaultValue) |
+                Some(ValueSource::DefaultValueIf) => true,
+                _ => false,
+            };
+            if replace {
+                sc.matches.args.insert(arg, ma);
+            }
+            let mut am = ArgMatcher(mem::replace(&mut sc.matches, ArgMatches::new()));
+            am.propagate_config(arg);
+            mem::swap(&mut am.0, &mut sc.matches);
+        }
+    }
+
     pub fn get_mut(&mut self, arg: &str) -> Option<&mut MatchedArg> { self.0.args.get_mut(arg) }
 
     pub fn get(&self, arg: &str) -> Option<&MatchedArg> { self.0.args.get(arg) }
@@ -68,6 +101,8 @@ impl<'a> ArgMatcher<'a> {
 
     pub fn usage(&mut self, usage: String) { self.0.usage = Some(usage); }
 
+    pub fn keys(&mut self, keys: Vec<&'a str>) { self.0.keys = Some(keys); }
+
     pub fn arg_names(&'a self) -> Vec<&'a str> { self.0.args.keys().map(Deref::deref).collect() }
 
     pub fn entry(&mut self, arg: &'a str) -> Entry<&'a str, MatchedArg> { self.0.args.entry(arg) }
@@ -99,11 +134,40 @@ impl<'a> ArgMatcher<'a> {
         let ma = self.entry(arg).or_insert(MatchedArg {
             occurs: 0,
             vals: Vec::with_capacity(1),
+            parsed: None,
+            indices: Vec::with_capacity(1),
+            source: ValueSource::CommandLine,
+            origin: None,
         });
         // let len = ma.vals.len() + 1;
         ma.vals.push(val.to_owned());
     }
 
+    pub fn add_index_to(&mut self, arg: &'a str, idx: usize) {
+        let ma = self.entry(arg).or_insert(MatchedArg {
+            occurs: 0,
+            indices: Vec::with_capacity(1),
+            vals: Vec::new(),
+            parsed: None,
+            source: ValueSource::CommandLine,
+            origin: None,
+        });
+        ma.indices.push(idx);
+    }
+
+    pub fn set_source(&mut self, arg: &str, source: ValueSource) {
+        if let Some(ma) = self.get_mut(arg) {
+            ma.source = source;
+        }
+    }
+
+    pub fn set_origin(&mut self, arg: &str, origin: String) {
+        if let Some(ma) = self.get_mut(arg) {
+            ma.source = ValueSource::Config;
+            ma.origin = Some(origin);
+        }
+    }
+
     pub fn needs_more_vals<'b, A>(&self, o: &A) -> bool
         where A: AnyArg<'a, 'b>
     {
@@ -1,13 +1,18 @@
 // Std
+use std::any::Any;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::ffi::{OsStr, OsString};
+use std::fmt::Display;
 use std::iter::Map;
 use std::slice::Iter;
+use std::str::FromStr;
 
 // Internal
 use INVALID_UTF8;
-use args::MatchedArg;
+use errors::{Error, Result as ClapResult};
+use fmt::ColorWhen;
+use args::{MatchedArg, ValueSource};
 use args::SubCommand;
 
 /// Used to get information about the arguments that where supplied to the program at runtime by
@@ -65,6 +70,8 @@ pub struct ArgMatches<'a> {
     pub subcommand: Option<Box<SubCommand<'a>>>,
     #[doc(hidden)]
     pub usage: Option<String>,
+    #[doc(hidden)]
+    pub keys: Option<Vec<&'a str>>,
 }
 
 impl<'a> Default for ArgMatches<'a> {
@@ -73,6 +80,7 @@ impl<'a> Default for ArgMatches<'a> {
             args: HashMap::new(),
             subcommand: None,
             usage: None,
+            keys: None,
         }
     }
 }
@@ -109,7 +117,7 @@ impl<'a> ArgMatches<'a> {
     /// [`ArgMatches::values_of`]: ./struct.ArgMatches.html#method.values_of
     /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
     pub fn value_of<S: AsRef<str>>(&self, name: S) -> Option<&str> {
-        if let Some(arg) = self.args.get(name.as_ref()) {
+        if let Some(arg) = self.arg(name.as_ref()) {
             if let Some(v) = arg.vals.get(0) {
                 return Some(v.to_str().expect(INVALID_UTF8));
             }
@@ -141,7 +149,7 @@ impl<'a> ArgMatches<'a> {
     /// ```
     /// [`Arg::values_of_lossy`]: ./struct.ArgMatches.html#method.values_of_lossy
     pub fn value_of_lossy<S: AsRef<str>>(&'a self, name: S) -> Option<Cow<'a, str>> {
-        if let Some(arg) = self.args.get(name.as_ref()) {
+        if let Some(arg) = self.arg(name.as_ref()) {
             if let Some(v) = arg.vals.get(0) {
                 return Some(v.to_string_lossy());
             }
@@ -208,7 +216,7 @@ impl<'a> ArgMatches<'a> {
     /// [`Values`]: ./struct.Values.html
     /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
     pub fn values_of<S: AsRef<str>>(&'a self, name: S) -> Option<Values<'a>> {
-        if let Some(arg) = self.args.get(name.as_ref()) {
+        if let Some(arg) = self.arg(name.as_ref()) {
             fn to_str_slice(o: &OsString) -> &str { o.to_str().expect(INVALID_UTF8) }
             let to_str_slice: fn(&OsString) -> &str = to_str_slice; // coerce to fn pointer
             return Some(Values { iter: arg.vals.iter().map(to_str_slice) });
@@ -241,7 +249,7 @@ impl<'a> ArgMatches<'a> {
     /// assert_eq!(itr.next(), None);
     /// ```
     pub fn values_of_lossy<S: AsRef<str>>(&'a self, name: S) -> Option<Vec<String>> {
-        if let Some(arg) = self.args.get(name.as_ref()) {
+        if let Some(arg) = self.arg(name.as_ref()) {
             return Some(arg.vals
                 .iter()
                 .map(|v| v.to_string_lossy().into_owned())
@@ -284,12 +292,257 @@ impl<'a> ArgMatches<'a> {
     pub fn values_of_os<S: AsRef<str>>(&'a self, name: S) -> Option<OsValues<'a>> {
         fn to_str_slice(o: &OsString) -> &OsStr { &*o }
         let to_str_slice: fn(&'a OsString) -> &'a OsStr = to_str_slice; // coerce to fn pointer
-        if let Some(arg) = self.args.get(name.as_ref()) {
+        if let Some(arg) = self.arg(name.as_ref()) {
             return Some(OsValues { iter: arg.vals.iter().map(to_str_slice) });
         }
         None
     }
 
+    /// Gets the value of a specific argument parsed as type `R`, which can be any type implementing
+    /// [`FromStr`]. This is the method form of the [`value_t!`] macro, however a value which fails
+    /// to parse produces an error of the kind [`ErrorKind::ValueValidation`] which names the
+    /// argument, quotes the offending value, and includes the usage string.
+    ///
+    /// If the argument wasn't present at runtime an error of the kind
+    /// [`ErrorKind::ArgumentNotFound`] is returned.
+    ///
+    /// # Panics
+    ///
+    /// This method will [`panic!`] if the value contains invalid UTF-8 code points.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("len")
+    ///         .long("len")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec!["myapp", "--len", "12"]);
+    ///
+    /// assert_eq!(m.value_of_t::<u32, _>("len").unwrap(), 12);
+    ///
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("len")
+    ///         .long("len")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec!["myapp", "--len", "twelve"]);
+    ///
+    /// assert_eq!(m.value_of_t::<u32, _>("len").unwrap_err().kind, ErrorKind::ValueValidation);
+    /// ```
+    /// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+    /// [`value_t!`]: ./macro.value_t!.html
+    /// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+    /// [`ErrorKind::ArgumentNotFound`]: ./enum.ErrorKind.html#variant.ArgumentNotFound
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    pub fn value_of_t<R, S>(&self, name: S) -> ClapResult<R>
+        where R: FromStr,
+              <R as FromStr>::Err: Display,
+              S: AsRef<str>
+    {
+        let name = name.as_ref();
+        match self.value_of(name) {
+            Some(v) => self.parse_val(name, v),
+            None => Err(Error::argument_not_found_auto(name)),
+        }
+    }
+
+    /// Gets the value of a specific argument parsed as type `R`, or exits the program with an
+    /// error message if the argument wasn't present or its value failed to parse. See
+    /// [`ArgMatches::value_of_t`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("len")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec!["myapp", "12"]);
+    ///
+    /// let len: u32 = m.value_of_t_or_exit("len");
+    /// assert_eq!(len + 2, 14);
+    /// ```
+    /// [`ArgMatches::value_of_t`]: ./struct.ArgMatches.html#method.value_of_t
+    pub fn value_of_t_or_exit<R, S>(&self, name: S) -> R
+        where R: FromStr,
+              <R as FromStr>::Err: Display,
+              S: AsRef<str>
+    {
+        self.value_of_t(name).unwrap_or_else(|e| e.exit())
+    }
+
+    /// Gets all the values of a specific argument parsed as type `R`, which can be any type
+    /// implementing [`FromStr`]. This is the method form of the [`values_t!`] macro. The first
+    /// value which fails to parse produces an error in the same manner as
+    /// [`ArgMatches::value_of_t`].
+    ///
+    /// If the argument wasn't present at runtime an error of the kind
+    /// [`ErrorKind::ArgumentNotFound`] is returned.
+    ///
+    /// # Panics
+    ///
+    /// This method will [`panic!`] if any of the values contain invalid UTF-8 code points.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("seq")
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "1", "2", "3"]);
+    ///
+    /// assert_eq!(m.values_of_t::<u32, _>("seq").unwrap(), [1, 2, 3]);
+    /// ```
+    /// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+    /// [`values_t!`]: ./macro.values_t!.html
+    /// [`ArgMatches::value_of_t`]: ./struct.ArgMatches.html#method.value_of_t
+    /// [`ErrorKind::ArgumentNotFound`]: ./enum.ErrorKind.html#variant.ArgumentNotFound
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    pub fn values_of_t<R, S>(&self, name: S) -> ClapResult<Vec<R>>
+        where R: FromStr,
+              <R as FromStr>::Err: Display,
+              S: AsRef<str>
+    {
+        let name = name.as_ref();
+        match self.arg(name) {
+            Some(arg) => {
+                arg.vals
+                    .iter()
+                    .map(|v| self.parse_val(name, v.to_str().expect(INVALID_UTF8)))
+                    .collect()
+            }
+            None => Err(Error::argument_not_found_auto(name)),
+        }
+    }
+
+    /// Gets all the values of a specific argument parsed as type `R`, or exits the program with
+    /// an error message if the argument wasn't present or any of its values failed to parse. See
+    /// [`ArgMatches::values_of_t`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("seq")
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "1", "2", "3"]);
+    ///
+    /// let seq: Vec<u32> = m.values_of_t_or_exit("seq");
+    /// assert_eq!(seq.iter().sum::<u32>(), 6);
+    /// ```
+    /// [`ArgMatches::values_of_t`]: ./struct.ArgMatches.html#method.values_of_t
+    pub fn values_of_t_or_exit<R, S>(&self, name: S) -> Vec<R>
+        where R: FromStr,
+              <R as FromStr>::Err: Display,
+              S: AsRef<str>
+    {
+        self.values_of_t(name).unwrap_or_else(|e| e.exit())
+    }
+
+    /// Gets the value of a specific argument as parsed by its [value parser] while the arguments
+    /// were parsed, where `T` is the type of the values produced by the parser. Returns `None` if
+    /// the argument wasn't present at runtime.
+    ///
+    /// If the argument has more than one value, only the first is returned, see
+    /// [`ArgMatches::values_of_parsed`] for all the values.
+    ///
+    /// # Panics
+    ///
+    /// This method will [`panic!`] if the argument doesn't have a value parser, or if the values
+    /// of its parser aren't of type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, IntParser};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("len")
+    ///         .long("len")
+    ///         .takes_value(true)
+    ///         .value_parser(IntParser::<u32>::new()))
+    ///     .get_matches_from(vec!["myapp", "--len", "12"]);
+    ///
+    /// assert_eq!(m.value_of_parsed::<u32, _>("len"), Some(&12));
+    /// ```
+    /// [value parser]: ./struct.Arg.html#method.value_parser
+    /// [`ArgMatches::values_of_parsed`]: ./struct.ArgMatches.html#method.values_of_parsed
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    pub fn value_of_parsed<T: Any, S: AsRef<str>>(&self, name: S) -> Option<&T> {
+        self.parsed(name.as_ref()).and_then(|vals| vals.into_iter().next())
+    }
+
+    /// Gets all the values of a specific argument as parsed by its [value parser] while the
+    /// arguments were parsed, where `T` is the type of the values produced by the parser. Returns
+    /// `None` if the argument wasn't present at runtime.
+    ///
+    /// # Panics
+    ///
+    /// This method will [`panic!`] if the argument doesn't have a value parser, or if the values
+    /// of its parser aren't of type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, PathParser};
+    /// # use std::path::PathBuf;
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("files")
+    ///         .multiple(true)
+    ///         .value_parser(PathParser::new()))
+    ///     .get_matches_from(vec!["myapp", "a.txt", "b.txt"]);
+    ///
+    /// let files: Vec<&PathBuf> = m.values_of_parsed("files").unwrap();
+    /// assert_eq!(files, [&PathBuf::from("a.txt"), &PathBuf::from("b.txt")]);
+    /// ```
+    /// [value parser]: ./struct.Arg.html#method.value_parser
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    pub fn values_of_parsed<T: Any, S: AsRef<str>>(&self, name: S) -> Option<Vec<&T>> {
+        self.parsed(name.as_ref())
+    }
+
+    // The parsed values of the matched argument `name`, which must have a value parser producing
+    // values of type `T`
+    fn parsed<T: Any>(&self, name: &str) -> Option<Vec<&T>> {
+        self.arg(name).map(|arg| {
+            let parsed = match arg.parsed {
+                Some(ref parsed) => parsed,
+                None => panic!("The argument '{}' doesn't have a value parser", name),
+            };
+            parsed.iter()
+                .map(|v| {
+                         v.downcast_ref::<T>()
+                             .unwrap_or_else(|| {
+                                 panic!("The value parser of '{}' doesn't produce values of the \
+                                         requested type",
+                                        name)
+                             })
+                     })
+                .collect()
+        })
+    }
+
+    // The matched argument `name`, where `name` must be one of the keys if the app declared them
+    fn arg(&self, name: &str) -> Option<&MatchedArg> {
+        if let Some(ref keys) = self.keys {
+            assert!(keys.contains(&name),
+                    "'{}' isn't one of the argument keys of the app",
+                    name);
+        }
+        self.args.get(name)
+    }
+
+    fn parse_val<R>(&self, name: &str, v: &str) -> ClapResult<R>
+        where R: FromStr,
+              <R as FromStr>::Err: Display
+    {
+        v.parse::<R>().map_err(|e| {
+            Error::value_parse(name, v, e, self.usage()).render_message(ColorWhen::Auto, None)
+        })
+    }
+
     /// Returns `true` if an argument was present at runtime, otherwise `false`.
     ///
     /// # Examples
@@ -311,7 +564,7 @@ impl<'a> ArgMatches<'a> {
                 return true;
             }
         }
-        self.args.contains_key(name.as_ref())
+        self.arg(name.as_ref()).is_some()
     }
 
     /// Returns the number of times an argument was used at runtime. If an argument isn't present
@@ -354,7 +607,289 @@ impl<'a> ArgMatches<'a> {
     /// assert_eq!(m.occurrences_of("flag"), 1);
     /// ```
     pub fn occurrences_of<S: AsRef<str>>(&self, name: S) -> u64 {
-        self.args.get(name.as_ref()).map_or(0, |a| a.occurs)
+        self.arg(name.as_ref()).map_or(0, |a| a.occurs)
+    }
+
+    /// Gets the starting index of the argument in respect to all other arguments. Indices are
+    /// similar to argv indices, but are not exactly 1:1.
+    ///
+    /// For flags (i.e. those arguments which don't have an associated value), indices refer
+    /// to occurrence of the switch, such as `-f`, or `--flag`. However, for options the indices
+    /// refer to the *values* `-o val` would therefore not represent two distinct indices, only the
+    /// index for `val` would be recorded. This is by design.
+    ///
+    /// Besides the flag/option descrepancy, the primary difference between an argv index and clap
+    /// index, is that clap continues counting once all arguments have properly seperated, whereas
+    /// an argv index does not.
+    ///
+    /// The examples should clear this up.
+    ///
+    /// **NOTE:** If an argument is allowed multiple times, this method will only give the *first*
+    /// index.
+    ///
+    /// **NOTE:** Values which were not supplied at runtime (i.e. those from
+    /// [`Arg::default_value`] or [`Arg::env`]) are given indices *after* all the arguments which
+    /// were supplied.
+    ///
+    /// # Examples
+    ///
+    /// The argv indices are listed in the comments below. See how they correspond to the clap
+    /// indices. Note that if it's not listed in a clap index, this is becuase it's not saved in
+    /// in an `ArgMatches` struct for querying.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("flag")
+    ///         .short("f"))
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec!["myapp", "-f", "-o", "val"]);
+    ///             // ARGV idices: ^0       ^1    ^2    ^3
+    ///             // clap idices:          ^1          ^3
+    ///
+    /// assert_eq!(m.index_of("flag"), Some(1));
+    /// assert_eq!(m.index_of("option"), Some(3));
+    /// ```
+    ///
+    /// Now notice, if we use one of the other styles of options:
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("flag")
+    ///         .short("f"))
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec!["myapp", "-f", "-o=val"]);
+    ///             // ARGV idices: ^0       ^1    ^2
+    ///             // clap idices:          ^1       ^3
+    ///
+    /// assert_eq!(m.index_of("flag"), Some(1));
+    /// assert_eq!(m.index_of("option"), Some(3));
+    /// ```
+    ///
+    /// Things become much more complicated, or clear if we look at a more complex combination of
+    /// flags. Let's also throw in the final option style for good measure.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("flag")
+    ///         .short("f"))
+    ///     .arg(Arg::with_name("flag2")
+    ///         .short("F"))
+    ///     .arg(Arg::with_name("flag3")
+    ///         .short("z"))
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec!["myapp", "-fzF", "-oval"]);
+    ///             // ARGV idices: ^0      ^1       ^2
+    ///             // clap idices:         ^1,2,3    ^5
+    ///             //
+    ///             // clap sees the above as 'myapp -f -z -F -o val'
+    ///             //                         ^0    ^1 ^2 ^3 ^4 ^5
+    /// assert_eq!(m.index_of("flag"), Some(1));
+    /// assert_eq!(m.index_of("flag2"), Some(3));
+    /// assert_eq!(m.index_of("flag3"), Some(2));
+    /// assert_eq!(m.index_of("option"), Some(5));
+    /// ```
+    ///
+    /// One final combination of flags/options to see how they combine:
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("flag")
+    ///         .short("f"))
+    ///     .arg(Arg::with_name("flag2")
+    ///         .short("F"))
+    ///     .arg(Arg::with_name("flag3")
+    ///         .short("z"))
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true)
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "-fzFoval"]);
+    ///             // ARGV idices: ^0       ^1
+    ///             // clap idices:          ^1,2,3^5
+    ///             //
+    ///             // clap sees the above as 'myapp -f -z -F -o val'
+    ///             //                         ^0    ^1 ^2 ^3 ^4 ^5
+    /// assert_eq!(m.index_of("flag"), Some(1));
+    /// assert_eq!(m.index_of("flag2"), Some(3));
+    /// assert_eq!(m.index_of("flag3"), Some(2));
+    /// assert_eq!(m.index_of("option"), Some(5));
+    /// ```
+    ///
+    /// The last part to mention is when values are sent in multiple groups with a [delimiter].
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true)
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "-o=val1,val2,val3"]);
+    ///             // ARGV idices: ^0       ^1
+    ///             // clap idices:             ^2   ^3   ^4
+    ///             //
+    ///             // clap sees the above as 'myapp -o val1 val2 val3'
+    ///             //                         ^0    ^1 ^2   ^3   ^4
+    /// assert_eq!(m.index_of("option"), Some(2));
+    /// ```
+    /// [`ArgMatches`]: ./struct.ArgMatches.html
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    /// [delimiter]: ./struct.Arg.html#method.value_delimiter
+    pub fn index_of<S: AsRef<str>>(&self, name: S) -> Option<usize> {
+        if let Some(arg) = self.arg(name.as_ref()) {
+            if let Some(i) = arg.indices.get(0) {
+                return Some(*i);
+            }
+        }
+        None
+    }
+
+    /// Gets all indices of the argument in respect to all other arguments. Indices are
+    /// similar to argv indices, but are not exactly 1:1.
+    ///
+    /// For flags (i.e. those arguments which don't have an associated value), indices refer
+    /// to occurrence of the switch, such as `-f`, or `--flag`. However, for options the indices
+    /// refer to the *values* `-o val` would therefore not represent two distinct indices, only the
+    /// index for `val` would be recorded. This is by design.
+    ///
+    /// *NOTE:* For more information about how clap indices compare to argv indices, see
+    /// [`ArgMatches::index_of`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true)
+    ///         .use_delimiter(true)
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "-o=val1,val2,val3"]);
+    ///             // ARGV idices: ^0       ^1
+    ///             // clap idices:             ^2   ^3   ^4
+    ///             //
+    ///             // clap sees the above as 'myapp -o val1 val2 val3'
+    ///             //                         ^0    ^1 ^2   ^3   ^4
+    /// assert_eq!(m.indices_of("option").unwrap().collect::<Vec<_>>(), &[2, 3, 4]);
+    /// ```
+    ///
+    /// Another quick example is when flags and options are used together
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true)
+    ///         .multiple(true))
+    ///     .arg(Arg::with_name("flag")
+    ///         .short("f")
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "-o", "val1", "-f", "-o", "val2", "-f"]);
+    ///             // ARGV idices: ^0       ^1    ^2      ^3    ^4    ^5      ^6
+    ///             // clap idices:                ^2      ^3          ^5      ^6
+    ///
+    /// assert_eq!(m.indices_of("option").unwrap().collect::<Vec<_>>(), &[2, 5]);
+    /// assert_eq!(m.indices_of("flag").unwrap().collect::<Vec<_>>(), &[3, 6]);
+    /// ```
+    ///
+    /// One final example, which is an odd case; if we *don't* use  value delimiter as we did with
+    /// the first example above instead of `val1`, `val2` and `val3` all being distinc values, they
+    /// would all be a single value of `val1,val2,val3`, in which case case they'd only receive a
+    /// single index.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true)
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "-o=val1,val2,val3"]);
+    ///             // ARGV idices: ^0       ^1
+    ///             // clap idices:             ^2
+    ///             //
+    ///             // clap sees the above as 'myapp -o "val1,val2,val3"'
+    ///             //                         ^0    ^1  ^2
+    /// assert_eq!(m.indices_of("option").unwrap().collect::<Vec<_>>(), &[2]);
+    /// ```
+    /// [`ArgMatches`]: ./struct.ArgMatches.html
+    /// [`ArgMatches::index_of`]: ./struct.ArgMatches.html#method.index_of
+    /// [delimiter]: ./struct.Arg.html#method.value_delimiter
+    pub fn indices_of<S: AsRef<str>>(&'a self, name: S) -> Option<Indices<'a>> {
+        if let Some(arg) = self.arg(name.as_ref()) {
+            fn to_usize(i: &usize) -> usize { *i }
+            let to_usize: fn(&usize) -> usize = to_usize; // coerce to fn pointer
+            return Some(Indices { iter: arg.indices.iter().map(to_usize) });
+        }
+        None
+    }
+
+    /// Gets where the values of a specific argument came from, or `None` if the argument wasn't
+    /// present. This allows telling an argument given on the command line apart from one which was
+    /// filled in from a [default value], the environment, or a [`ConfigSource`], all of which make
+    /// [`ArgMatches::is_present`] return `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ValueSource};
+    /// let app = App::new("myapp")
+    ///     .arg(Arg::with_name("jobs")
+    ///         .long("jobs")
+    ///         .takes_value(true)
+    ///         .default_value("4"));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["myapp"]);
+    /// assert!(m.is_present("jobs"));
+    /// assert_eq!(m.value_source("jobs"), Some(ValueSource::DefaultValue));
+    ///
+    /// let m = app.get_matches_from(vec!["myapp", "--jobs", "4"]);
+    /// assert_eq!(m.value_source("jobs"), Some(ValueSource::CommandLine));
+    /// ```
+    /// [default value]: ./struct.Arg.html#method.default_value
+    /// [`ConfigSource`]: ./struct.ConfigSource.html
+    /// [`ArgMatches::is_present`]: ./struct.ArgMatches.html#method.is_present
+    pub fn value_source<S: AsRef<str>>(&self, name: S) -> Option<ValueSource> {
+        self.arg(name.as_ref()).map(|arg| arg.source)
+    }
+
+    /// Gets the origin of the [`ConfigSource`] which supplied the values of a specific argument.
+    /// Returns `None` if the argument wasn't present, or its values didn't come from a config
+    /// source (i.e. they were given on the command line).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ConfigSource};
+    /// let cfg = ConfigSource::new("/etc/tool.conf")
+    ///     .value("jobs", "2")
+    ///     .value("color", "never");
+    ///
+    /// let m = App::new("tool")
+    ///     .arg(Arg::with_name("jobs").long("jobs").takes_value(true))
+    ///     .arg(Arg::with_name("color").long("color").takes_value(true))
+    ///     .config(cfg)
+    ///     .get_matches_from(vec!["tool", "--color", "always"]);
+    ///
+    /// assert_eq!(m.config_origin("jobs"), Some("/etc/tool.conf"));
+    /// assert_eq!(m.config_origin("color"), None);
+    /// ```
+    /// [`ConfigSource`]: ./struct.ConfigSource.html
+    pub fn config_origin<S: AsRef<str>>(&self, name: S) -> Option<&str> {
+        self.arg(name.as_ref()).and_then(|arg| arg.origin.as_ref()).map(|o| &o[..])
     }
 
     /// Because [`Subcommand`]s are essentially "sub-[`App`]s" they have their own [`ArgMatches`]
@@ -650,3 +1185,66 @@ fn test_default_osvalues_with_shorter_lifetime() {
     let mut values = matches.values_of_os("").unwrap_or_default();
     assert_eq!(values.next(), None);
 }
+
+/// An iterator for getting multiple indices out of an argument via the [`ArgMatches::indices_of`]
+/// method.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg};
+/// let m = App::new("myapp")
+///     .arg(Arg::with_name("output")
+///         .short("o")
+///         .multiple(true)
+///         .takes_value(true))
+///     .get_matches_from(vec!["myapp", "-o", "val1", "val2"]);
+///
+/// let mut indices = m.indices_of("output").unwrap();
+///
+/// assert_eq!(indices.next(), Some(2));
+/// assert_eq!(indices.next(), Some(3));
+/// assert_eq!(indices.next(), None);
+/// ```
+/// [`ArgMatches::indices_of`]: ./struct.ArgMatches.html#method.indices_of
+#[derive(Clone)]
+#[allow(missing_debug_implementations)]
+pub struct Indices<'a> {
+    iter: Map<Iter<'a, usize>, fn(&'a usize) -> usize>,
+}
+
+impl<'a> Iterator for Indices<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> { self.iter.next() }
+    fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
+}
+
+impl<'a> DoubleEndedIterator for Indices<'a> {
+    fn next_back(&mut self) -> Option<usize> { self.iter.next_back() }
+}
+
+impl<'a> ExactSizeIterator for Indices<'a> {}
+
+/// Creates an empty iterator.
+impl<'a> Default for Indices<'a> {
+    fn default() -> Self {
+        static EMPTY: [usize; 0] = [];
+        // This is never called because the iterator is empty:
+        fn to_usize(_: &usize) -> usize { unreachable!() };
+        Indices { iter: EMPTY[..].iter().map(to_usize) }
+    }
+}
+
+#[test]
+fn test_default_indices() {
+    let mut indices: Indices = Indices::default();
+    assert_eq!(indices.next(), None);
+}
+
+#[test]
+fn test_default_indices_with_shorter_lifetime() {
+    let matches = ArgMatches::new();
+    let mut indices = matches.indices_of("").unwrap_or_default();
+    assert_eq!(indices.next(), None);
+}
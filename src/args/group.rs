@@ -91,6 +91,10 @@ pub struct ArgGroup<'a> {
     pub conflicts: Option<Vec<&'a str>>,
     #[doc(hidden)]
     pub multiple: bool,
+    #[doc(hidden)]
+    pub min_args: Option<usize>,
+    #[doc(hidden)]
+    pub max_args: Option<usize>,
 }
 
 impl<'a> ArgGroup<'a> {
@@ -112,6 +116,8 @@ impl<'a> ArgGroup<'a> {
             requires: None,
             conflicts: None,
             multiple: false,
+            min_args: None,
+            max_args: None,
         }
     }
 
@@ -233,6 +239,97 @@ impl<'a> ArgGroup<'a> {
         self
     }
 
+    /// Sets the minimum number of the [`Arg`]s in this group which must be used. This implies
+    /// [`ArgGroup::multiple(true)`], and a minimum greater than `0` also makes the group
+    /// [required].
+    ///
+    /// The arguments which were used are listed by the error, and the usage string displays the
+    /// constraint after the group (i.e. `<--major|--minor|--patch>{2,}`).
+    ///
+    /// **NOTE:** Arguments which only have a [default value] don't count as used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup, ErrorKind};
+    /// let result = App::new("myprog")
+    ///     .arg(Arg::with_name("red").long("red"))
+    ///     .arg(Arg::with_name("green").long("green"))
+    ///     .arg(Arg::with_name("blue").long("blue"))
+    ///     .group(ArgGroup::with_name("colors")
+    ///         .args(&["red", "green", "blue"])
+    ///         .min_args(2))
+    ///     .get_matches_from_safe(vec!["myprog", "--red"]);
+    /// assert!(result.is_err());
+    /// let err = result.unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::WrongNumberOfGroupArgs);
+    /// ```
+    /// [`Arg`]: ./struct.Arg.html
+    /// [`ArgGroup::multiple(true)`]: ./struct.ArgGroup.html#method.multiple
+    /// [required]: ./struct.ArgGroup.html#method.required
+    /// [default value]: ./struct.Arg.html#method.default_value
+    pub fn min_args(mut self, n: usize) -> Self {
+        self.min_args = Some(n);
+        self.multiple = true;
+        if n > 0 {
+            self.required = true;
+        }
+        self
+    }
+
+    /// Sets the maximum number of the [`Arg`]s in this group which may be used. This implies
+    /// [`ArgGroup::multiple(true)`], the maximum being checked instead of the args of the group
+    /// conflicting with each other.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup, ContextKind, ContextValue, ErrorKind};
+    /// let result = App::new("myprog")
+    ///     .arg(Arg::with_name("red").long("red"))
+    ///     .arg(Arg::with_name("green").long("green"))
+    ///     .arg(Arg::with_name("blue").long("blue"))
+    ///     .group(ArgGroup::with_name("colors")
+    ///         .args(&["red", "green", "blue"])
+    ///         .max_args(2))
+    ///     .get_matches_from_safe(vec!["myprog", "--red", "--green", "--blue"]);
+    /// let err = result.unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::WrongNumberOfGroupArgs);
+    /// assert_eq!(err.get(ContextKind::GivenArgs),
+    ///            Some(&ContextValue::Strings(vec!["--red".into(),
+    ///                                             "--green".into(),
+    ///                                             "--blue".into()])));
+    /// ```
+    /// [`Arg`]: ./struct.Arg.html
+    /// [`ArgGroup::multiple(true)`]: ./struct.ArgGroup.html#method.multiple
+    pub fn max_args(mut self, n: usize) -> Self {
+        self.max_args = Some(n);
+        self.multiple = true;
+        self
+    }
+
+    /// Sets the exact number of the [`Arg`]s in this group which must be used, which is the same
+    /// as setting both [`ArgGroup::min_args`] and [`ArgGroup::max_args`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup};
+    /// let result = App::new("myprog")
+    ///     .arg(Arg::with_name("red").long("red"))
+    ///     .arg(Arg::with_name("green").long("green"))
+    ///     .arg(Arg::with_name("blue").long("blue"))
+    ///     .group(ArgGroup::with_name("colors")
+    ///         .args(&["red", "green", "blue"])
+    ///         .number_of_args(2))
+    ///     .get_matches_from_safe(vec!["myprog", "--red", "--blue"]);
+    /// assert!(result.is_ok());
+    /// ```
+    /// [`Arg`]: ./struct.Arg.html
+    /// [`ArgGroup::min_args`]: ./struct.ArgGroup.html#method.min_args
+    /// [`ArgGroup::max_args`]: ./struct.ArgGroup.html#method.max_args
+    pub fn number_of_args(self, n: usize) -> Self { self.min_args(n).max_args(n) }
+
     /// Sets the group as required or not. A required group will be displayed in the usage string
     /// of the application in the format `<arg|arg2|arg3>`. A required `ArgGroup` simply states
     /// that one argument from this group *must* be present at runtime (unless
@@ -451,6 +548,8 @@ impl<'a, 'z> From<&'z ArgGroup<'a>> for ArgGroup<'a> {
             requires: g.requires.clone(),
             conflicts: g.conflicts.clone(),
             multiple: g.multiple,
+            min_args: g.min_args,
+            max_args: g.max_args,
         }
     }
 }
@@ -476,6 +575,9 @@ impl<'a> From<&'a BTreeMap<Yaml, Yaml>> for ArgGroup<'a> {
             a = match k.as_str().unwrap() {
                 "required" => a.required(v.as_bool().unwrap()),
                 "multiple" => a.multiple(v.as_bool().unwrap()),
+                "min_args" => a.min_args(v.as_i64().unwrap() as usize),
+                "max_args" => a.max_args(v.as_i64().unwrap() as usize),
+                "number_of_args" => a.number_of_args(v.as_i64().unwrap() as usize),
                 "args" => yaml_vec_or_str!(v, a, arg),
                 "arg" => {
                     if let Some(ys) = v.as_str() {
@@ -629,6 +731,8 @@ impl<'a> Clone for ArgGroup<'a> {
             requires: self.requires.clone(),
             conflicts: self.conflicts.clone(),
             multiple: self.multiple,
+            min_args: self.min_args,
+            max_args: self.max_args,
         }
     }
 }
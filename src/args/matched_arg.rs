@@ -1,5 +1,10 @@
 // Std
+use std::any::Any;
 use std::ffi::OsString;
+use std::sync::Arc;
+
+// Internal
+use args::ValueSource;
 
 #[doc(hidden)]
 #[derive(Debug, Clone)]
@@ -8,6 +13,15 @@ pub struct MatchedArg {
     pub occurs: u64,
     #[doc(hidden)]
     pub vals: Vec<OsString>,
+    // The values produced by the value parser of the argument, or `None` if it doesn't have one
+    #[doc(hidden)]
+    pub parsed: Option<Vec<Arc<Any + Send + Sync>>>,
+    #[doc(hidden)]
+    pub indices: Vec<usize>,
+    #[doc(hidden)]
+    pub source: ValueSource,
+    #[doc(hidden)]
+    pub origin: Option<String>,
 }
 
 impl Default for MatchedArg {
@@ -15,6 +29,10 @@ impl Default for MatchedArg {
         MatchedArg {
             occurs: 1,
             vals: Vec::with_capacity(1),
+            parsed: None,
+            indices: Vec::with_capacity(1),
+            source: ValueSource::CommandLine,
+            origin: None,
         }
     }
 }
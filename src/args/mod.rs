@@ -1,16 +1,22 @@
 pub use self::any_arg::{AnyArg, DispOrder};
 pub use self::arg::Arg;
+pub use self::arg_key::ArgKey;
 pub use self::arg_builder::{Base, Switched, Valued, FlagBuilder, OptBuilder, PosBuilder};
 pub use self::arg_matcher::ArgMatcher;
-pub use self::arg_matches::{Values, OsValues, ArgMatches};
+pub use self::arg_matches::{Values, OsValues, Indices, ArgMatches};
 pub use self::group::ArgGroup;
 pub use self::matched_arg::MatchedArg;
 pub use self::settings::{ArgFlags, ArgSettings};
 pub use self::subcommand::SubCommand;
+pub use self::value_hint::ValueHint;
+pub use self::value_parser::{BoolParser, DurationParser, EnumParser, IntParser, PathParser,
+                             ValueParser};
+pub use self::value_source::ValueSource;
 
 #[macro_use]
 mod macros;
 mod arg;
+mod arg_key;
 pub mod any_arg;
 mod arg_matches;
 mod arg_matcher;
@@ -19,3 +25,6 @@ mod arg_builder;
 mod matched_arg;
 mod group;
 pub mod settings;
+mod value_hint;
+mod value_parser;
+mod value_source;
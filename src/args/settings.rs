@@ -3,7 +3,7 @@ use std::ascii::AsciiExt;
 use std::str::FromStr;
 
 bitflags! {
-    struct Flags: u16 {
+    struct Flags: u32 {
         const REQUIRED         = 1;
         const MULTIPLE         = 1 << 1;
         const EMPTY_VALS       = 1 << 2;
@@ -20,6 +20,8 @@ bitflags! {
         const REQUIRE_EQUALS   = 1 << 13;
         const LAST             = 1 << 14;
         const HIDE_DEFAULT_VAL = 1 << 15;
+        const HIDE_ENV_VALS    = 1 << 16;
+        const CASE_INSENSITIVE = 1 << 17;
     }
 }
 
@@ -46,7 +48,9 @@ impl ArgFlags {
         AllowLeadingHyphen => ALLOW_TAC_VALS,
         RequireEquals => REQUIRE_EQUALS,
         Last => LAST,
-        HideDefaultValue => HIDE_DEFAULT_VAL
+        HideDefaultValue => HIDE_DEFAULT_VAL,
+        HideEnvValues => HIDE_ENV_VALS,
+        CaseInsensitive => CASE_INSENSITIVE
     }
 }
 
@@ -91,6 +95,10 @@ pub enum ArgSettings {
     Last,
     /// Hides the default value from the help string
     HideDefaultValue,
+    /// Hides the value of any environment variable from the help string
+    HideEnvValues,
+    /// Possible values are matched regardless of (ASCII) case
+    CaseInsensitive,
     #[doc(hidden)]
     RequiredUnlessAll,
     #[doc(hidden)]
@@ -117,6 +125,8 @@ impl FromStr for ArgSettings {
             "requireequals" => Ok(ArgSettings::RequireEquals),
             "last" => Ok(ArgSettings::Last),
             "hidedefaultvalue" => Ok(ArgSettings::HideDefaultValue),
+            "hideenvvalues" => Ok(ArgSettings::HideEnvValues),
+            "caseinsensitive" => Ok(ArgSettings::CaseInsensitive),
             _ => Err("unknown ArgSetting, cannot convert from str".to_owned()),
         }
     }
@@ -160,6 +170,10 @@ mod test {
                    ArgSettings::Last);
         assert_eq!("hidedefaultvalue".parse::<ArgSettings>().unwrap(),
                    ArgSettings::HideDefaultValue);
+        assert_eq!("hideenvvalues".parse::<ArgSettings>().unwrap(),
+                   ArgSettings::HideEnvValues);
+        assert_eq!("caseinsensitive".parse::<ArgSettings>().unwrap(),
+                   ArgSettings::CaseInsensitive);
         assert!("hahahaha".parse::<ArgSettings>().is_err());
     }
 }
@@ -0,0 +1,86 @@
+// Std
+use std::ascii::AsciiExt;
+use std::str::FromStr;
+
+/// Describes the kind of value an argument takes, so that shell completion scripts generated with
+/// [`App::gen_completions`] can complete it natively (i.e. only directories for an
+/// `--output-dir` option) instead of falling back to plain file completion.
+///
+/// See [`Arg::value_hint`] for details.
+///
+/// [`App::gen_completions`]: ./struct.App.html#method.gen_completions
+/// [`Arg::value_hint`]: ./struct.Arg.html#method.value_hint
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ValueHint {
+    /// Nothing is known about the value, the shell's default completion is used (default)
+    Unknown,
+    /// The value is free form text which should not be completed as a path, i.e. a number
+    Other,
+    /// Any existing path
+    AnyPath,
+    /// A path to a file
+    FilePath,
+    /// A path to a directory
+    DirPath,
+    /// A path to an executable file
+    ExecutablePath,
+    /// The name of a command found on `$PATH`
+    CommandName,
+    /// A single string containing a command and its arguments, i.e. `sh -c "..."`
+    CommandString,
+    /// A command name followed by its arguments as separate values, i.e. the value of an
+    /// argument which uses [`ArgSettings::Last`] or [`AppSettings::TrailingVarArg`]
+    ///
+    /// [`ArgSettings::Last`]: ./enum.ArgSettings.html#variant.Last
+    /// [`AppSettings::TrailingVarArg`]: ./enum.AppSettings.html#variant.TrailingVarArg
+    CommandWithArguments,
+    /// The name of a local user
+    Username,
+    /// The name of a host
+    Hostname,
+    /// A URL
+    Url,
+    /// An email address
+    EmailAddress,
+}
+
+impl Default for ValueHint {
+    fn default() -> Self { ValueHint::Unknown }
+}
+
+impl FromStr for ValueHint {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, <Self as FromStr>::Err> {
+        match &*s.to_ascii_lowercase() {
+            "unknown" => Ok(ValueHint::Unknown),
+            "other" => Ok(ValueHint::Other),
+            "anypath" => Ok(ValueHint::AnyPath),
+            "filepath" => Ok(ValueHint::FilePath),
+            "dirpath" => Ok(ValueHint::DirPath),
+            "executablepath" => Ok(ValueHint::ExecutablePath),
+            "commandname" => Ok(ValueHint::CommandName),
+            "commandstring" => Ok(ValueHint::CommandString),
+            "commandwitharguments" => Ok(ValueHint::CommandWithArguments),
+            "username" => Ok(ValueHint::Username),
+            "hostname" => Ok(ValueHint::Hostname),
+            "url" => Ok(ValueHint::Url),
+            "emailaddress" => Ok(ValueHint::EmailAddress),
+            _ => Err("unknown ValueHint, cannot convert from str".to_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ValueHint;
+
+    #[test]
+    fn value_hint_fromstr() {
+        assert_eq!("dirpath".parse::<ValueHint>().unwrap(), ValueHint::DirPath);
+        assert_eq!("FilePath".parse::<ValueHint>().unwrap(), ValueHint::FilePath);
+        assert_eq!("hostname".parse::<ValueHint>().unwrap(), ValueHint::Hostname);
+        assert_eq!("commandwitharguments".parse::<ValueHint>().unwrap(),
+                   ValueHint::CommandWithArguments);
+        assert!("directory".parse::<ValueHint>().is_err());
+    }
+}
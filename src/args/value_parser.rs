@@ -0,0 +1,379 @@
+// Std
+use std::ascii::AsciiExt;
+use std::ffi::OsStr;
+use std::fmt::{self as std_fmt, Display};
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Turns the raw value of an argument into a typed value while the arguments are parsed, so that
+/// the value is only parsed once, and a value which can't be parsed is reported like any other
+/// invalid value. The typed values are stored in the [`ArgMatches`] and retrieved with
+/// [`ArgMatches::value_of_parsed`] and [`ArgMatches::values_of_parsed`].
+///
+/// `clap` ships parsers for integers ([`IntParser`]), paths ([`PathParser`]), booleans
+/// ([`BoolParser`]), durations ([`DurationParser`]) and enums ([`EnumParser`]). Any function or
+/// closure taking an `&OsStr` and returning a `Result<T, String>` is a parser as well.
+///
+/// See [`Arg::value_parser`] for details.
+///
+/// [`ArgMatches`]: ./struct.ArgMatches.html
+/// [`ArgMatches::value_of_parsed`]: ./struct.ArgMatches.html#method.value_of_parsed
+/// [`ArgMatches::values_of_parsed`]: ./struct.ArgMatches.html#method.values_of_parsed
+/// [`IntParser`]: ./struct.IntParser.html
+/// [`PathParser`]: ./struct.PathParser.html
+/// [`BoolParser`]: ./struct.BoolParser.html
+/// [`DurationParser`]: ./struct.DurationParser.html
+/// [`EnumParser`]: ./struct.EnumParser.html
+/// [`Arg::value_parser`]: ./struct.Arg.html#method.value_parser
+pub trait ValueParser: 'static {
+    /// The type of the parsed values
+    type Value: Send + Sync + 'static;
+
+    /// Parses a single value, returning the reason why it isn't valid on failure
+    fn parse(&self, val: &OsStr) -> Result<Self::Value, String>;
+}
+
+impl<F, T> ValueParser for F
+    where F: Fn(&OsStr) -> Result<T, String> + 'static,
+          T: Send + Sync + 'static
+{
+    type Value = T;
+    fn parse(&self, val: &OsStr) -> Result<T, String> { self(val) }
+}
+
+// The value as a `&str`, which all the built-in parsers except `PathParser` require
+fn utf8(val: &OsStr) -> Result<&str, String> {
+    val.to_str().ok_or_else(|| String::from("must be valid UTF-8"))
+}
+
+/// Parses integers of type `T` (i.e. `u16` or `i64`), optionally only accepting values within a
+/// range.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, ErrorKind, IntParser};
+/// let app = App::new("prog")
+///     .arg(Arg::with_name("port")
+///         .long("port")
+///         .takes_value(true)
+///         .value_parser(IntParser::<u16>::range(1024, 49151)));
+///
+/// let m = app.clone().get_matches_from(vec!["prog", "--port", "8080"]);
+/// assert_eq!(m.value_of_parsed::<u16, _>("port"), Some(&8080));
+///
+/// let res = app.get_matches_from_safe(vec!["prog", "--port", "80"]);
+/// assert_eq!(res.unwrap_err().kind, ErrorKind::ValueValidation);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct IntParser<T> {
+    min: Option<T>,
+    max: Option<T>,
+}
+
+impl<T> IntParser<T>
+    where T: FromStr + PartialOrd + Display + Copy + 'static,
+          <T as FromStr>::Err: Display
+{
+    /// Creates a parser accepting any value of type `T`
+    pub fn new() -> Self {
+        IntParser {
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Creates a parser accepting the values from `min` to `max`, both inclusive
+    pub fn range(min: T, max: T) -> Self { IntParser::new().min(min).max(max) }
+
+    /// Only accepts values greater than or equal to `min`
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Only accepts values less than or equal to `max`
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+impl<T> Default for IntParser<T>
+    where T: FromStr + PartialOrd + Display + Copy + 'static,
+          <T as FromStr>::Err: Display
+{
+    fn default() -> Self { IntParser::new() }
+}
+
+impl<T> ValueParser for IntParser<T>
+    where T: FromStr + PartialOrd + Display + Copy + Send + Sync + 'static,
+          <T as FromStr>::Err: Display
+{
+    type Value = T;
+
+    fn parse(&self, val: &OsStr) -> Result<T, String> {
+        let v = try!(try!(utf8(val)).parse::<T>().map_err(|e| e.to_string()));
+        match (self.min, self.max) {
+            (Some(min), Some(max)) if v < min || v > max => {
+                Err(format!("must be between {} and {}", min, max))
+            }
+            (Some(min), None) if v < min => Err(format!("must be at least {}", min)),
+            (None, Some(max)) if v > max => Err(format!("must be at most {}", max)),
+            _ => Ok(v),
+        }
+    }
+}
+
+/// Parses paths into a [`PathBuf`], optionally only accepting paths which exist.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, PathParser};
+/// # use std::path::{Path, PathBuf};
+/// let m = App::new("prog")
+///     .arg(Arg::with_name("config")
+///         .long("config")
+///         .takes_value(true)
+///         .value_parser(PathParser::new()))
+///     .get_matches_from(vec!["prog", "--config", "/etc/prog.toml"]);
+///
+/// assert_eq!(m.value_of_parsed::<PathBuf, _>("config").map(|p| p.as_path()),
+///            Some(Path::new("/etc/prog.toml")));
+/// ```
+/// [`PathBuf`]: https://doc.rust-lang.org/std/path/struct.PathBuf.html
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PathParser {
+    must_exist: bool,
+}
+
+impl PathParser {
+    /// Creates a parser accepting any non-empty path
+    pub fn new() -> Self { PathParser::default() }
+
+    /// Only accepts paths which exist when the arguments are parsed
+    pub fn must_exist(mut self, yes: bool) -> Self {
+        self.must_exist = yes;
+        self
+    }
+}
+
+impl ValueParser for PathParser {
+    type Value = PathBuf;
+
+    fn parse(&self, val: &OsStr) -> Result<PathBuf, String> {
+        if val.is_empty() {
+            return Err(String::from("must not be empty"));
+        }
+        let path = PathBuf::from(val);
+        if self.must_exist && !path.exists() {
+            return Err(String::from("doesn't exist"));
+        }
+        Ok(path)
+    }
+}
+
+/// Parses booleans, accepting `true`, `yes`, `on` and `1` as `true`, and `false`, `no`, `off`
+/// and `0` as `false`, regardless of case.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, BoolParser};
+/// let m = App::new("prog")
+///     .arg(Arg::with_name("color")
+///         .long("color")
+///         .takes_value(true)
+///         .value_parser(BoolParser))
+///     .get_matches_from(vec!["prog", "--color", "Off"]);
+///
+/// assert_eq!(m.value_of_parsed::<bool, _>("color"), Some(&false));
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BoolParser;
+
+impl ValueParser for BoolParser {
+    type Value = bool;
+
+    fn parse(&self, val: &OsStr) -> Result<bool, String> {
+        match &*try!(utf8(val)).to_ascii_lowercase() {
+            "true" | "yes" | "on" | "1" => Ok(true),
+            "false" | "no" | "off" | "0" => Ok(false),
+            _ => Err(String::from("must be one of true, false, yes, no, on, off, 1 or 0")),
+        }
+    }
+}
+
+/// Parses durations into a [`Duration`], written as numbers followed by a unit (`d`, `h`, `m`,
+/// `s`, `ms`, `us` or `ns`), such as `90s`, `1h30m` or `250ms`. A number without a unit is a
+/// number of seconds.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, DurationParser};
+/// # use std::time::Duration;
+/// let m = App::new("prog")
+///     .arg(Arg::with_name("timeout")
+///         .long("timeout")
+///         .takes_value(true)
+///         .value_parser(DurationParser))
+///     .get_matches_from(vec!["prog", "--timeout", "1m30s"]);
+///
+/// assert_eq!(m.value_of_parsed::<Duration, _>("timeout"), Some(&Duration::from_secs(90)));
+/// ```
+/// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DurationParser;
+
+impl ValueParser for DurationParser {
+    type Value = Duration;
+
+    fn parse(&self, val: &OsStr) -> Result<Duration, String> {
+        let invalid = || String::from("must be a duration, i.e. 90s, 1h30m or 250ms");
+        let s = try!(utf8(val));
+        if let Ok(secs) = s.parse::<u64>() {
+            return Ok(Duration::from_secs(secs));
+        }
+        let is_digit = |c: char| c >= '0' && c <= '9';
+        let too_long = || String::from("is too long");
+        // The whole seconds and the nanoseconds of each part, which are added up separately
+        let (mut secs, mut nanos) = (0u64, 0u64);
+        let mut rest = s;
+        while !rest.is_empty() {
+            let digits = rest.find(|c: char| !is_digit(c)).unwrap_or(rest.len());
+            let n = try!(rest[..digits].parse::<u64>().map_err(|_| invalid()));
+            rest = &rest[digits..];
+            let unit = rest.find(&is_digit).unwrap_or(rest.len());
+            let (n_secs, n_nanos) = match &rest[..unit] {
+                "d" => (n.checked_mul(86400), 0),
+                "h" => (n.checked_mul(3600), 0),
+                "m" => (n.checked_mul(60), 0),
+                "s" => (Some(n), 0),
+                "ms" => (Some(n / 1_000), (n % 1_000) * 1_000_000),
+                "us" => (Some(n / 1_000_000), (n % 1_000_000) * 1_000),
+                "ns" => (Some(n / 1_000_000_000), n % 1_000_000_000),
+                _ => return Err(invalid()),
+            };
+            secs = try!(n_secs.and_then(|s| secs.checked_add(s)).ok_or_else(&too_long));
+            nanos += n_nanos;
+            rest = &rest[unit..];
+        }
+        secs = try!(secs.checked_add(nanos / 1_000_000_000).ok_or_else(&too_long));
+        let total = Duration::new(secs, (nanos % 1_000_000_000) as u32);
+        if s.is_empty() { Err(invalid()) } else { Ok(total) }
+    }
+}
+
+/// Parses the variants of an enum `E` by their names, regardless of case. The names are
+/// converted with the [`FromStr`] implementation of `E`, such as the one generated by
+/// [`arg_enum!`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate clap;
+/// # use clap::{App, Arg, EnumParser};
+/// arg_enum!{
+///     #[derive(Debug, PartialEq)]
+///     enum Level {
+///         Low,
+///         High
+///     }
+/// }
+///
+/// # fn main() {
+/// let m = App::new("prog")
+///     .arg(Arg::with_name("level")
+///         .long("level")
+///         .takes_value(true)
+///         .value_parser(EnumParser::<Level>::new(&Level::variants())))
+///     .get_matches_from(vec!["prog", "--level", "high"]);
+///
+/// assert_eq!(m.value_of_parsed::<Level, _>("level"), Some(&Level::High));
+/// # }
+/// ```
+/// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+/// [`arg_enum!`]: ./macro.arg_enum!.html
+pub struct EnumParser<E> {
+    variants: Vec<&'static str>,
+    _enum: PhantomData<fn() -> E>,
+}
+
+impl<E> EnumParser<E>
+    where E: FromStr + 'static,
+          <E as FromStr>::Err: Display
+{
+    /// Creates a parser accepting the names of the `variants`
+    pub fn new(variants: &[&'static str]) -> Self {
+        EnumParser {
+            variants: variants.to_vec(),
+            _enum: PhantomData,
+        }
+    }
+}
+
+impl<E> Clone for EnumParser<E> {
+    fn clone(&self) -> Self {
+        EnumParser {
+            variants: self.variants.clone(),
+            _enum: PhantomData,
+        }
+    }
+}
+
+impl<E> std_fmt::Debug for EnumParser<E> {
+    fn fmt(&self, f: &mut std_fmt::Formatter) -> std_fmt::Result {
+        f.debug_struct("EnumParser").field("variants", &self.variants).finish()
+    }
+}
+
+impl<E> ValueParser for EnumParser<E>
+    where E: FromStr + Send + Sync + 'static,
+          <E as FromStr>::Err: Display
+{
+    type Value = E;
+
+    fn parse(&self, val: &OsStr) -> Result<E, String> {
+        let s = try!(utf8(val));
+        match self.variants.iter().find(|v| v.eq_ignore_ascii_case(s)) {
+            Some(v) => v.parse::<E>().map_err(|e| e.to_string()),
+            None => Err(format!("must be one of {}", self.variants.join(", "))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsStr;
+    use std::time::Duration;
+
+    use super::{DurationParser, IntParser, ValueParser};
+
+    #[test]
+    fn int_ranges() {
+        let p = IntParser::<i32>::new().min(-5);
+        assert_eq!(p.parse(OsStr::new("-5")), Ok(-5));
+        assert_eq!(p.parse(OsStr::new("-6")), Err(String::from("must be at least -5")));
+        let p = IntParser::<u8>::new().max(10);
+        assert_eq!(p.parse(OsStr::new("11")), Err(String::from("must be at most 10")));
+        assert!(p.parse(OsStr::new("ten")).is_err());
+    }
+
+    #[test]
+    fn durations() {
+        let d = |s| DurationParser.parse(OsStr::new(s));
+        assert_eq!(d("45"), Ok(Duration::from_secs(45)));
+        assert_eq!(d("1d2h"), Ok(Duration::from_secs(93600)));
+        assert_eq!(d("1s500ms"), Ok(Duration::from_millis(1500)));
+        assert_eq!(d("3us"), Ok(Duration::new(0, 3_000)));
+        assert!(d("").is_err());
+        assert!(d("5 s").is_err());
+        assert!(d("s").is_err());
+        assert!(d("1w").is_err());
+    }
+}
@@ -0,0 +1,33 @@
+/// Describes where the values of an argument came from, as returned by
+/// [`ArgMatches::value_source`].
+///
+/// [`ArgMatches::value_source`]: ./struct.ArgMatches.html#method.value_source
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ValueSource {
+    /// The argument was used on the command line
+    CommandLine,
+    /// The values were read from the environment variable set with [`Arg::env`]
+    ///
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    EnvVariable,
+    /// The values were read from a [`ConfigSource`], see [`ArgMatches::config_origin`] for which
+    /// one
+    ///
+    /// [`ConfigSource`]: ./struct.ConfigSource.html
+    /// [`ArgMatches::config_origin`]: ./struct.ArgMatches.html#method.config_origin
+    Config,
+    /// The value is the argument's [`Arg::default_value`]
+    ///
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    DefaultValue,
+    /// The value is a conditional default set with [`Arg::default_value_if`] or
+    /// [`Arg::default_value_ifs`]
+    ///
+    /// [`Arg::default_value_if`]: ./struct.Arg.html#method.default_value_if
+    /// [`Arg::default_value_ifs`]: ./struct.Arg.html#method.default_value_ifs
+    DefaultValueIf,
+}
+
+impl Default for ValueSource {
+    fn default() -> Self { ValueSource::CommandLine }
+}
@@ -0,0 +1,306 @@
+// Std
+use std::fmt;
+
+/// A catalog of the messages `clap` displays to the user, which allows translating or rewording
+/// the built-in error messages, help headings and help descriptions.
+///
+/// Every method has a default implementation which returns the English message `clap` uses
+/// without a catalog, so a catalog only needs to implement the messages it wants to change.
+/// Catalogs are added to an [`App`] with [`App::catalog`], and are used by all of its
+/// subcommands which don't have a catalog of their own.
+///
+/// The arguments passed to the methods are already formatted for display, and may contain color
+/// codes. Error messages return the first sentence of the error, `clap` adds the `error:` label,
+/// the usage string and a pointer to `--help` around it. Numbers are passed as is, so that they
+/// can be pluralized as appropriate for the language.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, MessageCatalog};
+/// struct German;
+///
+/// impl MessageCatalog for German {
+///     fn error_label(&self) -> &str { "Fehler:" }
+///     fn usage_heading(&self) -> &str { "VERWENDUNG:" }
+///     fn help_description(&self) -> &'static str { "Zeigt diese Hilfe an" }
+///     fn wrong_number_of_values(&self, arg: &str, expected: usize, actual: usize) -> String {
+///         format!("Das Argument '{}' erwartet {} Werte, aber {} {} angegeben",
+///                 arg,
+///                 expected,
+///                 actual,
+///                 if actual == 1 { "wurde" } else { "wurden" })
+///     }
+/// }
+///
+/// let res = App::new("prog")
+///     .catalog(German)
+///     .arg(Arg::with_name("point")
+///         .long("point")
+///         .number_of_values(2))
+///     .get_matches_from_safe(vec!["prog", "--point", "1"]);
+///
+/// assert!(res.unwrap_err().message().starts_with("Fehler: Das Argument"));
+/// ```
+/// [`App`]: ./struct.App.html
+/// [`App::catalog`]: ./struct.App.html#method.catalog
+pub trait MessageCatalog: Send + Sync {
+    /// The label which starts every error message
+    fn error_label(&self) -> &str { "error:" }
+
+    /// The heading of the usage string
+    fn usage_heading(&self) -> &str { "USAGE:" }
+
+    /// The heading of the flags in the help message
+    fn flags_heading(&self) -> &str { "FLAGS:" }
+
+    /// The heading of the options in the help message, which also lists the flags when
+    /// [`AppSettings::UnifiedHelpMessage`] is used
+    ///
+    /// [`AppSettings::UnifiedHelpMessage`]: ./enum.AppSettings.html#variant.UnifiedHelpMessage
+    fn options_heading(&self) -> &str { "OPTIONS:" }
+
+    /// The heading of the positional arguments in the help message
+    fn args_heading(&self) -> &str { "ARGS:" }
+
+    /// The heading of the subcommands in the help message
+    fn subcommands_heading(&self) -> &str { "SUBCOMMANDS:" }
+
+    /// The description of the generated help flag, unless one was set with
+    /// [`App::help_message`]. It has to be `'static` because it becomes part of the flag.
+    ///
+    /// [`App::help_message`]: ./struct.App.html#method.help_message
+    fn help_description(&self) -> &'static str { "Prints help information" }
+
+    /// The description of the generated version flag, unless one was set with
+    /// [`App::version_message`]. It has to be `'static` because it becomes part of the flag.
+    ///
+    /// [`App::version_message`]: ./struct.App.html#method.version_message
+    fn version_description(&self) -> &'static str { "Prints version information" }
+
+    /// The description of the generated `help` subcommand
+    fn help_subcommand_description(&self) -> &'static str {
+        "Prints this message or the help of the given subcommand(s)"
+    }
+
+    /// The description of the argument of the generated `help` subcommand
+    fn help_subcommand_arg_description(&self) -> &'static str {
+        "The subcommand whose help message to display"
+    }
+
+    /// The label of an argument's default value in the help message (i.e. `[default: auto]`)
+    fn default_label(&self) -> &str { "default" }
+
+    /// The label of an argument's possible values in help and error messages (i.e.
+    /// `[values: always, never]`)
+    fn values_label(&self) -> &str { "values" }
+
+    /// The label of an argument's aliases in the help message (i.e. `[aliases: col]`)
+    fn aliases_label(&self) -> &str { "aliases" }
+
+    /// The label of an argument's environment variable in the help message (i.e.
+    /// `[env: COLOR=always]`)
+    fn env_label(&self) -> &str { "env" }
+
+    /// The last line of most error messages, where `help` is the help flag
+    fn more_info(&self, help: &str) -> String { format!("For more information try {}", help) }
+
+    /// A suggestion of what the user probably meant to type, such as a value or a flag
+    fn did_you_mean(&self, suggestion: &str) -> String { format!("Did you mean {}?", suggestion) }
+
+    /// A suggestion to move an argument after the subcommand it belongs to
+    fn did_you_mean_in_subcommand(&self, arg: &str, subcommand: &str) -> String {
+        format!("Did you mean to put '{}' after the subcommand '{}'?",
+                arg,
+                subcommand)
+    }
+
+    /// An argument was used with another argument it conflicts with, which is `None` when it's
+    /// not known which one
+    fn argument_conflict(&self, arg: &str, other: Option<&str>) -> String {
+        match other {
+            Some(other) => format!("The argument '{}' cannot be used with '{}'", arg, other),
+            None => {
+                format!("The argument '{}' cannot be used with one or more of the other \
+                         specified arguments",
+                        arg)
+            }
+        }
+    }
+
+    /// An argument requires a value but none was given
+    fn empty_value(&self, arg: &str) -> String {
+        format!("The argument '{}' requires a value but none was supplied", arg)
+    }
+
+    /// A value isn't one of the possible values of an argument
+    fn invalid_value(&self, val: &str, arg: &str, valid_values: &str) -> String {
+        format!("'{}' isn't a valid value for '{}'\n\t[{}: {}]",
+                val,
+                arg,
+                self.values_label(),
+                valid_values)
+    }
+
+    /// A subcommand isn't known
+    fn unknown_subcommand(&self, subcommand: &str) -> String {
+        format!("The subcommand '{}' wasn't recognized", subcommand)
+    }
+
+    /// A hint on how to pass an unknown subcommand as a positional argument instead, where `cmd`
+    /// is the command line which would do so
+    fn unknown_subcommand_hint(&self, cmd: &str) -> String {
+        format!("If you believe you received this message in error, try re-running with '{}'",
+                cmd)
+    }
+
+    /// Required arguments weren't given, they're listed after this message
+    fn missing_required_arguments(&self) -> String {
+        "The following required arguments were not provided:".to_owned()
+    }
+
+    /// A command requires a subcommand but none was given
+    fn missing_subcommand(&self, name: &str) -> String {
+        format!("'{}' requires a subcommand, but one was not provided", name)
+    }
+
+    /// An argument contains invalid UTF-8
+    fn invalid_utf8(&self) -> String {
+        "Invalid UTF-8 was detected in one or more arguments".to_owned()
+    }
+
+    /// A value was given to an argument which doesn't accept any more values
+    fn too_many_values(&self, val: &str, arg: &str) -> String {
+        format!("The value '{}' was provided to '{}', but it wasn't expecting any more values",
+                val,
+                arg)
+    }
+
+    /// An argument received fewer values than its minimum
+    fn too_few_values(&self, arg: &str, min: usize, actual: usize) -> String {
+        format!("The argument '{}' requires at least {} values, but only {} w{} provided",
+                arg,
+                min,
+                actual,
+                if actual > 1 { "ere" } else { "as" })
+    }
+
+    /// An argument received a number of values it doesn't accept
+    fn wrong_number_of_values(&self, arg: &str, expected: usize, actual: usize) -> String {
+        format!("The argument '{}' requires {} values, but {} w{} provided",
+                arg,
+                expected,
+                actual,
+                if actual == 1 { "as" } else { "ere" })
+    }
+
+    /// The number of arguments of a group which were given isn't within the bounds set for the
+    /// group, where `args` are the arguments of the group and `given` the ones which were given
+    fn wrong_number_of_group_args(&self,
+                                  args: &str,
+                                  min: Option<usize>,
+                                  max: Option<usize>,
+                                  given: &[String])
+                                  -> String {
+        let bound = match (min, max) {
+            (Some(min), Some(max)) if min == max => format!("Exactly {} of {} must", min, args),
+            (Some(min), Some(max)) => format!("Between {} and {} of {} must", min, max, args),
+            (Some(min), None) => format!("At least {} of {} must", min, args),
+            (None, Some(max)) => format!("At most {} of {} can", max, args),
+            (None, None) => format!("Any of {} can", args),
+        };
+        match given.len() {
+            0 => format!("{} be used, but none were provided", bound),
+            1 => format!("{} be used, but only {} was provided", bound, given[0]),
+            _ => format!("{} be used, but {} were provided", bound, given.join(", ")),
+        }
+    }
+
+    /// A value was rejected by a validator, or couldn't be parsed into the requested type, where
+    /// `reason` is the message of the validator or parser
+    fn value_validation(&self, arg: Option<&str>, val: Option<&str>, reason: &str) -> String {
+        match (arg, val) {
+            (Some(arg), Some(val)) => format!("Invalid value '{}' for '{}': {}", val, arg, reason),
+            (Some(arg), None) => format!("Invalid value for '{}': {}", arg, reason),
+            _ => format!("Invalid value: {}", reason),
+        }
+    }
+
+    /// The arguments were rejected by a validator of the `App`, where `reason` is the message of
+    /// the validator
+    fn matches_validation(&self, reason: &str) -> String {
+        format!("Invalid arguments: {}", reason)
+    }
+
+    /// An argument which doesn't accept multiple occurrences was given more than once
+    fn unexpected_multiple_usage(&self, arg: &str) -> String {
+        format!("The argument '{}' was provided more than once, but cannot be used multiple \
+                 times",
+                arg)
+    }
+
+    /// An argument isn't known, or isn't valid in the current context
+    fn unknown_argument(&self, arg: &str) -> String {
+        format!("Found argument '{}' which wasn't expected, or isn't valid in this context",
+                arg)
+    }
+
+    /// A [`ConfigSource`] couldn't be parsed, where `reason` is the message of the parser
+    ///
+    /// [`ConfigSource`]: ./struct.ConfigSource.html
+    fn invalid_config(&self, origin: &str, reason: &str) -> String {
+        format!("Invalid configuration in '{}': {}", origin, reason)
+    }
+
+    /// A key in a document describing an [`App`] isn't valid, where `path` is the path of the
+    /// key (i.e. `args[0].short`), or `None` when the whole document isn't valid
+    ///
+    /// [`App`]: ./struct.App.html
+    fn invalid_definition(&self, path: Option<&str>, reason: &str) -> String {
+        match path {
+            Some(path) => format!("Invalid definition at '{}': {}", path, reason),
+            None => format!("Invalid definition: {}", reason),
+        }
+    }
+
+    /// A key of a document describing an [`App`] isn't valid, where `path` is the path of the key
+    /// (i.e. `args[0].short`) and `pos` is its line and column
+    ///
+    /// [`App`]: ./struct.App.html
+    fn invalid_definition_at(&self, path: &str, pos: (usize, usize), reason: &str) -> String {
+        format!("Invalid definition at '{}' (line {}, column {}): {}",
+                path,
+                pos.0,
+                pos.1,
+                reason)
+    }
+
+    /// A document describing an [`App`] couldn't be parsed, where `pos` is the line and column of
+    /// the syntax error when it's known
+    ///
+    /// [`App`]: ./struct.App.html
+    fn invalid_definition_syntax(&self, pos: Option<(usize, usize)>, reason: &str) -> String {
+        match pos {
+            Some((line, col)) => {
+                format!("Invalid definition at line {}, column {}: {}", line, col, reason)
+            }
+            None => format!("Invalid definition: {}", reason),
+        }
+    }
+
+    /// An argument which wasn't defined was requested from the matches
+    fn argument_not_found(&self, arg: &str) -> String {
+        format!("The argument '{}' wasn't found", arg)
+    }
+}
+
+impl fmt::Debug for MessageCatalog {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "MessageCatalog") }
+}
+
+struct DefaultCatalog;
+
+impl MessageCatalog for DefaultCatalog {}
+
+// The catalog used by apps and errors which weren't given one
+pub fn default_catalog() -> &'static MessageCatalog { &DefaultCatalog }
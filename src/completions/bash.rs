@@ -3,7 +3,7 @@ use std::io::Write;
 
 // Internal
 use app::parser::Parser;
-use args::{ArgSettings, OptBuilder};
+use args::{ArgSettings, OptBuilder, ValueHint};
 use completions;
 
 pub struct BashGen<'a, 'b>
@@ -51,7 +51,7 @@ impl<'a, 'b> BashGen<'a, 'b> {
                     COMPREPLY=()
                     ;;
             esac
-            COMPREPLY=( $(compgen -W \"${{opts}}\" -- ${{cur}}) )
+            COMPREPLY=( $(compgen -W \"${{opts}}\" -- ${{cur}}){name_pos_vals} )
             return 0
             ;;
         {subcmd_details}
@@ -64,6 +64,8 @@ complete -F _{name} -o bashdefault -o default {name}
                    name_opts = self.all_options_for_path(self.p.meta.bin_name.as_ref().unwrap()),
                    name_opts_details =
                        self.option_details_for_path(self.p.meta.bin_name.as_ref().unwrap()),
+                   name_pos_vals =
+                       self.positional_vals_for_path(self.p.meta.bin_name.as_ref().unwrap()),
                    subcmds = self.all_subcommands(),
                    subcmd_details = self.subcommand_details())
                .as_bytes());
@@ -107,14 +109,15 @@ complete -F _{name} -o bashdefault -o default {name}
                     COMPREPLY=()
                     ;;
             esac
-            COMPREPLY=( $(compgen -W \"${{opts}}\" -- ${{cur}}) )
+            COMPREPLY=( $(compgen -W \"${{opts}}\" -- ${{cur}}){pos_vals} )
             return 0
             ;;",
                                   subcmd_dets,
                                   subcmd = sc.replace("-", "__"),
                                   sc_opts = self.all_options_for_path(&*sc),
                                   level = sc.split("__").map(|_| 1).fold(0, |acc, n| acc + n),
-                                  opts_details = self.option_details_for_path(&*sc));
+                                  opts_details = self.option_details_for_path(&*sc),
+                                  pos_vals = self.positional_vals_for_path(&*sc));
         }
 
         subcmd_dets
@@ -153,6 +156,25 @@ complete -F _{name} -o bashdefault -o default {name}
         opts
     }
 
+    // The completions of the positional args whose value hints bash can complete natively,
+    // which are offered along with the options and subcommands
+    fn positional_vals_for_path(&self, path: &str) -> String {
+        debugln!("BashGen::positional_vals_for_path: path={}", path);
+        let mut p = self.p;
+        for sc in path.split("__").skip(1) {
+            debugln!("BashGen::positional_vals_for_path:iter: sc={}", sc);
+            p = &find_subcmd!(p, sc).unwrap().p;
+        }
+        let mut actions: Vec<&str> = vec![];
+        for action in p.positionals().filter_map(|pos| compgen_action_for(pos.v.value_hint)) {
+            if !actions.contains(&action) {
+                actions.push(action);
+            }
+        }
+        actions.iter().fold(String::new(),
+                            |acc, a| format!("{} $(compgen {} -- ${{cur}})", acc, a))
+    }
+
     fn vals_for(&self, o: &OptBuilder) -> String {
         debugln!("BashGen::vals_for: o={}", o.b.name);
         use args::AnyArg;
@@ -161,6 +183,9 @@ complete -F _{name} -o bashdefault -o default {name}
         if let Some(vals) = o.possible_vals() {
             needs_quotes = false;
             ret = format!("$(compgen -W \"{}\" -- ${{cur}})", vals.join(" "));
+        } else if let Some(action) = compgen_action_for(o.value_hint()) {
+            needs_quotes = false;
+            ret = format!("$(compgen {} -- ${{cur}})", action);
         } else if let Some(vec) = o.val_names() {
             let mut it = vec.iter().peekable();
             while let Some((_, val)) = it.next() {
@@ -228,3 +253,15 @@ complete -F _{name} -o bashdefault -o default {name}
         opts
     }
 }
+
+// Maps a value hint to the `compgen` action which completes it natively, if bash has one
+fn compgen_action_for(hint: ValueHint) -> Option<&'static str> {
+    match hint {
+        ValueHint::AnyPath | ValueHint::FilePath | ValueHint::ExecutablePath => Some("-f"),
+        ValueHint::DirPath => Some("-d"),
+        ValueHint::CommandName | ValueHint::CommandWithArguments => Some("-c"),
+        ValueHint::Username => Some("-u"),
+        ValueHint::Hostname => Some("-A hostname"),
+        _ => None,
+    }
+}
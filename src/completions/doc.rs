@@ -0,0 +1,152 @@
+// Std
+use std::fmt::Display;
+
+// Internal
+use app::App;
+use app::parser::Parser;
+use app::AppSettings as AS;
+use args::AnyArg;
+use args::settings::ArgSettings;
+
+// An argument as it is listed by the documentation generators, without any markup
+pub struct DocArg<'e> {
+    // The switches and values, i.e. "-c, --config <FILE>"
+    pub name: String,
+    pub help: Option<String>,
+    pub default_val: Option<String>,
+    pub possible_vals: Vec<&'e str>,
+}
+
+// A table of arguments, titled after either their kind or their `help_heading`
+pub struct DocSection<'e> {
+    pub title: &'e str,
+    pub args: Vec<DocArg<'e>>,
+}
+
+// The sections listing the (non-hidden) arguments of the parser, in the order of the help message
+pub fn arg_sections<'n, 'e>(p: &Parser<'n, 'e>) -> Vec<DocSection<'e>> {
+    debugln!("doc::arg_sections;");
+    let mut sections = vec![];
+    push_section(&mut sections,
+                 "Flags",
+                 p.flags()
+                     .filter(|f| f.b.help_heading.is_none())
+                     .filter_map(doc_arg)
+                     .collect());
+    push_section(&mut sections,
+                 "Options",
+                 p.opts()
+                     .filter(|o| o.b.help_heading.is_none())
+                     .filter_map(doc_arg)
+                     .collect());
+    push_section(&mut sections,
+                 "Arguments",
+                 p.positionals()
+                     .filter(|p| p.b.help_heading.is_none())
+                     .filter_map(doc_arg)
+                     .collect());
+    for &heading in &p.help_headings {
+        let args = p.flags()
+            .filter(|f| f.b.help_heading == Some(heading))
+            .filter_map(doc_arg)
+            .chain(p.opts()
+                       .filter(|o| o.b.help_heading == Some(heading))
+                       .filter_map(doc_arg))
+            .chain(p.positionals()
+                       .filter(|p| p.b.help_heading == Some(heading))
+                       .filter_map(doc_arg))
+            .collect();
+        push_section(&mut sections, heading, args);
+    }
+    sections
+}
+
+// The subcommands of the parser which aren't hidden
+pub fn visible_subcommands<'p, 'n, 'e>(p: &'p Parser<'n, 'e>) -> Vec<&'p App<'n, 'e>> {
+    p.subcommands.iter().filter(|s| !s.p.is_set(AS::Hidden)).collect()
+}
+
+// The text of an anchor linking to the documentation of a (sub)command, i.e. "myapp-test" for
+// "myapp test"
+pub fn anchor(bin_name: &str) -> String {
+    bin_name
+        .chars()
+        .map(|c| if c.is_alphanumeric() {
+                 c.to_lowercase().next().unwrap_or(c)
+             } else {
+                 '-'
+             })
+        .collect()
+}
+
+// The description of the parser, preferring the long one like the man page does
+pub fn description(p: &Parser) -> Option<String> {
+    p.meta.long_about.or(p.meta.about).map(|d| d.replace("{n}", "\n"))
+}
+
+fn push_section<'e>(sections: &mut Vec<DocSection<'e>>, title: &'e str, args: Vec<DocArg<'e>>) {
+    if !args.is_empty() {
+        sections.push(DocSection {
+                          title: title,
+                          args: args,
+                      });
+    }
+}
+
+fn doc_arg<'n, 'e, A>(a: &A) -> Option<DocArg<'e>>
+    where A: AnyArg<'n, 'e> + Display
+{
+    debugln!("doc::doc_arg: name={}", a.name());
+    if a.is_set(ArgSettings::Hidden) {
+        return None;
+    }
+    let mut name = vec![a.short().map(|s| format!("-{}", s)), a.long().map(|l| format!("--{}", l))]
+        .into_iter()
+        .filter_map(|s| s)
+        .collect::<Vec<_>>()
+        .join(", ");
+    if !a.has_switch() {
+        // Positional arguments are displayed the same way as in the help message
+        name = a.to_string();
+    } else if a.takes_value() {
+        name.push(if a.is_set(ArgSettings::RequireEquals) {
+                      '='
+                  } else {
+                      ' '
+                  });
+        name.push_str(&val_names(a));
+    }
+    Some(DocArg {
+             name: name,
+             help: a.long_help()
+                 .or_else(|| a.help())
+                 .map(|h| h.replace("{n}", "\n")),
+             default_val: if a.is_set(ArgSettings::HideDefaultValue) {
+                 None
+             } else {
+                 a.default_val().map(|v| v.to_string_lossy().into_owned())
+             },
+             possible_vals: if a.is_set(ArgSettings::HidePossibleValues) {
+                 vec![]
+             } else {
+                 a.possible_vals().map_or(vec![], |pv| pv.to_vec())
+             },
+         })
+}
+
+// The values an option takes, i.e. `<FILE>...`
+fn val_names<'n, 'e, A>(a: &A) -> String
+    where A: AnyArg<'n, 'e>
+{
+    let names: Vec<String> = if let Some(vn) = a.val_names() {
+        vn.values().map(|n| format!("<{}>", n)).collect()
+    } else {
+        let n = format!("<{}>", a.name());
+        (0..a.num_vals().unwrap_or(1)).map(|_| n.clone()).collect()
+    };
+    let mut ret = names.join(" ");
+    if a.is_set(ArgSettings::Multiple) && names.len() == 1 {
+        ret.push_str("...");
+    }
+    ret
+}
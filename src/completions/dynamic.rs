@@ -0,0 +1,158 @@
+// Std
+use std::ffi::OsStr;
+use std::io::Write;
+
+// Internal
+use app::parser::{Parser, ParseResult};
+use app::AppSettings as AS;
+use args::{AnyArg, ArgMatcher, Valued};
+use args::settings::ArgSettings;
+use completions::Shell;
+
+pub struct DynamicGen<'a, 'b>
+    where 'a: 'b
+{
+    p: &'b Parser<'a, 'b>,
+}
+
+impl<'a, 'b> DynamicGen<'a, 'b> {
+    pub fn new(p: &'b Parser<'a, 'b>) -> Self { DynamicGen { p: p } }
+
+    pub fn generate<W: Write>(&self, for_shell: Shell, buf: &mut W) {
+        let name = self.p.meta.bin_name.as_ref().unwrap();
+        let shim = match for_shell {
+            Shell::Bash => {
+                format!("_{name}() {{
+    local IFS=$'\\n'
+    COMPREPLY=( $(_CLAP_COMPLETE_INDEX=${{COMP_CWORD}} \"${{COMP_WORDS[@]}}\" 2>/dev/null) )
+    return 0
+}}
+
+complete -F _{name} -o bashdefault -o default {name}
+",
+                        name = name)
+            }
+            Shell::Zsh => {
+                format!("#compdef {name}
+
+_{name}() {{
+    local -a candidates
+    candidates=(\"${{(@f)$(_CLAP_COMPLETE_INDEX=$((CURRENT - 1)) \"${{words[@]}}\" 2>/dev/null)}}\")
+    if [[ -n \"${{candidates[1]}}\" ]]; then
+        compadd -a candidates
+    else
+        _files
+    fi
+}}
+
+_{name} \"$@\"",
+                        name = name)
+            }
+            Shell::Fish => {
+                format!("function __{name}_complete
+    set -l tokens (commandline -opc)
+    env _CLAP_COMPLETE_INDEX=(count $tokens) $tokens (commandline -ct) 2>/dev/null
+end
+
+complete -c {name} -f -a \"(__{name}_complete)\"
+",
+                        name = name)
+            }
+            _ => panic!("Dynamic completions are not supported for {}", for_shell),
+        };
+
+        w!(buf, shim.as_bytes());
+    }
+}
+
+// Gets the candidates for the word currently being completed (`current`), once the parser `p` has
+// consumed all the words before it. `needs_val_of` and `pos_counter` are the state the parser was
+// left in, i.e. whether an option is still waiting for a value, or which positional argument is
+// next.
+pub fn candidates<'a, 'b>(p: &Parser<'a, 'b>,
+                          matcher: &ArgMatcher<'a>,
+                          needs_val_of: ParseResult<'a>,
+                          pos_counter: usize,
+                          current: &OsStr)
+                          -> Vec<String> {
+    debugln!("dynamic::candidates: current={:?}, needs_val_of={:?}", current, needs_val_of);
+    let cur = current.to_string_lossy();
+    if let ParseResult::Opt(name) = needs_val_of {
+        if !cur.starts_with('-') {
+            let o = find_by_name!(p, name, opts, iter).expect(::INTERNAL_ERROR_MSG);
+            return values_of(&o.v, &*cur);
+        }
+    }
+
+    if cur.starts_with("--") {
+        if let Some(eq) = cur.find('=') {
+            let long = &cur[2..eq];
+            return p.opts()
+                .find(|o| o.s.long == Some(long))
+                .map(|o| {
+                         values_of(&o.v, &cur[eq + 1..])
+                             .into_iter()
+                             .map(|v| format!("--{}={}", long, v))
+                             .collect()
+                     })
+                .unwrap_or_else(Vec::new);
+        }
+    }
+
+    let mut ret = vec![];
+    if cur.starts_with('-') {
+        switches_of(p, matcher, &mut ret);
+    } else {
+        for sc in p.subcommands.iter().filter(|s| !s.p.is_set(AS::Hidden)) {
+            ret.push(sc.p.meta.name.clone());
+            if let Some(ref aliases) = sc.p.meta.aliases {
+                ret.extend(aliases.iter().filter(|&&(_, vis)| vis).map(|&(n, _)| n.to_owned()));
+            }
+        }
+        if let Some(pos) = p.positionals.get(pos_counter) {
+            ret.extend(values_of(&pos.v, &*cur));
+        }
+    }
+    ret.into_iter().filter(|c| c.starts_with(&*cur)).collect()
+}
+
+// Adds every visible short and long switch of `p` to `ret`, leaving out those which were already
+// used and can't be used again
+fn switches_of<'a, 'b>(p: &Parser<'a, 'b>, matcher: &ArgMatcher<'a>, ret: &mut Vec<String>) {
+    fn add<'n, 'e, A>(a: &A, matcher: &ArgMatcher<'n>, ret: &mut Vec<String>)
+        where A: AnyArg<'n, 'e>
+    {
+        if a.is_set(ArgSettings::Hidden) ||
+           (matcher.contains(a.name()) && !a.is_set(ArgSettings::Multiple)) {
+            return;
+        }
+        if let Some(l) = a.long() {
+            ret.push(format!("--{}", l));
+        }
+        if let Some(aliases) = a.aliases() {
+            ret.extend(aliases.iter().map(|al| format!("--{}", al)));
+        }
+        if let Some(s) = a.short() {
+            ret.push(format!("-{}", s));
+        }
+    }
+    for f in p.flags() {
+        add(f, matcher, ret);
+    }
+    for o in p.opts() {
+        add(o, matcher, ret);
+    }
+}
+
+// Gets the candidate values of an argument, either from the user's completer or the possible
+// values, filtered by the partial value the user has typed so far
+fn values_of(v: &Valued, partial: &str) -> Vec<String> {
+    let vals = if let Some(ref f) = v.completer {
+        f(partial)
+    } else if let Some(ref pv) = v.possible_vals {
+        pv.iter().map(|&v| v.to_owned()).collect()
+    } else {
+        vec![]
+    };
+    vals.into_iter().filter(|c| c.starts_with(partial)).collect()
+}
@@ -0,0 +1,184 @@
+// Std
+use std::io::Write;
+
+// Internal
+use app::parser::Parser;
+use args::ValueHint;
+use INTERNAL_ERROR_MSG;
+
+pub struct ElvishGen<'a, 'b>
+    where 'a: 'b
+{
+    p: &'b Parser<'a, 'b>,
+}
+
+impl<'a, 'b> ElvishGen<'a, 'b> {
+    pub fn new(p: &'b Parser<'a, 'b>) -> Self { ElvishGen { p: p } }
+
+    pub fn generate_to<W: Write>(&self, buf: &mut W) {
+        let bin_name = self.p.meta.bin_name.as_ref().unwrap();
+
+        let (subcommands_cases, values_cases) = generate_inner(self.p, "");
+        let values_cases = if values_cases.is_empty() {
+            String::from("[&]")
+        } else {
+            format!("[{}\n    ]", values_cases)
+        };
+
+        let result = format!(r#"
+use builtin;
+use path;
+use str;
+
+set edit:completion:arg-completer['{bin_name}'] = {{|@words|
+    fn spaces {{|n|
+        builtin:repeat $n ' ' | str:join ''
+    }}
+    fn cand {{|text desc|
+        edit:complex-candidate $text &display=$text' '(spaces (- 14 (wcswidth $text)))$desc
+    }}
+    var command = '{bin_name}'
+    for word $words[1..-1] {{
+        if (str:has-prefix $word '-') {{
+            break
+        }}
+        set command = $command';'$word
+    }}
+    var completions = [{subcommands_cases}
+    ]
+    var values = {values_cases}
+    var prev = $command';'$words[-2]
+    if (has-key $values $prev) {{
+        $values[$prev]
+    }} else {{
+        $completions[$command]
+    }}
+}}
+"#,
+                             bin_name = escape_string(bin_name),
+                             subcommands_cases = subcommands_cases,
+                             values_cases = values_cases);
+
+        w!(buf, result.as_bytes());
+    }
+}
+
+// Escapes a string for use inside of an Elvish single quoted string
+fn escape_string(string: &str) -> String { string.replace("'", "''") }
+
+// Maps a value hint to a function which completes it natively, if Elvish has one
+fn value_completion(hint: ValueHint) -> Option<&'static str> {
+    match hint {
+        ValueHint::AnyPath | ValueHint::FilePath | ValueHint::ExecutablePath => {
+            Some("{ edit:complete-filename $words[-1] }")
+        }
+        ValueHint::DirPath => {
+            Some("{ edit:complete-filename $words[-1] | \
+                   each {|c| if (path:is-dir $c[stem]) { put $c } } }")
+        }
+        _ => None,
+    }
+}
+
+fn get_tooltip<T: ToString>(help: Option<&str>, data: T) -> String {
+    match help {
+        Some(help) => escape_string(help),
+        _ => escape_string(&data.to_string()),
+    }
+}
+
+// Returns a tuple of (subcommands_cases, values_cases) where subcommands_cases maps each
+// `;` separated command path to the candidates for that command, and values_cases maps each
+// `command;--option` pair to a function producing the candidates for that option's value
+fn generate_inner<'a, 'b, 'p>(p: &'p Parser<'a, 'b>, previous_command_name: &str) -> (String, String) {
+    debugln!("ElvishGen::generate_inner;");
+    let command_name = if previous_command_name.is_empty() {
+        p.meta.bin_name.as_ref().expect(INTERNAL_ERROR_MSG).clone()
+    } else {
+        format!("{};{}", previous_command_name, &p.meta.name)
+    };
+
+    let mut completions = String::new();
+    let mut values_cases = String::new();
+    let preamble = String::from("\n            cand ");
+
+    for option in p.opts() {
+        let values = if let Some(ref pv) = option.v.possible_vals {
+            Some(format!("{{ put {} }}",
+                         pv.iter()
+                             .map(|v| format!("'{}'", escape_string(v)))
+                             .collect::<Vec<_>>()
+                             .join(" ")))
+        } else {
+            value_completion(option.v.value_hint).map(String::from)
+        };
+        if let Some(data) = option.s.short {
+            let tooltip = get_tooltip(option.b.help, data);
+            completions.push_str(&preamble);
+            completions.push_str(format!("-{} '{}'", data, tooltip).as_str());
+            if let Some(ref vals) = values {
+                values_cases.push_str(&format!("\n        &'{};-{}'= {}",
+                                               escape_string(&command_name),
+                                               data,
+                                               vals));
+            }
+        }
+        if let Some(data) = option.s.long {
+            let tooltip = get_tooltip(option.b.help, data);
+            completions.push_str(&preamble);
+            completions.push_str(format!("--{} '{}'", data, tooltip).as_str());
+            if let Some(ref vals) = values {
+                values_cases.push_str(&format!("\n        &'{};--{}'= {}",
+                                               escape_string(&command_name),
+                                               escape_string(data),
+                                               vals));
+            }
+        }
+    }
+
+    for flag in p.flags() {
+        if let Some(data) = flag.s.short {
+            let tooltip = get_tooltip(flag.b.help, data);
+            completions.push_str(&preamble);
+            completions.push_str(format!("-{} '{}'", data, tooltip).as_str());
+        }
+        if let Some(data) = flag.s.long {
+            let tooltip = get_tooltip(flag.b.help, data);
+            completions.push_str(&preamble);
+            completions.push_str(format!("--{} '{}'", data, tooltip).as_str());
+        }
+    }
+
+    // Positional args can't be told apart by the previous word, so the functions completing
+    // their values are called along with the other candidates of the command
+    let mut hints = vec![];
+    for hint in p.positionals().filter_map(|pos| value_completion(pos.v.value_hint)) {
+        if !hints.contains(&hint) {
+            hints.push(hint);
+            completions.push_str("\n            ");
+            completions.push_str(hint);
+        }
+    }
+
+    for subcommand in &p.subcommands {
+        let data = &subcommand.p.meta.name;
+        let tooltip = get_tooltip(subcommand.p.meta.about, data);
+        completions.push_str(&preamble);
+        completions.push_str(format!("{} '{}'", data, tooltip).as_str());
+    }
+
+    let mut subcommands_cases = format!(r"
+        &'{}'= {{{}
+        }}",
+                                        escape_string(&command_name),
+                                        completions);
+
+    for subcommand in &p.subcommands {
+        let (subcommand_subcommands_cases, subcommand_values_cases) =
+            generate_inner(&subcommand.p, &command_name);
+        subcommands_cases.push_str(&subcommand_subcommands_cases);
+        values_cases.push_str(&subcommand_values_cases);
+    }
+
+    (subcommands_cases, values_cases)
+}
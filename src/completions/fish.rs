@@ -4,6 +4,7 @@ use std::io::Write;
 
 // Internal
 use app::parser::Parser;
+use args::ValueHint;
 
 pub struct FishGen<'a, 'b>
     where 'a: 'b
@@ -71,6 +72,9 @@ fn gen_fish_inner(root_command: &str, comp_gen: &FishGen, parent_cmds: &str, buf
         }
         if let Some(ref data) = option.v.possible_vals {
             template.push_str(format!(" -r -f -a \"{}\"", data.join(" ")).as_str());
+        } else if option.v.value_hint != ValueHint::Unknown {
+            template.push_str(" -r");
+            template.push_str(value_completion(option.v.value_hint));
         }
         buffer.push_str(template.as_str());
         buffer.push_str("\n");
@@ -91,6 +95,19 @@ fn gen_fish_inner(root_command: &str, comp_gen: &FishGen, parent_cmds: &str, buf
         buffer.push_str("\n");
     }
 
+    for positional in comp_gen.p.positionals() {
+        if positional.v.value_hint == ValueHint::Unknown {
+            continue;
+        }
+        let mut template = basic_template.clone();
+        if let Some(data) = positional.b.help {
+            template.push_str(format!(" -d \"{}\"", data).as_str());
+        }
+        template.push_str(value_completion(positional.v.value_hint));
+        buffer.push_str(template.as_str());
+        buffer.push_str("\n");
+    }
+
     for subcommand in &comp_gen.p.subcommands {
         let mut template = basic_template.clone();
         template.push_str(" -f");
@@ -114,3 +131,19 @@ fn gen_fish_inner(root_command: &str, comp_gen: &FishGen, parent_cmds: &str, buf
         gen_fish_inner(root_command, &sub_comp_gen, &sub_parent_cmds, buffer);
     }
 }
+
+// Maps a value hint to the arguments of `complete` which complete it natively. Options also need
+// `-r` so the value is completed after them.
+fn value_completion(hint: ValueHint) -> &'static str {
+    match hint {
+        ValueHint::Unknown => "",
+        ValueHint::AnyPath | ValueHint::FilePath | ValueHint::ExecutablePath => " -F",
+        ValueHint::DirPath => " -f -a \"(__fish_complete_directories)\"",
+        ValueHint::CommandName |
+        ValueHint::CommandString |
+        ValueHint::CommandWithArguments => " -f -a \"(__fish_complete_command)\"",
+        ValueHint::Username => " -f -a \"(__fish_complete_users)\"",
+        ValueHint::Hostname => " -f -a \"(__fish_print_hostnames)\"",
+        ValueHint::Other | ValueHint::Url | ValueHint::EmailAddress => " -f",
+    }
+}
@@ -0,0 +1,124 @@
+// Std
+use std::cmp;
+use std::io::Write;
+
+// Internal
+use app::parser::Parser;
+use app::usage;
+use completions::doc::{self, DocArg};
+
+pub struct HtmlGen<'a, 'b>
+    where 'a: 'b
+{
+    p: &'b Parser<'a, 'b>,
+}
+
+impl<'a, 'b> HtmlGen<'a, 'b> {
+    pub fn new(p: &'b Parser<'a, 'b>) -> Self { HtmlGen { p: p } }
+
+    pub fn generate_to<W: Write>(&self, buf: &mut W) {
+        let mut buffer = String::new();
+        gen_command(self.p, 1, &mut buffer);
+        w!(buf, buffer.as_bytes());
+    }
+}
+
+// Documents a (sub)command in a `<section>` which contains the sections of its (non-hidden)
+// subcommands, recursively, with headings getting smaller the deeper the subcommand is
+fn gen_command(p: &Parser, level: usize, buffer: &mut String) {
+    debugln!("HtmlGen::gen_command: name={}", p.meta.name);
+    let bin_name = p.meta.bin_name.as_ref().unwrap();
+    let title = match p.meta.version {
+        Some(v) if level == 1 => format!("{} {}", bin_name, v),
+        _ => bin_name.clone(),
+    };
+    let h = cmp::min(level, 6);
+    buffer.push_str(&format!("<section id=\"{}\">\n", doc::anchor(bin_name)));
+    buffer.push_str(&format!("<h{}>{}</h{}>\n", h, escape(&title), h));
+
+    if let Some(desc) = doc::description(p) {
+        buffer.push_str(&format!("<p>{}</p>\n", text(&desc)));
+    }
+
+    buffer.push_str("<p><strong>Usage:</strong></p>\n");
+    buffer.push_str(&format!("<pre><code>{}</code></pre>\n",
+                             escape(&usage::create_usage_no_title(p, &[]))));
+
+    for section in doc::arg_sections(p) {
+        buffer.push_str(&format!("<p><strong>{}:</strong></p>\n", escape(section.title)));
+        args_table(&section.args, buffer);
+    }
+
+    let scs = doc::visible_subcommands(p);
+    if !scs.is_empty() {
+        buffer.push_str("<p><strong>Subcommands:</strong></p>\n");
+        buffer.push_str("<table>\n<thead>\n<tr><th>Subcommand</th><th>Description</th></tr>\n\
+                         </thead>\n<tbody>\n");
+        for sc in &scs {
+            buffer.push_str(&format!("<tr><td><a href=\"#{}\"><code>{}</code></a></td><td>{}</td>\
+                                      </tr>\n",
+                                     doc::anchor(sc.p.meta.bin_name.as_ref().unwrap()),
+                                     escape(&sc.p.meta.name),
+                                     text(sc.p.meta.about.unwrap_or(""))));
+        }
+        buffer.push_str("</tbody>\n</table>\n");
+    }
+
+    if let Some(more) = p.meta.more_help {
+        buffer.push_str(&format!("<p>{}</p>\n", text(&more.replace("{n}", "\n"))));
+    }
+
+    for sc in scs {
+        gen_command(&sc.p, level + 1, buffer);
+    }
+    buffer.push_str("</section>\n");
+}
+
+// A table of arguments, where the columns for default and possible values are only included if
+// at least one of the arguments has them
+fn args_table(args: &[DocArg], buffer: &mut String) {
+    let defaults = args.iter().any(|a| a.default_val.is_some());
+    let possible = args.iter().any(|a| !a.possible_vals.is_empty());
+
+    buffer.push_str("<table>\n<thead>\n<tr><th>Argument</th><th>Description</th>");
+    if defaults {
+        buffer.push_str("<th>Default</th>");
+    }
+    if possible {
+        buffer.push_str("<th>Possible values</th>");
+    }
+    buffer.push_str("</tr>\n</thead>\n<tbody>\n");
+
+    for a in args {
+        buffer.push_str(&format!("<tr><td>{}</td><td>{}</td>",
+                                 code(&a.name),
+                                 text(a.help.as_ref().map_or("", |h| &**h))));
+        if defaults {
+            buffer.push_str(&format!("<td>{}</td>",
+                                     a.default_val.as_ref().map_or(String::new(), |v| code(v))));
+        }
+        if possible {
+            buffer.push_str(&format!("<td>{}</td>",
+                                     a.possible_vals
+                                         .iter()
+                                         .map(|v| code(v))
+                                         .collect::<Vec<_>>()
+                                         .join(", ")));
+        }
+        buffer.push_str("</tr>\n");
+    }
+    buffer.push_str("</tbody>\n</table>\n");
+}
+
+// Escapes text so that HTML displays it verbatim
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Escapes text for a paragraph or a table cell, keeping its line breaks
+fn text(s: &str) -> String { escape(s).replace('\n', "<br>\n") }
+
+fn code(s: &str) -> String { format!("<code>{}</code>", escape(s)) }
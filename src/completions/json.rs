@@ -0,0 +1,256 @@
+// Std
+use std::io::Write;
+
+// Third Party
+use serde_json::{self, Map, Value};
+
+// Internal
+use app::parser::Parser;
+use app::definition::SCHEMA_VERSION;
+use app::AppSettings as AS;
+use app::usage;
+use args::{AnyArg, ArgGroup};
+use args::settings::ArgSettings;
+
+// The settings which can be set by users, internal settings are never exported
+const APP_SETTINGS: &'static [(AS, &'static str)] = &[
+    (AS::ArgRequiredElseHelp, "ArgRequiredElseHelp"),
+    (AS::ArgsNegateSubcommands, "ArgsNegateSubcommands"),
+    (AS::AllowInvalidUtf8, "AllowInvalidUtf8"),
+    (AS::AllowLeadingHyphen, "AllowLeadingHyphen"),
+    (AS::AllowExternalSubcommands, "AllowExternalSubcommands"),
+    (AS::AllowNegativeNumbers, "AllowNegativeNumbers"),
+    (AS::ColorAlways, "ColorAlways"),
+    (AS::ColorNever, "ColorNever"),
+    (AS::ColoredHelp, "ColoredHelp"),
+    (AS::DeriveDisplayOrder, "DeriveDisplayOrder"),
+    (AS::DontCollapseArgsInUsage, "DontCollapseArgsInUsage"),
+    (AS::DontDelimitTrailingValues, "DontDelimitTrailingValues"),
+    (AS::DisableHelpSubcommand, "DisableHelpSubcommand"),
+    (AS::DisableVersion, "DisableVersion"),
+    (AS::DynamicCompletion, "DynamicCompletion"),
+    (AS::GlobalVersion, "GlobalVersion"),
+    (AS::Hidden, "Hidden"),
+    (AS::HidePossibleValuesInHelp, "HidePossibleValuesInHelp"),
+    (AS::InferSubcommands, "InferSubcommands"),
+    (AS::LowIndexMultiplePositional, "LowIndexMultiplePositional"),
+    (AS::NoBinaryName, "NoBinaryName"),
+    (AS::NextLineHelp, "NextLineHelp"),
+    (AS::StrictUtf8, "StrictUtf8"),
+    (AS::SubcommandsNegateReqs, "SubcommandsNegateReqs"),
+    (AS::SubcommandRequired, "SubcommandRequired"),
+    (AS::SubcommandRequiredElseHelp, "SubcommandRequiredElseHelp"),
+    (AS::TrailingVarArg, "TrailingVarArg"),
+    (AS::UnifiedHelpMessage, "UnifiedHelpMessage"),
+    (AS::VersionlessSubcommands, "VersionlessSubcommands"),
+    (AS::WaitOnError, "WaitOnError"),
+];
+
+const ARG_SETTINGS: &'static [(ArgSettings, &'static str)] = &[
+    (ArgSettings::Required, "Required"),
+    (ArgSettings::Multiple, "Multiple"),
+    (ArgSettings::Global, "Global"),
+    (ArgSettings::EmptyValues, "EmptyValues"),
+    (ArgSettings::Hidden, "Hidden"),
+    (ArgSettings::TakesValue, "TakesValue"),
+    (ArgSettings::UseValueDelimiter, "UseValueDelimiter"),
+    (ArgSettings::NextLineHelp, "NextLineHelp"),
+    (ArgSettings::RequireDelimiter, "RequireDelimiter"),
+    (ArgSettings::HidePossibleValues, "HidePossibleValues"),
+    (ArgSettings::AllowLeadingHyphen, "AllowLeadingHyphen"),
+    (ArgSettings::RequireEquals, "RequireEquals"),
+    (ArgSettings::Last, "Last"),
+    (ArgSettings::HideDefaultValue, "HideDefaultValue"),
+    (ArgSettings::HideEnvValues, "HideEnvValues"),
+    (ArgSettings::CaseInsensitive, "CaseInsensitive"),
+    (ArgSettings::RequiredUnlessAll, "RequiredUnlessAll"),
+];
+
+pub struct JsonGen<'a, 'b>
+    where 'a: 'b
+{
+    p: &'b Parser<'a, 'b>,
+}
+
+impl<'a, 'b> JsonGen<'a, 'b> {
+    pub fn new(p: &'b Parser<'a, 'b>) -> Self { JsonGen { p: p } }
+
+    pub fn generate(&self) -> Value {
+        let mut root = Map::new();
+        root.insert("schema_version".into(), Value::from(SCHEMA_VERSION));
+        if let Value::Object(app) = app_to_json(self.p) {
+            root.extend(app);
+        }
+        Value::Object(root)
+    }
+
+    pub fn generate_to<W: Write>(&self, buf: &mut W) {
+        let json = serde_json::to_string_pretty(&self.generate())
+            .expect("Failed to serialize the App to JSON");
+        w!(buf, json.as_bytes());
+    }
+}
+
+fn app_to_json(p: &Parser) -> Value {
+    debugln!("JsonGen::app_to_json: name={}", p.meta.name);
+    let mut obj = Map::new();
+    obj.insert("name".into(), Value::from(&*p.meta.name));
+    obj.insert("bin_name".into(), opt_str(p.meta.bin_name.as_ref().map(|s| &**s)));
+    obj.insert("version".into(), opt_str(p.meta.version));
+    obj.insert("long_version".into(), opt_str(p.meta.long_version));
+    obj.insert("author".into(), opt_str(p.meta.author));
+    obj.insert("about".into(), opt_str(p.meta.about));
+    obj.insert("long_about".into(), opt_str(p.meta.long_about));
+    obj.insert("before_help".into(), opt_str(p.meta.pre_help));
+    obj.insert("after_help".into(), opt_str(p.meta.more_help));
+    obj.insert("help_heading".into(), opt_str(p.meta.help_heading));
+    obj.insert("usage".into(),
+               Value::from(usage::create_usage_no_title(p, &[])));
+    obj.insert("aliases".into(), aliases(p.meta.aliases.as_ref()));
+    obj.insert("settings".into(),
+               Value::Array(APP_SETTINGS.iter()
+                                .filter(|&&(s, _)| p.is_set(s))
+                                .map(|&(_, n)| Value::from(n))
+                                .collect()));
+
+    let mut args = vec![];
+    for f in p.flags() {
+        let mut arg = arg_to_json(f, "flag");
+        arg.insert("aliases".into(), aliases(f.s.aliases.as_ref()));
+        arg.insert("help_heading".into(), opt_str(f.b.help_heading));
+        args.push(Value::Object(arg));
+    }
+    for o in p.opts() {
+        let mut arg = arg_to_json(o, "option");
+        arg.insert("aliases".into(), aliases(o.s.aliases.as_ref()));
+        arg.insert("help_heading".into(), opt_str(o.b.help_heading));
+        args.push(Value::Object(arg));
+    }
+    for pos in p.positionals() {
+        let mut arg = arg_to_json(pos, "positional");
+        arg.insert("index".into(), Value::from(pos.index));
+        arg.insert("help_heading".into(), opt_str(pos.b.help_heading));
+        args.push(Value::Object(arg));
+    }
+    obj.insert("args".into(), Value::Array(args));
+    obj.insert("groups".into(),
+               Value::Array(p.groups.iter().map(group_to_json).collect()));
+    let subcommands = p.subcommands
+        .iter()
+        .map(|sc| {
+            let mut sc_obj = app_to_json(&sc.p);
+            // The help subcommand is generated by clap
+            let generated = sc.p.meta.name == "help" && p.is_set(AS::NeedsSubcommandHelp);
+            if let Value::Object(ref mut o) = sc_obj {
+                o.insert("generated".into(), Value::from(generated));
+            }
+            sc_obj
+        })
+        .collect();
+    obj.insert("subcommands".into(), Value::Array(subcommands));
+    Value::Object(obj)
+}
+
+fn arg_to_json<'a, 'b, A>(a: &A, kind: &str) -> Map<String, Value>
+    where A: AnyArg<'a, 'b>
+{
+    debugln!("JsonGen::arg_to_json: name={}", a.name());
+    let mut obj = Map::new();
+    // The generated help and version flags use internal names to sort them last
+    let (name, generated) = match a.name() {
+        "hclap_help" => ("help", true),
+        "vclap_version" => ("version", true),
+        n => (n, false),
+    };
+    obj.insert("name".into(), Value::from(name));
+    obj.insert("generated".into(), Value::from(generated));
+    obj.insert("kind".into(), Value::from(kind));
+    obj.insert("short".into(),
+               a.short().map_or(Value::Null, |s| Value::from(s.to_string())));
+    obj.insert("long".into(), opt_str(a.long()));
+    obj.insert("aliases".into(), Value::Array(vec![]));
+    obj.insert("help".into(), opt_str(a.help()));
+    obj.insert("long_help".into(), opt_str(a.long_help()));
+    obj.insert("settings".into(),
+               Value::Array(ARG_SETTINGS.iter()
+                                .filter(|&&(s, _)| a.is_set(s))
+                                .map(|&(_, n)| Value::from(n))
+                                .collect()));
+    obj.insert("value_names".into(),
+               strs(a.val_names().map(|vn| vn.values().cloned().collect::<Vec<_>>())));
+    obj.insert("possible_values".into(),
+               strs(a.possible_vals().map(|pv| pv.to_vec())));
+    obj.insert("default_value".into(),
+               a.default_val().map_or(Value::Null, |v| Value::from(v.to_string_lossy())));
+    let default_value_ifs = a.default_vals_ifs()
+        .into_iter()
+        .flat_map(|vals| vals)
+        .map(|&(arg, val, default)| {
+            let mut dv = Map::new();
+            dv.insert("arg".into(), Value::from(arg));
+            dv.insert("value".into(),
+                      val.map_or(Value::Null, |v| Value::from(v.to_string_lossy())));
+            dv.insert("default".into(), Value::from(default.to_string_lossy()));
+            Value::Object(dv)
+        })
+        .collect();
+    obj.insert("default_value_ifs".into(), Value::Array(default_value_ifs));
+    obj.insert("env".into(),
+               a.env().map_or(Value::Null, |(name, _)| Value::from(name.to_string_lossy())));
+    obj.insert("value_hint".into(), Value::from(format!("{:?}", a.value_hint())));
+    obj.insert("value_delimiter".into(),
+               a.val_delim().map_or(Value::Null, |d| Value::from(d.to_string())));
+    obj.insert("value_terminator".into(), opt_str(a.val_terminator()));
+    obj.insert("number_of_values".into(), opt_num(a.num_vals()));
+    obj.insert("min_values".into(), opt_num(a.min_vals()));
+    obj.insert("max_values".into(), opt_num(a.max_vals()));
+    let requires = a.requires()
+        .unwrap_or(&[])
+        .iter()
+        .map(|&(val, arg)| {
+            let mut req = Map::new();
+            req.insert("arg".into(), Value::from(arg));
+            req.insert("value".into(), opt_str(val));
+            Value::Object(req)
+        })
+        .collect();
+    obj.insert("requires".into(), Value::Array(requires));
+    obj.insert("required_unless".into(), strs(a.required_unless().map(|r| r.to_vec())));
+    obj.insert("conflicts_with".into(), strs(a.blacklist().map(|b| b.to_vec())));
+    obj.insert("overrides_with".into(), strs(a.overrides().map(|o| o.to_vec())));
+    obj
+}
+
+fn group_to_json(g: &ArgGroup) -> Value {
+    let mut obj = Map::new();
+    obj.insert("name".into(), Value::from(g.name));
+    obj.insert("args".into(), strs(Some(g.args.clone())));
+    obj.insert("required".into(), Value::from(g.required));
+    obj.insert("multiple".into(), Value::from(g.multiple));
+    obj.insert("min_args".into(), opt_num(g.min_args.map(|n| n as u64)));
+    obj.insert("max_args".into(), opt_num(g.max_args.map(|n| n as u64)));
+    obj.insert("requires".into(), strs(g.requires.clone()));
+    obj.insert("conflicts_with".into(), strs(g.conflicts.clone()));
+    Value::Object(obj)
+}
+
+fn aliases(aliases: Option<&Vec<(&str, bool)>>) -> Value {
+    Value::Array(aliases.map_or(vec![], |a| {
+        a.iter()
+            .map(|&(name, visible)| {
+                let mut obj = Map::new();
+                obj.insert("name".into(), Value::from(name));
+                obj.insert("visible".into(), Value::from(visible));
+                Value::Object(obj)
+            })
+            .collect()
+    }))
+}
+
+fn opt_str(s: Option<&str>) -> Value { s.map_or(Value::Null, Value::from) }
+
+fn opt_num(n: Option<u64>) -> Value { n.map_or(Value::Null, Value::from) }
+
+fn strs(v: Option<Vec<&str>>) -> Value {
+    Value::Array(v.unwrap_or_else(Vec::new).into_iter().map(Value::from).collect())
+}
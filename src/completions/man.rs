@@ -0,0 +1,221 @@
+// Std
+use std::io::Write;
+
+// Internal
+use app::parser::Parser;
+use app::AppSettings as AS;
+use app::usage;
+use args::AnyArg;
+use args::settings::ArgSettings;
+
+pub struct ManGen<'a, 'b>
+    where 'a: 'b
+{
+    p: &'b Parser<'a, 'b>,
+}
+
+impl<'a, 'b> ManGen<'a, 'b> {
+    pub fn new(p: &'b Parser<'a, 'b>) -> Self { ManGen { p: p } }
+
+    pub fn generate_to<W: Write>(&self, buf: &mut W) {
+        let name = self.p.meta.bin_name.as_ref().unwrap();
+        let mut buffer = String::new();
+
+        // .TH <title> <section> <date> <source>
+        buffer.push_str(&format!(".TH \"{}\" \"1\" \"\" \"{}\"\n",
+                                 escape(&name.to_uppercase()),
+                                 escape(&match self.p.meta.version {
+                                            Some(v) => format!("{} {}", name, v),
+                                            None => name.clone(),
+                                        })));
+
+        buffer.push_str(".SH NAME\n");
+        match self.p.meta.about {
+            Some(about) => buffer.push_str(&format!("{} \\- {}\n", escape(name), escape(about))),
+            None => buffer.push_str(&format!("{}\n", escape(name))),
+        }
+
+        buffer.push_str(".SH SYNOPSIS\n");
+        buffer.push_str(&format!("{}\n", escape(&usage::create_usage_no_title(self.p, &[]))));
+
+        if let Some(desc) = self.p.meta.long_about.or(self.p.meta.about) {
+            buffer.push_str(".SH DESCRIPTION\n");
+            buffer.push_str(&format!("{}\n", escape(desc)));
+        }
+
+        gen_man_args(self.p, ".SH", &mut buffer);
+        gen_man_subcommands(self.p, ".SH", &mut buffer);
+
+        if let Some(more) = self.p.meta.more_help {
+            buffer.push_str(".PP\n");
+            buffer.push_str(&format!("{}\n", escape(more)));
+        }
+
+        gen_man_subcommand_sections(self.p, &mut buffer);
+
+        if let Some(version) = self.p.meta.version {
+            buffer.push_str(".SH VERSION\n");
+            buffer.push_str(&format!("{}\n", escape(version)));
+        }
+
+        if let Some(author) = self.p.meta.author {
+            buffer.push_str(".SH AUTHORS\n");
+            buffer.push_str(&format!("{}\n", escape(author)));
+        }
+
+        w!(buf, buffer.as_bytes());
+    }
+}
+
+// Writes one section (using the `heading` macro, i.e. `.SH` or `.SS`) for each kind of argument
+// the parser knows about, skipping any hidden arguments
+fn gen_man_args(p: &Parser, heading: &str, buffer: &mut String) {
+    debugln!("ManGen::gen_man_args;");
+    let flags: Vec<_> = p.flags().filter(|f| !f.is_set(ArgSettings::Hidden)).collect();
+    if !flags.is_empty() {
+        buffer.push_str(&format!("{} FLAGS\n", heading));
+        for f in flags {
+            gen_man_arg(f, buffer);
+        }
+    }
+
+    let opts: Vec<_> = p.opts().filter(|o| !o.is_set(ArgSettings::Hidden)).collect();
+    if !opts.is_empty() {
+        buffer.push_str(&format!("{} OPTIONS\n", heading));
+        for o in opts {
+            gen_man_arg(o, buffer);
+        }
+    }
+
+    let pos: Vec<_> = p.positionals().filter(|p| !p.is_set(ArgSettings::Hidden)).collect();
+    if !pos.is_empty() {
+        buffer.push_str(&format!("{} ARGS\n", heading));
+        for p in pos {
+            gen_man_arg(p, buffer);
+        }
+    }
+}
+
+// Writes a single tagged paragraph for an argument, i.e.
+//
+// .TP
+// \fB\-o\fR, \fB\-\-output\fR <\fIFILE\fR>
+// {long_help}
+// .br
+// [default: {default}]
+// .br
+// [possible values: {possible values}]
+fn gen_man_arg<'n, 'e, A>(a: &A, buffer: &mut String)
+    where A: AnyArg<'n, 'e>
+{
+    debugln!("ManGen::gen_man_arg: name={}", a.name());
+    let mut tag = String::new();
+    if let Some(s) = a.short() {
+        tag.push_str(&format!("\\fB\\-{}\\fR", escape(&s.to_string())));
+    }
+    if let Some(l) = a.long() {
+        if !tag.is_empty() {
+            tag.push_str(", ");
+        }
+        tag.push_str(&format!("\\fB\\-\\-{}\\fR", escape(l)));
+    }
+    if a.takes_value() {
+        if !tag.is_empty() {
+            tag.push(if a.is_set(ArgSettings::RequireEquals) {
+                         '='
+                     } else {
+                         ' '
+                     });
+        }
+        tag.push_str(&man_val_names(a));
+    }
+
+    buffer.push_str(".TP\n");
+    buffer.push_str(&format!("{}\n", tag));
+    if let Some(h) = a.long_help().or_else(|| a.help()) {
+        buffer.push_str(&format!("{}\n", escape(h)));
+    }
+    if !a.is_set(ArgSettings::HideDefaultValue) {
+        if let Some(pv) = a.default_val() {
+            buffer.push_str(".br\n");
+            buffer.push_str(&format!("[default: {}]\n", escape(&pv.to_string_lossy())));
+        }
+    }
+    if !a.is_set(ArgSettings::HidePossibleValues) {
+        if let Some(pv) = a.possible_vals() {
+            buffer.push_str(".br\n");
+            buffer.push_str(&format!("[possible values: {}]\n", escape(&pv.join(", "))));
+        }
+    }
+}
+
+// Builds the value portion of an argument's tag such as `<\fIFILE\fR>...`
+fn man_val_names<'n, 'e, A>(a: &A) -> String
+    where A: AnyArg<'n, 'e>
+{
+    let names: Vec<String> = if let Some(vn) = a.val_names() {
+        vn.values().map(|n| format!("<\\fI{}\\fR>", escape(n))).collect()
+    } else {
+        let n = format!("<\\fI{}\\fR>", escape(a.name()));
+        (0..a.num_vals().unwrap_or(1)).map(|_| n.clone()).collect()
+    };
+    let mut ret = names.join(" ");
+    if a.is_set(ArgSettings::Multiple) && names.len() == 1 {
+        ret.push_str("...");
+    }
+    ret
+}
+
+// Lists the direct subcommands of the parser along with their short descriptions
+fn gen_man_subcommands(p: &Parser, heading: &str, buffer: &mut String) {
+    debugln!("ManGen::gen_man_subcommands;");
+    let scs: Vec<_> = p.subcommands.iter().filter(|s| !s.p.is_set(AS::Hidden)).collect();
+    if scs.is_empty() {
+        return;
+    }
+    buffer.push_str(&format!("{} SUBCOMMANDS\n", heading));
+    for sc in scs {
+        buffer.push_str(".TP\n");
+        buffer.push_str(&format!("\\fB{}\\fR\n", escape(&sc.p.meta.name)));
+        if let Some(about) = sc.p.meta.about {
+            buffer.push_str(&format!("{}\n", escape(about)));
+        }
+    }
+}
+
+// Writes one `.SH` section per (non-hidden) subcommand, recursively, titled with the full path to
+// the subcommand (i.e. "rustup toolchain install")
+fn gen_man_subcommand_sections(p: &Parser, buffer: &mut String) {
+    debugln!("ManGen::gen_man_subcommand_sections;");
+    for sc in p.subcommands.iter().filter(|s| !s.p.is_set(AS::Hidden)) {
+        let bin_name = sc.p.meta.bin_name.as_ref().unwrap();
+        buffer.push_str(&format!(".SH \"{}\"\n", escape(bin_name)));
+        if let Some(desc) = sc.p.meta.long_about.or(sc.p.meta.about) {
+            buffer.push_str(&format!("{}\n", escape(desc)));
+        }
+        buffer.push_str(".SS USAGE\n");
+        buffer.push_str(&format!("{}\n", escape(&usage::create_usage_no_title(&sc.p, &[]))));
+        gen_man_args(&sc.p, ".SS", buffer);
+        gen_man_subcommands(&sc.p, ".SS", buffer);
+        if let Some(more) = sc.p.meta.more_help {
+            buffer.push_str(".PP\n");
+            buffer.push_str(&format!("{}\n", escape(more)));
+        }
+        gen_man_subcommand_sections(&sc.p, buffer);
+    }
+}
+
+// Escapes text so that roff prints it verbatim: backslashes and hyphens are escaped, and any line
+// which would otherwise be interpreted as a request (i.e. starts with '.' or '\'') is guarded
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\e")
+        .replace('-', "\\-")
+        .lines()
+        .map(|l| if l.starts_with('.') || l.starts_with('\'') {
+                 format!("\\&{}", l)
+             } else {
+                 l.to_owned()
+             })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,135 @@
+// Std
+use std::cmp;
+use std::io::Write;
+use std::iter;
+
+// Internal
+use app::parser::Parser;
+use app::usage;
+use completions::doc::{self, DocArg};
+
+pub struct MarkdownGen<'a, 'b>
+    where 'a: 'b
+{
+    p: &'b Parser<'a, 'b>,
+}
+
+impl<'a, 'b> MarkdownGen<'a, 'b> {
+    pub fn new(p: &'b Parser<'a, 'b>) -> Self { MarkdownGen { p: p } }
+
+    pub fn generate_to<W: Write>(&self, buf: &mut W) {
+        let mut blocks = vec![];
+        gen_command(self.p, 1, &mut blocks);
+        w!(buf, format!("{}\n", blocks.join("\n\n")).as_bytes());
+    }
+}
+
+// Documents a (sub)command followed by its (non-hidden) subcommands, recursively, with headings
+// getting smaller the deeper the subcommand is
+fn gen_command(p: &Parser, level: usize, blocks: &mut Vec<String>) {
+    debugln!("MarkdownGen::gen_command: name={}", p.meta.name);
+    let bin_name = p.meta.bin_name.as_ref().unwrap();
+    let title = match p.meta.version {
+        Some(v) if level == 1 => format!("{} {}", bin_name, v),
+        _ => bin_name.clone(),
+    };
+    blocks.push(format!("<a id=\"{}\"></a>\n{} {}",
+                        doc::anchor(bin_name),
+                        iter::repeat('#').take(cmp::min(level, 6)).collect::<String>(),
+                        escape(&title)));
+
+    if let Some(desc) = doc::description(p) {
+        blocks.push(escape(&desc));
+    }
+
+    blocks.push(format!("**Usage:**\n\n```text\n{}\n```",
+                        usage::create_usage_no_title(p, &[])));
+
+    for section in doc::arg_sections(p) {
+        blocks.push(format!("**{}:**", escape(section.title)));
+        blocks.push(args_table(&section.args));
+    }
+
+    let scs = doc::visible_subcommands(p);
+    if !scs.is_empty() {
+        blocks.push(String::from("**Subcommands:**"));
+        let mut table = String::from("| Subcommand | Description |\n| --- | --- |");
+        for sc in &scs {
+            table.push_str(&format!("\n| [`{}`](#{}) | {} |",
+                                    sc.p.meta.name,
+                                    doc::anchor(sc.p.meta.bin_name.as_ref().unwrap()),
+                                    cell(sc.p.meta.about.unwrap_or(""))));
+        }
+        blocks.push(table);
+    }
+
+    if let Some(more) = p.meta.more_help {
+        blocks.push(escape(&more.replace("{n}", "\n")));
+    }
+
+    for sc in scs {
+        gen_command(&sc.p, level + 1, blocks);
+    }
+}
+
+// A table of arguments, where the columns for default and possible values are only included if
+// at least one of the arguments has them
+fn args_table(args: &[DocArg]) -> String {
+    let defaults = args.iter().any(|a| a.default_val.is_some());
+    let possible = args.iter().any(|a| !a.possible_vals.is_empty());
+
+    let mut table = String::from("| Argument | Description |");
+    let mut sep = String::from("| --- | --- |");
+    if defaults {
+        table.push_str(" Default |");
+        sep.push_str(" --- |");
+    }
+    if possible {
+        table.push_str(" Possible values |");
+        sep.push_str(" --- |");
+    }
+    table.push('\n');
+    table.push_str(&sep);
+
+    for a in args {
+        table.push_str(&format!("\n| {} | {} |",
+                                code(&a.name),
+                                cell(a.help.as_ref().map_or("", |h| &**h))));
+        if defaults {
+            table.push_str(&format!(" {} |",
+                                    a.default_val.as_ref().map_or(String::new(), |v| code(v))));
+        }
+        if possible {
+            table.push_str(&format!(" {} |",
+                                    a.possible_vals
+                                        .iter()
+                                        .map(|v| code(v))
+                                        .collect::<Vec<_>>()
+                                        .join(", ")));
+        }
+    }
+    table
+}
+
+// Escapes text so that Markdown displays it verbatim
+fn escape(s: &str) -> String {
+    let mut ret = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '#' | '|' => {
+                ret.push('\\');
+                ret.push(c);
+            }
+            '<' => ret.push_str("&lt;"),
+            '>' => ret.push_str("&gt;"),
+            _ => ret.push(c),
+        }
+    }
+    ret
+}
+
+// Escapes text for a table cell, which can't span multiple lines
+fn cell(s: &str) -> String { escape(s).replace('\n', "<br>") }
+
+// A code span, escaping the pipes which would otherwise end the table cell
+fn code(s: &str) -> String { format!("`{}`", s.replace('|', "\\|")) }
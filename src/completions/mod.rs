@@ -4,7 +4,15 @@ mod bash;
 mod fish;
 mod zsh;
 mod powershell;
+mod elvish;
 mod shell;
+mod man;
+mod doc;
+mod markdown;
+mod html;
+#[cfg(feature = "json")]
+mod json;
+pub mod dynamic;
 
 // Std
 use std::io::Write;
@@ -15,7 +23,14 @@ use self::bash::BashGen;
 use self::fish::FishGen;
 use self::zsh::ZshGen;
 use self::powershell::PowerShellGen;
+use self::elvish::ElvishGen;
 pub use self::shell::Shell;
+pub use self::man::ManGen;
+pub use self::markdown::MarkdownGen;
+pub use self::html::HtmlGen;
+#[cfg(feature = "json")]
+pub use self::json::JsonGen;
+pub use self::dynamic::DynamicGen;
 
 pub struct ComplGen<'a, 'b>
     where 'a: 'b
@@ -32,6 +47,7 @@ impl<'a, 'b> ComplGen<'a, 'b> {
             Shell::Fish => FishGen::new(self.p).generate_to(buf),
             Shell::Zsh => ZshGen::new(self.p).generate_to(buf),
             Shell::PowerShell => PowerShellGen::new(self.p).generate_to(buf),
+            Shell::Elvish => ElvishGen::new(self.p).generate_to(buf),
         }
     }
 }
@@ -6,7 +6,7 @@ use std::ascii::AsciiExt;
 // Internal
 use app::App;
 use app::parser::Parser;
-use args::{ArgSettings, AnyArg};
+use args::{ArgSettings, AnyArg, ValueHint};
 use completions;
 use INTERNAL_ERROR_MSG;
 
@@ -263,7 +263,12 @@ fn get_args_of(p: &Parser) -> String {
     let mut ret = vec![String::from("_arguments -s -S -C \\")];
     let opts = write_opts_of(p);
     let flags = write_flags_of(p);
-    let sc_or_a = if p.has_subcommands() || p.has_positionals() {
+    let positionals = write_positionals_of(p);
+    let first_hinted = !p.has_subcommands() &&
+                       p.positionals
+                           .get(1)
+                           .map_or(false, |pos| action_for(pos.value_hint()).is_some());
+    let sc_or_a = if p.has_subcommands() || (p.has_positionals() && !first_hinted) {
         format!("\"1:: :_{name}_commands\" \\", 
                 name = p.meta.bin_name.as_ref().unwrap().replace(" ", "__"))
     } else {
@@ -284,6 +289,9 @@ fn get_args_of(p: &Parser) -> String {
     if !sc_or_a.is_empty() {
         ret.push(sc_or_a);
     }
+    if !positionals.is_empty() {
+        ret.push(positionals);
+    }
     if !sc.is_empty() {
         ret.push(sc);
     }
@@ -312,6 +320,8 @@ fn write_opts_of(p: &Parser) -> String {
         };
         let pv = if let Some(pv_vec) = o.possible_vals() {
             format!(": :({})", pv_vec.join(" "))
+        } else if let Some(action) = action_for(o.value_hint()) {
+            format!(": :{}", action)
         } else {
             String::new()
         };
@@ -385,3 +395,58 @@ fn write_flags_of(p: &Parser) -> String {
 
     ret.join("\n")
 }
+
+// Writes out the positional args with a value hint, which are completed by the hint's action
+// instead of being listed with the subcommands. The words of a `CommandWithArguments` arg are
+// passed on to the action on their own, so it can complete the arguments of the command too.
+//
+//    "1:input:_files" \
+//    "*::cmd:_cmdambivalent" \
+fn write_positionals_of(p: &Parser) -> String {
+    debugln!("write_positionals_of;");
+    if p.has_subcommands() {
+        return String::new();
+    }
+    let mut ret = vec![];
+    for pos in p.positionals() {
+        debugln!("write_positionals_of:iter: pos={}", pos.name());
+        let action = match action_for(pos.value_hint()) {
+            Some(action) => action,
+            None => continue,
+        };
+        let index = if pos.value_hint() == ValueHint::CommandWithArguments {
+            String::from("*:")
+        } else if pos.is_set(ArgSettings::Multiple) {
+            String::from("*")
+        } else {
+            pos.index.to_string()
+        };
+        let s = format!("\"{index}:{name}:{action}\" \\",
+            index = index,
+            name = pos.name(),
+            action = action);
+
+        debugln!("write_positionals_of:iter: Wrote...{}", &*s);
+        ret.push(s);
+    }
+
+    ret.join("\n")
+}
+
+// Maps a value hint to the ZSH completion function (or action) which completes it natively
+fn action_for(hint: ValueHint) -> Option<&'static str> {
+    match hint {
+        ValueHint::Unknown => None,
+        ValueHint::Other => Some("( )"),
+        ValueHint::AnyPath | ValueHint::FilePath => Some("_files"),
+        ValueHint::DirPath => Some("_files -/"),
+        ValueHint::ExecutablePath => Some("_absolute_command_paths"),
+        ValueHint::CommandName => Some("_command_names -e"),
+        ValueHint::CommandString => Some("_cmdstring"),
+        ValueHint::CommandWithArguments => Some("_cmdambivalent"),
+        ValueHint::Username => Some("_users"),
+        ValueHint::Hostname => Some("_hosts"),
+        ValueHint::Url => Some("_urls"),
+        ValueHint::EmailAddress => Some("_email_addresses"),
+    }
+}
@@ -0,0 +1,487 @@
+// Std
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+// Third Party
+#[cfg(feature = "yaml")]
+use yaml_rust::{Yaml, YamlLoader};
+#[cfg(feature = "toml")]
+use toml;
+
+// Internal
+use errors::{Error, Result as ClapResult};
+
+/// A source of argument values, such as a configuration file, which is layered beneath the
+/// command line. Values are keyed by the argument's name (i.e. the name given to
+/// [`Arg::with_name`]) and are only used for arguments which weren't given on the command line or
+/// through the environment (see [`Arg::env`]), but take priority over [default values].
+///
+/// Values from a `ConfigSource` receive the same validation as values from the command line, and
+/// conflicts and requirements are checked after all sources have been merged. Keys which don't
+/// match any argument are ignored. Values for the arguments of a subcommand are kept in a nested
+/// `ConfigSource` under the subcommand's name, which maps to a section in INI files or a table in
+/// TOML and YAML files.
+///
+/// Flags accept `true`, `false`, or (for flags which accept multiple occurrences) a number of
+/// occurrences. Arguments which take multiple values accept a list in TOML and YAML files.
+///
+/// Sources are added to an [`App`] with [`App::config`]. [`ArgMatches::config_origin`] tells
+/// which source an argument's values came from.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, ConfigSource};
+/// let system = ConfigSource::from_ini("/etc/tool.conf", "jobs = 2\nverbose = true").unwrap();
+/// let user = ConfigSource::new("~/.config/tool").value("jobs", "8");
+///
+/// let m = App::new("tool")
+///     .arg(Arg::with_name("jobs").long("jobs").takes_value(true))
+///     .arg(Arg::with_name("verbose").long("verbose"))
+///     .config(system)
+///     .config(user)
+///     .get_matches_from(vec!["tool"]);
+///
+/// assert_eq!(m.value_of("jobs"), Some("8"));
+/// assert_eq!(m.config_origin("jobs"), Some("~/.config/tool"));
+/// assert!(m.is_present("verbose"));
+/// assert_eq!(m.config_origin("verbose"), Some("/etc/tool.conf"));
+/// ```
+/// [`Arg::with_name`]: ./struct.Arg.html#method.with_name
+/// [`Arg::env`]: ./struct.Arg.html#method.env
+/// [default values]: ./struct.Arg.html#method.default_value
+/// [`App`]: ./struct.App.html
+/// [`App::config`]: ./struct.App.html#method.config
+/// [`ArgMatches::config_origin`]: ./struct.ArgMatches.html#method.config_origin
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigSource {
+    origin: String,
+    vals: Vec<(String, Vec<String>)>,
+    subcommands: Vec<(String, ConfigSource)>,
+}
+
+impl ConfigSource {
+    /// Creates a new empty `ConfigSource`. The `origin` describes where the values come from,
+    /// such as the path of a configuration file, and is used in error messages and reported by
+    /// [`ArgMatches::config_origin`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::ConfigSource;
+    /// let cfg = ConfigSource::new("/etc/tool.conf");
+    /// assert_eq!(cfg.origin(), "/etc/tool.conf");
+    /// ```
+    /// [`ArgMatches::config_origin`]: ./struct.ArgMatches.html#method.config_origin
+    pub fn new<S: Into<String>>(origin: S) -> Self {
+        ConfigSource {
+            origin: origin.into(),
+            vals: vec![],
+            subcommands: vec![],
+        }
+    }
+
+    /// Adds a value for the argument named `key`. Adding a value for the same key multiple
+    /// times appends to its values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::ConfigSource;
+    /// let cfg = ConfigSource::new("defaults")
+    ///     .value("include", "src")
+    ///     .value("include", "tests");
+    /// assert_eq!(cfg.get("include"), Some(&["src".to_owned(), "tests".to_owned()][..]));
+    /// ```
+    pub fn value<K: Into<String>, V: Into<String>>(mut self, key: K, val: V) -> Self {
+        self.push(key.into(), val.into());
+        self
+    }
+
+    /// Adds the values for the arguments of the subcommand `name`. The origin of `source` (and
+    /// any of its subcommands) is replaced by the origin of this `ConfigSource`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::ConfigSource;
+    /// let cfg = ConfigSource::new("/etc/tool.conf")
+    ///     .subcommand("build", ConfigSource::new("").value("release", "true"));
+    /// let build = cfg.subcommand_source("build").unwrap();
+    /// assert_eq!(build.origin(), "/etc/tool.conf");
+    /// assert_eq!(build.get("release"), Some(&["true".to_owned()][..]));
+    /// ```
+    pub fn subcommand<S: Into<String>>(mut self, name: S, source: ConfigSource) -> Self {
+        let name = name.into();
+        self.subcommand_mut(&*name).merge(source);
+        self
+    }
+
+    /// Returns the origin of this `ConfigSource`
+    pub fn origin(&self) -> &str { &*self.origin }
+
+    /// Returns the values for the argument named `key`, if any
+    pub fn get(&self, key: &str) -> Option<&[String]> {
+        self.vals.iter().find(|&&(ref k, _)| k == key).map(|&(_, ref v)| &v[..])
+    }
+
+    /// Returns the values for the arguments of the subcommand `name`, if any
+    pub fn subcommand_source(&self, name: &str) -> Option<&ConfigSource> {
+        self.subcommands.iter().find(|&&(ref n, _)| n == name).map(|&(_, ref s)| s)
+    }
+
+    /// Creates a `ConfigSource` from the contents of an INI style file. Each `key = value` line
+    /// sets a value, with the last line winning when a key is repeated in a section. A `key`
+    /// without a value is the same as `key = true`, and values may be surrounded by single or
+    /// double quotes. Lines starting with `#` or `;` are comments. A
+    /// `[section]` holds the values for the subcommand of the same name, and nested subcommands
+    /// are separated by dots, i.e. `[remote.add]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::ConfigSource;
+    /// let cfg = ConfigSource::from_ini("tool.conf", "
+    /// ; global settings
+    /// color = never
+    ///
+    /// [remote.add]
+    /// fetch
+    /// track = \"master\"
+    /// ").unwrap();
+    ///
+    /// assert_eq!(cfg.get("color"), Some(&["never".to_owned()][..]));
+    /// let add = cfg.subcommand_source("remote").unwrap().subcommand_source("add").unwrap();
+    /// assert_eq!(add.get("fetch"), Some(&["true".to_owned()][..]));
+    /// assert_eq!(add.get("track"), Some(&["master".to_owned()][..]));
+    /// ```
+    pub fn from_ini<S: Into<String>>(origin: S, ini: &str) -> ClapResult<Self> {
+        let mut cfg = ConfigSource::new(origin);
+        let mut section: Vec<String> = vec![];
+        for (i, line) in ini.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if line.starts_with('[') {
+                if !line.ends_with(']') {
+                    return Err(Error::invalid_config(&*cfg.origin,
+                                                     format!("unterminated section header on \
+                                                              line {}",
+                                                             i + 1)));
+                }
+                section = line[1..line.len() - 1]
+                    .split('.')
+                    .map(|s| s.trim().to_owned())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                continue;
+            }
+            let (key, val) = match line.find('=') {
+                Some(i) => (line[..i].trim(), unquote(line[i + 1..].trim())),
+                None => (line, "true"),
+            };
+            if key.is_empty() {
+                return Err(Error::invalid_config(&*cfg.origin,
+                                                 format!("missing key on line {}", i + 1)));
+            }
+            let mut target = &mut cfg;
+            for sc in &section {
+                target = target.subcommand_mut(&*sc);
+            }
+            target.set(key.to_owned(), val.to_owned());
+        }
+        Ok(cfg)
+    }
+
+    /// Creates a `ConfigSource` from a YAML document. Scalars and lists of scalars are values,
+    /// while a mapping holds the values for the subcommand of the same name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate clap;
+    /// # #[cfg(feature = "yaml")]
+    /// # fn main() {
+    /// # use clap::{ConfigSource, YamlLoader};
+    /// let docs = YamlLoader::load_from_str("jobs: 4\ninclude: [src, tests]").unwrap();
+    /// let cfg = ConfigSource::from_yaml("tool.yml", &docs[0]).unwrap();
+    /// assert_eq!(cfg.get("jobs"), Some(&["4".to_owned()][..]));
+    /// assert_eq!(cfg.get("include"), Some(&["src".to_owned(), "tests".to_owned()][..]));
+    /// # }
+    /// # #[cfg(not(feature = "yaml"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml<S: Into<String>>(origin: S, yaml: &Yaml) -> ClapResult<Self> {
+        fn scalar(y: &Yaml) -> Option<String> {
+            match *y {
+                Yaml::String(ref s) |
+                Yaml::Real(ref s) => Some(s.clone()),
+                Yaml::Integer(i) => Some(i.to_string()),
+                Yaml::Boolean(b) => Some(b.to_string()),
+                _ => None,
+            }
+        }
+
+        fn add(cfg: &mut ConfigSource, yaml: &Yaml) -> ClapResult<()> {
+            let hash = match *yaml {
+                Yaml::Hash(ref h) => h,
+                Yaml::Null => return Ok(()),
+                _ => return Err(Error::invalid_config(&*cfg.origin, "expected a mapping")),
+            };
+            for (k, v) in hash {
+                let key = match scalar(k) {
+                    Some(k) => k,
+                    None => return Err(Error::invalid_config(&*cfg.origin, "expected a scalar key")),
+                };
+                match *v {
+                    Yaml::Hash(_) => try!(add(cfg.subcommand_mut(&*key), v)),
+                    Yaml::Array(ref a) => {
+                        for v in a {
+                            match scalar(v) {
+                                Some(v) => cfg.push(key.clone(), v),
+                                None => {
+                                    return Err(Error::invalid_config(&*cfg.origin,
+                                                                     format!("expected a list \
+                                                                              of values for '{}'",
+                                                                             key)))
+                                }
+                            }
+                        }
+                    }
+                    Yaml::Null => (),
+                    _ => {
+                        match scalar(v) {
+                            Some(v) => cfg.push(key, v),
+                            None => {
+                                return Err(Error::invalid_config(&*cfg.origin,
+                                                                 format!("unsupported value \
+                                                                          for '{}'",
+                                                                         key)))
+                            }
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        let mut cfg = ConfigSource::new(origin);
+        try!(add(&mut cfg, yaml));
+        Ok(cfg)
+    }
+
+    /// Creates a `ConfigSource` from the contents of a TOML file. Strings, numbers, booleans,
+    /// dates, and arrays of those are values, while a table holds the values for the subcommand
+    /// of the same name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate clap;
+    /// # #[cfg(feature = "toml")]
+    /// # fn main() {
+    /// # use clap::ConfigSource;
+    /// let cfg = ConfigSource::from_toml("tool.toml", "jobs = 4\n[build]\nrelease = true")
+    ///     .unwrap();
+    /// assert_eq!(cfg.get("jobs"), Some(&["4".to_owned()][..]));
+    /// let build = cfg.subcommand_source("build").unwrap();
+    /// assert_eq!(build.get("release"), Some(&["true".to_owned()][..]));
+    /// # }
+    /// # #[cfg(not(feature = "toml"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml<S: Into<String>>(origin: S, s: &str) -> ClapResult<Self> {
+        use toml::Value;
+
+        fn scalar(v: &Value) -> Option<String> {
+            match *v {
+                Value::String(ref s) => Some(s.clone()),
+                Value::Integer(i) => Some(i.to_string()),
+                Value::Float(f) => Some(f.to_string()),
+                Value::Boolean(b) => Some(b.to_string()),
+                Value::Datetime(ref d) => Some(d.to_string()),
+                _ => None,
+            }
+        }
+
+        fn add(cfg: &mut ConfigSource, table: &toml::value::Table) -> ClapResult<()> {
+            for (key, v) in table {
+                match *v {
+                    Value::Table(ref t) => try!(add(cfg.subcommand_mut(&*key), t)),
+                    Value::Array(ref a) => {
+                        for v in a {
+                            match scalar(v) {
+                                Some(v) => cfg.push(key.clone(), v),
+                                None => {
+                                    return Err(Error::invalid_config(&*cfg.origin,
+                                                                     format!("expected a list \
+                                                                              of values for '{}'",
+                                                                             key)))
+                                }
+                            }
+                        }
+                    }
+                    _ => {
+                        let v = scalar(v).expect(::INTERNAL_ERROR_MSG);
+                        cfg.push(key.clone(), v);
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        let mut cfg = ConfigSource::new(origin);
+        let table = match s.parse::<Value>() {
+            Ok(Value::Table(t)) => t,
+            Ok(_) => return Err(Error::invalid_config(&*cfg.origin, "expected a table")),
+            Err(e) => return Err(Error::invalid_config(&*cfg.origin, e.to_string())),
+        };
+        try!(add(&mut cfg, &table));
+        Ok(cfg)
+    }
+
+    /// Reads a configuration file, using the file's path as the origin. The format is chosen by
+    /// the file's extension: `.toml` files are parsed as TOML (requires the `toml` feature),
+    /// `.yml` and `.yaml` files as YAML (requires the `yaml` feature), and everything else as
+    /// INI (see [`ConfigSource::from_ini`]).
+    ///
+    /// Because configuration files are usually optional, a file which doesn't exist returns
+    /// `Ok(None)`. Other I/O errors return an error of the kind [`ErrorKind::Io`], and a file
+    /// which can't be parsed returns an error of the kind [`ErrorKind::InvalidConfig`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg, ConfigSource};
+    /// let mut app = App::new("tool")
+    ///     .arg(Arg::with_name("jobs").long("jobs").takes_value(true));
+    /// for path in &["/etc/tool.conf", "/home/me/.config/tool"] {
+    ///     if let Some(cfg) = ConfigSource::from_file(path).unwrap_or_else(|e| e.exit()) {
+    ///         app = app.config(cfg);
+    ///     }
+    /// }
+    /// let m = app.get_matches();
+    /// ```
+    /// [`ConfigSource::from_ini`]: ./struct.ConfigSource.html#method.from_ini
+    /// [`ErrorKind::Io`]: ./enum.ErrorKind.html#variant.Io
+    /// [`ErrorKind::InvalidConfig`]: ./enum.ErrorKind.html#variant.InvalidConfig
+    pub fn from_file<P: AsRef<Path>>(path: P) -> ClapResult<Option<Self>> {
+        let path = path.as_ref();
+        let mut contents = String::new();
+        match File::open(path).and_then(|mut f| f.read_to_string(&mut contents)) {
+            Ok(_) => (),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(Error::from(e)),
+        }
+        let origin = path.display().to_string();
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        match ext {
+            "toml" => from_toml_file(origin, &*contents).map(Some),
+            "yml" | "yaml" => from_yaml_file(origin, &*contents).map(Some),
+            _ => ConfigSource::from_ini(origin, &*contents).map(Some),
+        }
+    }
+
+    fn push(&mut self, key: String, val: String) {
+        if let Some(&mut (_, ref mut vals)) = self.vals.iter_mut().find(|&&mut (ref k, _)| *k == key) {
+            vals.push(val);
+            return;
+        }
+        self.vals.push((key, vec![val]));
+    }
+
+    fn set(&mut self, key: String, val: String) {
+        self.vals.retain(|&(ref k, _)| *k != key);
+        self.vals.push((key, vec![val]));
+    }
+
+    fn merge(&mut self, other: ConfigSource) {
+        for (k, vals) in other.vals {
+            for v in vals {
+                self.push(k.clone(), v);
+            }
+        }
+        for (n, sub) in other.subcommands {
+            self.subcommand_mut(&*n).merge(sub);
+        }
+    }
+
+    fn subcommand_mut(&mut self, name: &str) -> &mut ConfigSource {
+        if let Some(i) = self.subcommands.iter().position(|&(ref n, _)| n == name) {
+            return &mut self.subcommands[i].1;
+        }
+        let sub = ConfigSource::new(&*self.origin);
+        self.subcommands.push((name.to_owned(), sub));
+        &mut self.subcommands.last_mut().expect(::INTERNAL_ERROR_MSG).1
+    }
+}
+
+// Strips one pair of matching quotes surrounding an INI value
+fn unquote(s: &str) -> &str {
+    if s.len() >= 2 &&
+       ((s.starts_with('"') && s.ends_with('"')) || (s.starts_with('\'') && s.ends_with('\''))) {
+        &s[1..s.len() - 1]
+    } else {
+        s
+    }
+}
+
+#[cfg(feature = "toml")]
+fn from_toml_file(origin: String, contents: &str) -> ClapResult<ConfigSource> {
+    ConfigSource::from_toml(origin, contents)
+}
+
+#[cfg(not(feature = "toml"))]
+fn from_toml_file(origin: String, _: &str) -> ClapResult<ConfigSource> {
+    Err(Error::invalid_config(&*origin, "TOML files require the 'toml' feature"))
+}
+
+#[cfg(feature = "yaml")]
+fn from_yaml_file(origin: String, contents: &str) -> ClapResult<ConfigSource> {
+    match YamlLoader::load_from_str(contents) {
+        Ok(ref docs) if docs.is_empty() => Ok(ConfigSource::new(origin)),
+        Ok(docs) => ConfigSource::from_yaml(origin, &docs[0]),
+        Err(e) => Err(Error::invalid_config(&*origin, e.to_string())),
+    }
+}
+
+#[cfg(not(feature = "yaml"))]
+fn from_yaml_file(origin: String, _: &str) -> ClapResult<ConfigSource> {
+    Err(Error::invalid_config(&*origin, "YAML files require the 'yaml' feature"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConfigSource;
+    use errors::ErrorKind;
+
+    #[test]
+    fn ini_values() {
+        let cfg = ConfigSource::from_ini("t", "a = 1\n; comment\nb='two words'\na = 3\n").unwrap();
+        assert_eq!(cfg.get("a"), Some(&["3".to_owned()][..]));
+        assert_eq!(cfg.get("b"), Some(&["two words".to_owned()][..]));
+        assert_eq!(cfg.get("c"), None);
+    }
+
+    #[test]
+    fn ini_sections() {
+        let cfg = ConfigSource::from_ini("t", "[sub]\nflag\n[sub.inner]\nx=1").unwrap();
+        let sub = cfg.subcommand_source("sub").unwrap();
+        assert_eq!(sub.get("flag"), Some(&["true".to_owned()][..]));
+        assert_eq!(sub.subcommand_source("inner").unwrap().get("x"),
+                   Some(&["1".to_owned()][..]));
+        assert_eq!(sub.origin(), "t");
+    }
+
+    #[test]
+    fn ini_errors() {
+        assert_eq!(ConfigSource::from_ini("t", "[sub").unwrap_err().kind,
+                   ErrorKind::InvalidConfig);
+        assert_eq!(ConfigSource::from_ini("t", "= 1").unwrap_err().kind,
+                   ErrorKind::InvalidConfig);
+    }
+}
@@ -0,0 +1,139 @@
+// Std
+use std::ffi::OsString;
+
+// Internal
+use app::App;
+use args::ArgMatches;
+use errors::Result as ClapResult;
+
+/// Builds the [`App`] which parses a type. This is usually derived with `#[derive(Clap)]` from
+/// the `clap_derive` crate, see [`Clap`] for an example.
+///
+/// [`App`]: ./struct.App.html
+/// [`Clap`]: ./trait.Clap.html
+pub trait IntoApp {
+    /// Returns the `App` which parses this type, including its name, version and description.
+    fn into_app<'a, 'b>() -> App<'a, 'b>;
+
+    /// Adds the arguments and subcommands of this type to an existing `App`, i.e. when the type
+    /// holds the arguments of a subcommand.
+    fn augment_app<'a, 'b>(app: App<'a, 'b>) -> App<'a, 'b>;
+}
+
+/// Builds a type back from the [`ArgMatches`] of the [`App`] returned by [`IntoApp`]. This is
+/// usually derived with `#[derive(Clap)]` from the `clap_derive` crate, see [`Clap`] for an
+/// example.
+///
+/// [`ArgMatches`]: ./struct.ArgMatches.html
+/// [`App`]: ./struct.App.html
+/// [`IntoApp`]: ./trait.IntoApp.html
+/// [`Clap`]: ./trait.Clap.html
+pub trait FromArgMatches: Sized {
+    /// Builds the type from the matches, returning an error if a value fails to parse into the
+    /// type of its field (see [`ArgMatches::value_of_t`]) or if a value is missing.
+    ///
+    /// [`ArgMatches::value_of_t`]: ./struct.ArgMatches.html#method.value_of_t
+    fn from_arg_matches(matches: &ArgMatches) -> ClapResult<Self>;
+}
+
+/// The subcommands of an enum, one per variant. This is derived by `#[derive(Clap)]` from the
+/// `clap_derive` crate for enums, which can then be used for a field marked with
+/// `#[clap(subcommand)]`.
+pub trait Subcommand: Sized {
+    /// Adds a subcommand for each variant to an `App`.
+    fn augment_subcommands<'a, 'b>(app: App<'a, 'b>) -> App<'a, 'b>;
+
+    /// Builds the variant of the subcommand `name` from its matches, or returns `None` if `name`
+    /// isn't one of the subcommands of this type. The arguments are those returned by
+    /// [`ArgMatches::subcommand`].
+    ///
+    /// [`ArgMatches::subcommand`]: ./struct.ArgMatches.html#method.subcommand
+    fn from_subcommand(name: &str, matches: Option<&ArgMatches>) -> Option<ClapResult<Self>>;
+}
+
+/// Parses the command line directly into a type, instead of reading each value back out of the
+/// [`ArgMatches`] by its name. The `App` is derived from the definition of the type with
+/// `#[derive(Clap)]` from the `clap_derive` crate:
+///
+/// * Structs become an `App`, with a field per argument, and doc comments become the `about` of
+///   the `App` and the `help` of the arguments.
+/// * Fields marked with `#[clap(short)]` or `#[clap(long)]` become flags or options, the others
+///   positional arguments. The type of the field determines how many values the argument takes:
+///   `bool` is a flag, `Option<T>` an optional value, `Vec<T>` multiple values and any other type
+///   a required value. Values are parsed with the field type's `FromStr` implementation.
+/// * Enums become subcommands, one per variant, with the arguments of the variant's fields or of
+///   the struct it wraps. A field marked with `#[clap(subcommand)]` holds the subcommand used.
+///
+/// Any other `#[clap(key = "value")]` attribute calls the method of the same name on the `Arg`
+/// or `App`, i.e. `#[clap(default_value = "1")]`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[macro_use]
+/// extern crate clap_derive;
+/// extern crate clap;
+///
+/// use clap::Clap;
+///
+/// /// Copies files around
+/// #[derive(Clap)]
+/// #[clap(name = "cp")]
+/// struct Opts {
+///     /// Overwrite existing files
+///     #[clap(short, long)]
+///     force: bool,
+///     /// How many times to retry
+///     #[clap(long, default_value = "3")]
+///     retries: u32,
+///     /// The files to copy
+///     sources: Vec<String>,
+/// }
+///
+/// fn main() {
+///     let opts = Opts::parse_from(vec!["cp", "-f", "a.txt", "b.txt"]);
+///     assert!(opts.force);
+///     assert_eq!(opts.retries, 3);
+///     assert_eq!(opts.sources, ["a.txt", "b.txt"]);
+/// }
+/// ```
+/// [`ArgMatches`]: ./struct.ArgMatches.html
+pub trait Clap: IntoApp + FromArgMatches {
+    /// Parses `env::args`, exiting the program with an error message if parsing fails. See
+    /// [`App::get_matches`].
+    ///
+    /// [`App::get_matches`]: ./struct.App.html#method.get_matches
+    fn parse() -> Self {
+        Self::from_arg_matches(&Self::into_app().get_matches()).unwrap_or_else(|e| e.exit())
+    }
+
+    /// Parses `env::args`, returning an error if parsing fails. See
+    /// [`App::get_matches_safe`].
+    ///
+    /// [`App::get_matches_safe`]: ./struct.App.html#method.get_matches_safe
+    fn try_parse() -> ClapResult<Self> {
+        Self::from_arg_matches(&try!(Self::into_app().get_matches_safe()))
+    }
+
+    /// Parses the given arguments, exiting the program with an error message if parsing fails.
+    /// See [`App::get_matches_from`].
+    ///
+    /// [`App::get_matches_from`]: ./struct.App.html#method.get_matches_from
+    fn parse_from<I, T>(itr: I) -> Self
+        where I: IntoIterator<Item = T>,
+              T: Into<OsString> + Clone
+    {
+        Self::try_parse_from(itr).unwrap_or_else(|e| e.exit())
+    }
+
+    /// Parses the given arguments, returning an error if parsing fails. See
+    /// [`App::get_matches_from_safe`].
+    ///
+    /// [`App::get_matches_from_safe`]: ./struct.App.html#method.get_matches_from_safe
+    fn try_parse_from<I, T>(itr: I) -> ClapResult<Self>
+        where I: IntoIterator<Item = T>,
+              T: Into<OsString> + Clone
+    {
+        Self::from_arg_matches(&try!(Self::into_app().get_matches_from_safe(itr)))
+    }
+}
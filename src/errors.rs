@@ -6,9 +6,18 @@ use std::fmt::Display;
 use std::io::{self, Write};
 use std::process;
 use std::result::Result as StdResult;
+use std::slice::Iter;
+use std::sync::Arc;
+
+// Third Party
+#[cfg(feature = "json")]
+use serde_json;
+#[cfg(feature = "toml")]
+use toml;
 
 // Internal
-use args::{FlagBuilder, AnyArg};
+use args::{FlagBuilder, AnyArg, ArgSettings};
+use catalog::{self, MessageCatalog};
 use fmt::{Colorizer, ColorizerOption, ColorWhen};
 use suggestions;
 
@@ -142,6 +151,28 @@ pub enum ErrorKind {
     /// ```
     ValueValidation,
 
+    /// Occurs when a validator registered with [`App::validator`] rejects the arguments which
+    /// were provided, such as when two values are valid on their own but not together.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let result = App::new("prog")
+    ///     .arg(Arg::with_name("start").long("start").takes_value(true))
+    ///     .arg(Arg::with_name("end").long("end").takes_value(true))
+    ///     .validator(|m| if m.value_of("start") == m.value_of("end") {
+    ///         Err(String::from("the range is empty"))
+    ///     } else {
+    ///         Ok(())
+    ///     })
+    ///     .get_matches_from_safe(vec!["prog", "--start", "3", "--end", "3"]);
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().kind, ErrorKind::MatchesValidation);
+    /// ```
+    /// [`App::validator`]: ./struct.App.html#method.validator
+    MatchesValidation,
+
     /// Occurs when a user provides more values for an argument than were defined by setting
     /// [`Arg::max_values`].
     ///
@@ -200,6 +231,28 @@ pub enum ErrorKind {
     /// [`Arg::value_names`]: ./struct.Arg.html#method.value_names
     WrongNumberOfValues,
 
+    /// Occurs when the user provides fewer or more of the arguments of an [`ArgGroup`] than
+    /// allowed by [`ArgGroup::min_args`], [`ArgGroup::max_args`] or
+    /// [`ArgGroup::number_of_args`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup, ErrorKind};
+    /// let result = App::new("prog")
+    ///     .arg(Arg::with_name("json").long("json"))
+    ///     .arg(Arg::with_name("yaml").long("yaml"))
+    ///     .group(ArgGroup::with_name("format").args(&["json", "yaml"]).max_args(1))
+    ///     .get_matches_from_safe(vec!["prog", "--json", "--yaml"]);
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().kind, ErrorKind::WrongNumberOfGroupArgs);
+    /// ```
+    /// [`ArgGroup`]: ./struct.ArgGroup.html
+    /// [`ArgGroup::min_args`]: ./struct.ArgGroup.html#method.min_args
+    /// [`ArgGroup::max_args`]: ./struct.ArgGroup.html#method.max_args
+    /// [`ArgGroup::number_of_args`]: ./struct.ArgGroup.html#method.number_of_args
+    WrongNumberOfGroupArgs,
+
     /// Occurs when the user provides two values which conflict with each other and can't be used
     /// together.
     ///
@@ -350,6 +403,14 @@ pub enum ErrorKind {
     /// ```
     VersionDisplayed,
 
+    /// Not a true "error" as it means a shell requested completion candidates for a partially
+    /// typed command line using [`AppSettings::DynamicCompletion`]. The candidates will be sent to
+    /// `stdout`, one per line, and are also available in [`Error::info`].
+    ///
+    /// [`AppSettings::DynamicCompletion`]: ./enum.AppSettings.html#variant.DynamicCompletion
+    /// [`Error::info`]: ./struct.Error.html#structfield.info
+    CompletionsDisplayed,
+
     /// Occurs when using the [`value_t!`] and [`values_t!`] macros to convert an argument value
     /// into type `T`, but the argument you requested wasn't used. I.e. you asked for an argument
     /// with name `config` to be converted, but `config` wasn't used by the user.
@@ -362,6 +423,50 @@ pub enum ErrorKind {
     /// [I/O error]: https://doc.rust-lang.org/std/io/struct.Error.html
     Io,
 
+    /// Occurs when a configuration file can't be parsed into a [`ConfigSource`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{ConfigSource, ErrorKind};
+    /// let res = ConfigSource::from_ini("tool.conf", "[build");
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::InvalidConfig);
+    /// ```
+    /// [`ConfigSource`]: ./struct.ConfigSource.html
+    InvalidConfig,
+
+    /// Occurs when a document describing an [`App`] can't be parsed, or contains keys or values
+    /// which aren't valid, or when [`App::validate_definition`] finds problems. The
+    /// [`ContextKind::KeyPath`] of every problem (or the [`ContextKind::Line`] and
+    /// [`ContextKind::Column`] of a syntax error) are stored in the error, or the
+    /// [`ContextKind::DefinitionPath`]s of the problems found by [`App::validate_definition`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate clap;
+    /// # #[cfg(feature = "json")]
+    /// # extern crate serde_json;
+    /// # use clap::{App, ErrorKind};
+    /// # #[cfg(feature = "json")]
+    /// # fn main() {
+    /// let def = r#"{"name": "prog", "args": [{"input": {"requried": true}}]}"#;
+    /// let def = serde_json::from_str(def).unwrap();
+    /// let res = App::from_json(&def);
+    /// assert_eq!(res.err().unwrap().kind, ErrorKind::InvalidDefinition);
+    /// # }
+    /// # #[cfg(not(feature = "json"))]
+    /// # fn main() {}
+    /// ```
+    /// [`App`]: ./struct.App.html
+    /// [`App::validate_definition`]: ./struct.App.html#method.validate_definition
+    /// [`ContextKind::KeyPath`]: ./enum.ContextKind.html#variant.KeyPath
+    /// [`ContextKind::DefinitionPath`]: ./enum.ContextKind.html#variant.DefinitionPath
+    /// [`ContextKind::Line`]: ./enum.ContextKind.html#variant.Line
+    /// [`ContextKind::Column`]: ./enum.ContextKind.html#variant.Column
+    InvalidDefinition,
+
     /// Represents a [Format error] (which is a part of [`Display`]).
     /// Typically caused by writing to `stderr` or `stdout`.
     /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
@@ -369,15 +474,170 @@ pub enum ErrorKind {
     Format,
 }
 
+/// The key of a piece of context information stored in an [`Error`], see [`Error::get`]
+///
+/// [`Error`]: ./struct.Error.html
+/// [`Error::get`]: ./struct.Error.html#method.get
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ContextKind {
+    /// The argument which caused the error, as it's displayed to the user (i.e. `--color <when>`)
+    InvalidArg,
+    /// The argument [`InvalidArg`] conflicts with
+    ///
+    /// [`InvalidArg`]: ./enum.ContextKind.html#variant.InvalidArg
+    PriorArg,
+    /// The value which caused the error
+    InvalidValue,
+    /// The values which would have been accepted
+    ValidValues,
+    /// The subcommand which wasn't recognized
+    InvalidSubcommand,
+    /// The required arguments which weren't provided, as they're displayed to the user
+    MissingArgs,
+    /// The argument the user probably meant to use
+    SuggestedArg,
+    /// The subcommand the user probably meant to use, or the subcommand [`SuggestedArg`] belongs
+    /// to
+    ///
+    /// [`SuggestedArg`]: ./enum.ContextKind.html#variant.SuggestedArg
+    SuggestedSubcommand,
+    /// The value the user probably meant to use
+    SuggestedValue,
+    /// The number of values the argument expects
+    ExpectedNumValues,
+    /// The minimum number of values the argument expects
+    MinValues,
+    /// The number of values which were provided
+    ActualNumValues,
+    /// The name of the [`ArgGroup`] which caused the error
+    ///
+    /// [`ArgGroup`]: ./struct.ArgGroup.html
+    Group,
+    /// The arguments of [`Group`], as they're displayed to the user
+    ///
+    /// [`Group`]: ./enum.ContextKind.html#variant.Group
+    GroupArgs,
+    /// The arguments of [`Group`] which were provided, as they're displayed to the user
+    ///
+    /// [`Group`]: ./enum.ContextKind.html#variant.Group
+    GivenArgs,
+    /// The minimum number of arguments of [`Group`] which must be provided, if any
+    ///
+    /// [`Group`]: ./enum.ContextKind.html#variant.Group
+    MinArgs,
+    /// The maximum number of arguments of [`Group`] which may be provided, if any
+    ///
+    /// [`Group`]: ./enum.ContextKind.html#variant.Group
+    MaxArgs,
+    /// The binary name of the command in which the error occured
+    BinName,
+    /// The origin of the [`ConfigSource`] which couldn't be parsed
+    ///
+    /// [`ConfigSource`]: ./struct.ConfigSource.html
+    ConfigOrigin,
+    /// The paths of the keys in an app definition which aren't valid (i.e.
+    /// `subcommands[2].args[0].possible_values`), where the path of a problem with the whole
+    /// document is empty
+    KeyPath,
+    /// The paths of the arguments, groups and subcommands with a problem found by
+    /// [`App::validate_definition`] (i.e. `subcommands.build.args.jobs`)
+    ///
+    /// [`App::validate_definition`]: ./struct.App.html#method.validate_definition
+    DefinitionPath,
+    /// The line of a syntax error in an app definition, starting at `1`, or the lines of the keys
+    /// of every [`KeyPath`] when the text of the definition was given (see
+    /// [`App::from_json_source`])
+    ///
+    /// [`KeyPath`]: ./enum.ContextKind.html#variant.KeyPath
+    /// [`App::from_json_source`]: ./struct.App.html#method.from_json_source
+    Line,
+    /// The column of a syntax error in an app definition, starting at `1`, or the columns of the
+    /// keys of every [`KeyPath`] when the text of the definition was given
+    ///
+    /// [`KeyPath`]: ./enum.ContextKind.html#variant.KeyPath
+    Column,
+    /// An additional description of the error, such as the message returned by a [validator]
+    ///
+    /// [validator]: ./struct.Arg.html#method.validator
+    Custom,
+    /// The usage string of the command in which the error occured
+    Usage,
+}
+
+/// The value of a piece of context information stored in an [`Error`], see [`Error::get`]
+///
+/// [`Error`]: ./struct.Error.html
+/// [`Error::get`]: ./struct.Error.html#method.get
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContextValue {
+    /// A single string, such as an argument or a value
+    String(String),
+    /// A list of strings, such as the valid values of an argument
+    Strings(Vec<String>),
+    /// A number, such as a number of values
+    Number(usize),
+    /// A list of numbers, such as the lines of the problems of an app definition
+    Numbers(Vec<usize>),
+}
+
+impl Display for ContextValue {
+    fn fmt(&self, f: &mut std_fmt::Formatter) -> std_fmt::Result {
+        match *self {
+            ContextValue::String(ref s) => write!(f, "{}", s),
+            ContextValue::Strings(ref v) => write!(f, "{}", v.join(", ")),
+            ContextValue::Number(n) => write!(f, "{}", n),
+            ContextValue::Numbers(ref v) => {
+                let v: Vec<_> = v.iter().map(|n| n.to_string()).collect();
+                write!(f, "{}", v.join(", "))
+            }
+        }
+    }
+}
+
 /// Command Line Argument Parser Error
+///
+/// Besides its [`kind`], an error carries context information such as the offending argument,
+/// the invalid value, and the usage string, which can be retrieved with [`Error::get`]. The
+/// message shown to the user is rendered from the kind and context once the error is returned
+/// from parsing, so the context can be used to present the error in other formats as well.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, ErrorKind, ContextKind, ContextValue};
+/// let res = App::new("prog")
+///     .arg(Arg::with_name("color")
+///         .long("color")
+///         .takes_value(true)
+///         .possible_values(&["always", "never"]))
+///     .get_matches_from_safe(vec!["prog", "--color", "alwys"]);
+/// let err = res.unwrap_err();
+///
+/// assert_eq!(err.kind, ErrorKind::InvalidValue);
+/// assert_eq!(err.get(ContextKind::InvalidValue),
+///            Some(&ContextValue::String("alwys".into())));
+/// # #[cfg(feature = "suggestions")]
+/// assert_eq!(err.get(ContextKind::SuggestedValue),
+///            Some(&ContextValue::String("always".into())));
+/// ```
+/// [`kind`]: ./enum.ErrorKind.html
+/// [`Error::get`]: ./struct.Error.html#method.get
 #[derive(Debug)]
 pub struct Error {
-    /// Formated error message
+    /// Formated error message, which is rendered from the `kind` and `context` (in the colors
+    /// and [`MessageCatalog`] of the [`App`]) when the error is returned from parsing
+    ///
+    /// [`MessageCatalog`]: ./trait.MessageCatalog.html
+    /// [`App`]: ./struct.App.html
     pub message: String,
     /// The type of error
     pub kind: ErrorKind,
     /// Any additional information passed along, such as the argument name that caused the error
     pub info: Option<Vec<String>>,
+    /// Context information about the error, see [`Error::get`]
+    ///
+    /// [`Error::get`]: ./struct.Error.html#method.get
+    pub context: Vec<(ContextKind, ContextValue)>,
 }
 
 impl Error {
@@ -385,7 +645,8 @@ impl Error {
     pub fn use_stderr(&self) -> bool {
         match self.kind {
             ErrorKind::HelpDisplayed |
-            ErrorKind::VersionDisplayed => false,
+            ErrorKind::VersionDisplayed |
+            ErrorKind::CompletionsDisplayed => false,
             _ => true,
         }
     }
@@ -393,131 +654,409 @@ impl Error {
     /// Prints the error to `stderr` and exits with a status of `1`
     pub fn exit(&self) -> ! {
         if self.use_stderr() {
-            wlnerr!("{}", self.message);
+            wlnerr!("{}", self.message());
             process::exit(1);
         }
         let out = io::stdout();
-        writeln!(&mut out.lock(), "{}", self.message).expect("Error writing Error to stdout");
+        writeln!(&mut out.lock(), "{}", self.message()).expect("Error writing Error to stdout");
         process::exit(0);
     }
 
+    /// Gets the formatted message of the error. This is the [`message`] field, or if it hasn't
+    /// been rendered yet, the message rendered from the kind and context of the error.
+    ///
+    /// [`message`]: ./struct.Error.html#structfield.message
+    pub fn message(&self) -> String {
+        if self.message.is_empty() && !self.context.is_empty() {
+            self.render(ColorWhen::Auto, None)
+        } else {
+            self.message.clone()
+        }
+    }
+
+    /// Gets a piece of context information about the error, such as the argument which caused
+    /// it, or `None` if the error has no such information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ContextKind, ContextValue};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::with_name("files")
+    ///         .long("files")
+    ///         .takes_value(true)
+    ///         .number_of_values(2))
+    ///     .get_matches_from_safe(vec!["prog", "--files", "a"]);
+    /// let err = res.unwrap_err();
+    ///
+    /// assert_eq!(err.get(ContextKind::InvalidArg),
+    ///            Some(&ContextValue::String("--files <files> <files>".into())));
+    /// assert_eq!(err.get(ContextKind::ExpectedNumValues), Some(&ContextValue::Number(2)));
+    /// assert_eq!(err.get(ContextKind::ActualNumValues), Some(&ContextValue::Number(1)));
+    /// assert_eq!(err.get(ContextKind::SuggestedArg), None);
+    /// ```
+    pub fn get(&self, kind: ContextKind) -> Option<&ContextValue> {
+        self.context.iter().find(|&&(k, _)| k == kind).map(|&(_, ref v)| v)
+    }
+
+    /// Gets all the context information about the error, in the order it was added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ContextKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::with_name("input").required(true))
+    ///     .get_matches_from_safe(vec!["prog"]);
+    /// let err = res.unwrap_err();
+    ///
+    /// let kinds = err.context().map(|&(k, _)| k).collect::<Vec<_>>();
+    /// assert_eq!(kinds, [ContextKind::MissingArgs, ContextKind::Usage]);
+    /// ```
+    pub fn context(&self) -> Iter<(ContextKind, ContextValue)> { self.context.iter() }
+
+    #[doc(hidden)]
+    pub fn write_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write!(w, "{}", self.message())
+    }
+
+    // Renders the message from the kind and context, unless it was already rendered (i.e. by a
+    // subcommand) or the error has no context
     #[doc(hidden)]
-    pub fn write_to<W: Write>(&self, w: &mut W) -> io::Result<()> { write!(w, "{}", self.message) }
+    pub fn render_message(mut self,
+                          color: ColorWhen,
+                          catalog: Option<&Arc<MessageCatalog>>)
+                          -> Self {
+        if self.message.is_empty() && !self.context.is_empty() {
+            self.message = self.render(color, catalog.map(|c| &**c));
+        }
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn raw<S: Into<String>>(kind: ErrorKind, message: S) -> Self {
+        Error {
+            message: message.into(),
+            kind: kind,
+            info: None,
+            context: vec![],
+        }
+    }
+
+    fn new(kind: ErrorKind, info: Option<Vec<String>>) -> Self {
+        Error {
+            message: String::new(),
+            kind: kind,
+            info: info,
+            context: vec![],
+        }
+    }
+
+    fn described(kind: ErrorKind, description: &str, color: ColorWhen) -> Self {
+        let c = Colorizer::new(ColorizerOption {
+            use_stderr: true,
+            when: color,
+        });
+        Error::raw(kind,
+                   format!("{} {}",
+                           c.error(catalog::default_catalog().error_label()),
+                           description))
+    }
+
+    fn with<V: Into<ContextValue>>(mut self, kind: ContextKind, val: V) -> Self {
+        self.context.push((kind, val.into()));
+        self
+    }
+
+    // Gets a piece of string context, or an empty string if it doesn't exist
+    fn ctx(&self, kind: ContextKind) -> String {
+        self.get(kind).map_or_else(String::new, |v| v.to_string())
+    }
+
+    // Gets a piece of numeric context, or `0` if it doesn't exist
+    fn num(&self, kind: ContextKind) -> usize {
+        match self.get(kind) {
+            Some(&ContextValue::Number(n)) => n,
+            _ => 0,
+        }
+    }
+
+    fn render(&self, color: ColorWhen, catalog: Option<&MessageCatalog>) -> String {
+        use self::ContextKind::*;
+
+        let c = Colorizer::new(ColorizerOption {
+            use_stderr: true,
+            when: color,
+        });
+        let cat = catalog.unwrap_or(catalog::default_catalog());
+        let warn = |kind| c.warning(self.ctx(kind)).to_string();
+        let usage_suffix = format!("\n\n{}\n\n{}",
+                                   self.ctx(Usage),
+                                   cat.more_info(&c.good("--help").to_string()));
+        let msg = match self.kind {
+            ErrorKind::ArgumentConflict => {
+                format!("{}{}",
+                        cat.argument_conflict(&*warn(InvalidArg),
+                                              self.get(PriorArg)
+                                                  .map(|_| warn(PriorArg))
+                                                  .as_ref()
+                                                  .map(|s| &**s)),
+                        usage_suffix)
+            }
+            ErrorKind::EmptyValue => {
+                format!("{}{}", cat.empty_value(&*warn(InvalidArg)), usage_suffix)
+            }
+            ErrorKind::InvalidValue => {
+                let mut sorted = match self.get(ValidValues) {
+                    Some(&ContextValue::Strings(ref v)) => v.clone(),
+                    _ => vec![],
+                };
+                sorted.sort();
+                let valid_values = sorted.iter()
+                    .map(|v| c.good(&**v).to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{}\n{}{}",
+                        cat.invalid_value(&*warn(InvalidValue), &*warn(InvalidArg), &*valid_values),
+                        match self.get(SuggestedValue) {
+                            Some(v) => {
+                                format!("\n\t{}",
+                                        cat.did_you_mean(&*format!("'{}'",
+                                                                   c.good(v.to_string()))))
+                            }
+                            None => String::new(),
+                        },
+                        usage_suffix)
+            }
+            ErrorKind::InvalidSubcommand => {
+                let s = self.ctx(InvalidSubcommand);
+                format!("{}\n\t{}\n\n{}{}",
+                        cat.unknown_subcommand(&*c.warning(&*s).to_string()),
+                        cat.did_you_mean(&*format!("'{}'", c.good(self.ctx(SuggestedSubcommand)))),
+                        cat.unknown_subcommand_hint(&*format!("{} {} {}",
+                                                              self.ctx(BinName),
+                                                              c.good("--"),
+                                                              s)),
+                        usage_suffix)
+            }
+            ErrorKind::UnrecognizedSubcommand => {
+                format!("{}\n\n{}\n\t{} help <subcommands>...\n\n{}",
+                        cat.unknown_subcommand(&*warn(InvalidSubcommand)),
+                        c.warning(cat.usage_heading()),
+                        self.ctx(BinName),
+                        cat.more_info(&c.good("--help").to_string()))
+            }
+            ErrorKind::MissingRequiredArgument => {
+                let required = match self.get(MissingArgs) {
+                    Some(&ContextValue::Strings(ref v)) => {
+                        v.iter().fold(String::new(),
+                                      |acc, s| acc + &format!("\n    {}", c.error(&**s))[..])
+                    }
+                    _ => String::new(),
+                };
+                format!("{}{}{}", cat.missing_required_arguments(), required, usage_suffix)
+            }
+            ErrorKind::MissingSubcommand => {
+                format!("{}{}", cat.missing_subcommand(&*warn(BinName)), usage_suffix)
+            }
+            ErrorKind::InvalidUtf8 => format!("{}{}", cat.invalid_utf8(), usage_suffix),
+            ErrorKind::TooManyValues => {
+                format!("{}{}",
+                        cat.too_many_values(&*warn(InvalidValue), &*warn(InvalidArg)),
+                        usage_suffix)
+            }
+            ErrorKind::TooFewValues => {
+                format!("{}{}",
+                        cat.too_few_values(&*warn(InvalidArg),
+                                           self.num(MinValues),
+                                           self.num(ActualNumValues)),
+                        usage_suffix)
+            }
+            ErrorKind::WrongNumberOfValues => {
+                format!("{}{}",
+                        cat.wrong_number_of_values(&*warn(InvalidArg),
+                                                   self.num(ExpectedNumValues),
+                                                   self.num(ActualNumValues)),
+                        usage_suffix)
+            }
+            ErrorKind::WrongNumberOfGroupArgs => {
+                let args = |kind| -> Vec<String> {
+                    match self.get(kind) {
+                        Some(&ContextValue::Strings(ref v)) => {
+                            v.iter().map(|a| format!("'{}'", c.warning(&**a))).collect()
+                        }
+                        _ => vec![],
+                    }
+                };
+                let bound = |kind| match self.get(kind) {
+                    Some(&ContextValue::Number(n)) => Some(n),
+                    _ => None,
+                };
+                format!("{}{}",
+                        cat.wrong_number_of_group_args(&args(GroupArgs).join(", "),
+                                                       bound(MinArgs),
+                                                       bound(MaxArgs),
+                                                       &args(GivenArgs)),
+                        usage_suffix)
+            }
+            ErrorKind::ValueValidation if self.get(Usage).is_some() => {
+                format!("{}{}",
+                        cat.value_validation(Some(&*warn(InvalidArg)),
+                                             Some(&*warn(InvalidValue)),
+                                             &*self.ctx(Custom)),
+                        usage_suffix)
+            }
+            ErrorKind::ValueValidation => {
+                let arg = self.get(InvalidArg).map(|_| warn(InvalidArg));
+                cat.value_validation(arg.as_ref().map(|s| &**s), None, &*self.ctx(Custom))
+            }
+            ErrorKind::MatchesValidation => {
+                format!("{}{}", cat.matches_validation(&*self.ctx(Custom)), usage_suffix)
+            }
+            ErrorKind::UnexpectedMultipleUsage => {
+                format!("{}{}",
+                        cat.unexpected_multiple_usage(&*warn(InvalidArg)),
+                        usage_suffix)
+            }
+            ErrorKind::UnknownArgument => {
+                let a = self.ctx(InvalidArg);
+                let did_you_mean = match (self.get(SuggestedArg), self.get(SuggestedSubcommand)) {
+                    (Some(arg), None) => {
+                        format!("\n\t{}",
+                                cat.did_you_mean(&*format!("{}{}",
+                                                           c.good("--"),
+                                                           c.good(arg.to_string()))))
+                    }
+                    (Some(_), Some(sc)) => {
+                        format!("\n\t{}",
+                                cat.did_you_mean_in_subcommand(&*c.good(&*a).to_string(),
+                                                               &*c.good(sc.to_string())
+                                                                   .to_string()))
+                    }
+                    _ => String::new(),
+                };
+                format!("{}{}{}",
+                        cat.unknown_argument(&*c.warning(&*a).to_string()),
+                        did_you_mean,
+                        usage_suffix)
+            }
+            ErrorKind::InvalidConfig => {
+                cat.invalid_config(&*warn(ConfigOrigin), &*self.ctx(Custom))
+            }
+            ErrorKind::InvalidDefinition if self.get(KeyPath).is_some() ||
+                                            self.get(DefinitionPath).is_some() => {
+                let paths = self.get(KeyPath).or_else(|| self.get(DefinitionPath));
+                let (paths, reasons) = match (paths, self.get(Custom)) {
+                    (Some(&ContextValue::Strings(ref p)), Some(&ContextValue::Strings(ref r))) => {
+                        (&**p, &**r)
+                    }
+                    _ => (&[][..], &[][..]),
+                };
+                let positions = match (self.get(Line), self.get(Column)) {
+                    (Some(&ContextValue::Numbers(ref lines)),
+                     Some(&ContextValue::Numbers(ref cols))) => {
+                        lines.iter().cloned().zip(cols.iter().cloned()).collect()
+                    }
+                    _ => vec![],
+                };
+                paths.iter()
+                    .zip(reasons)
+                    .enumerate()
+                    .map(|(i, (p, r))| {
+                        if p.is_empty() {
+                            // A problem with the whole document, such as an unknown key
+                            return match positions.get(i) {
+                                Some(&pos) => cat.invalid_definition_syntax(Some(pos), r),
+                                None => cat.invalid_definition(None, r),
+                            };
+                        }
+                        let p = c.warning(&**p).to_string();
+                        match positions.get(i) {
+                            Some(&pos) => cat.invalid_definition_at(&*p, pos, r),
+                            None => cat.invalid_definition(Some(&*p), r),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(&*format!("\n{} ", c.error(cat.error_label())))
+            }
+            ErrorKind::InvalidDefinition => {
+                let pos = self.get(Line).map(|_| (self.num(Line), self.num(Column)));
+                cat.invalid_definition_syntax(pos, &*self.ctx(Custom))
+            }
+            ErrorKind::ArgumentNotFound => cat.argument_not_found(&*self.ctx(InvalidArg)),
+            _ => self.ctx(Custom),
+        };
+        format!("{} {}", c.error(cat.error_label()), msg)
+    }
 
     #[doc(hidden)]
     pub fn argument_conflict<'a, 'b, A, O, U>(arg: &A,
                                               other: Option<O>,
-                                              usage: U,
-                                              color: ColorWhen)
+                                              usage: U)
                                               -> Self
         where A: AnyArg<'a, 'b> + Display,
               O: Into<String>,
               U: Display
     {
         let mut v = vec![arg.name().to_owned()];
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} The argument '{}' cannot be used with {}\n\n\
-                            {}\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             c.warning(&*arg.to_string()),
-                             match other {
-                                 Some(name) => {
-                                     let n = name.into();
-                                     v.push(n.clone());
-                                     c.warning(format!("'{}'", n))
-                                 }
-                                 None => {
-                                     c.none("one or more of the other specified arguments"
-                                         .to_owned())
-                                 }
-                             },
-                             usage,
-                             c.good("--help")),
-            kind: ErrorKind::ArgumentConflict,
-            info: Some(v),
+        let other = other.map(|o| o.into());
+        if let Some(ref n) = other {
+            v.push(n.clone());
+        }
+        let err = Error::new(ErrorKind::ArgumentConflict, Some(v))
+            .with(ContextKind::InvalidArg, arg.to_string());
+        match other {
+            Some(o) => err.with(ContextKind::PriorArg, o),
+            None => err,
         }
+        .with(ContextKind::Usage, usage.to_string())
     }
 
     #[doc(hidden)]
-    pub fn empty_value<'a, 'b, A, U>(arg: &A, usage: U, color: ColorWhen) -> Self
+    pub fn empty_value<'a, 'b, A, U>(arg: &A, usage: U) -> Self
         where A: AnyArg<'a, 'b> + Display,
               U: Display
     {
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} The argument '{}' requires a value but none was supplied\
-                            \n\n\
-                            {}\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             c.warning(arg.to_string()),
-                             usage,
-                             c.good("--help")),
-            kind: ErrorKind::EmptyValue,
-            info: Some(vec![arg.name().to_owned()]),
-        }
+        Error::new(ErrorKind::EmptyValue, Some(vec![arg.name().to_owned()]))
+            .with(ContextKind::InvalidArg, arg.to_string())
+            .with(ContextKind::Usage, usage.to_string())
     }
 
     #[doc(hidden)]
     pub fn invalid_value<'a, 'b, B, G, A, U>(bad_val: B,
                                              good_vals: &[G],
                                              arg: &A,
-                                             usage: U,
-                                             color: ColorWhen)
+                                             usage: U)
                                              -> Self
         where B: AsRef<str>,
               G: AsRef<str> + Display,
               A: AnyArg<'a, 'b> + Display,
               U: Display
     {
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        let suffix =
-            suggestions::did_you_mean_value_suffix(
-                bad_val.as_ref(),
-                good_vals.iter());
-
-        let mut sorted = vec![];
-        for v in good_vals {
-            let val = format!("{}", c.good(v));
-            sorted.push(val);
-        }
-        sorted.sort();
-        let valid_values = sorted.join(", ");
-        Error {
-            message: format!("{} '{}' isn't a valid value for '{}'\n\t\
-                            [values: {}]\n\
-                            {}\n\n\
-                            {}\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             c.warning(bad_val.as_ref()),
-                             c.warning(arg.to_string()),
-                             valid_values,
-                             suffix.0,
-                             usage,
-                             c.good("--help")),
-            kind: ErrorKind::InvalidValue,
-            info: Some(vec![arg.name().to_owned(), bad_val.as_ref().to_owned()]),
+        let bad_val = bad_val.as_ref();
+        let err = Error::new(ErrorKind::InvalidValue,
+                             Some(vec![arg.name().to_owned(), bad_val.to_owned()]))
+            .with(ContextKind::InvalidArg, arg.to_string())
+            .with(ContextKind::InvalidValue, bad_val)
+            .with(ContextKind::ValidValues,
+                  good_vals.iter().map(|v| v.as_ref().to_owned()).collect::<Vec<_>>());
+        let did_you_mean = if arg.is_set(ArgSettings::CaseInsensitive) {
+            suggestions::did_you_mean_ignore_case(bad_val, good_vals.iter())
+        } else {
+            suggestions::did_you_mean(bad_val, good_vals.iter())
+        };
+        match did_you_mean {
+            Some(candidate) => err.with(ContextKind::SuggestedValue, candidate),
+            None => err,
         }
+        .with(ContextKind::Usage, usage.to_string())
     }
 
     #[doc(hidden)]
     pub fn invalid_subcommand<S, D, N, U>(subcmd: S,
                                           did_you_mean: D,
                                           name: N,
-                                          usage: U,
-                                          color: ColorWhen)
+                                          usage: U)
                                           -> Self
         where S: Into<String>,
               D: AsRef<str> + Display,
@@ -525,309 +1064,268 @@ impl Error {
               U: Display
     {
         let s = subcmd.into();
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} The subcommand '{}' wasn't recognized\n\t\
-                            Did you mean '{}'?\n\n\
-                            If you believe you received this message in error, try \
-                            re-running with '{} {} {}'\n\n\
-                            {}\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             c.warning(&*s),
-                             c.good(did_you_mean.as_ref()),
-                             name,
-                             c.good("--"),
-                             &*s,
-                             usage,
-                             c.good("--help")),
-            kind: ErrorKind::InvalidSubcommand,
-            info: Some(vec![s]),
-        }
+        Error::new(ErrorKind::InvalidSubcommand, Some(vec![s.clone()]))
+            .with(ContextKind::InvalidSubcommand, s)
+            .with(ContextKind::SuggestedSubcommand, did_you_mean.as_ref())
+            .with(ContextKind::BinName, name.to_string())
+            .with(ContextKind::Usage, usage.to_string())
     }
 
     #[doc(hidden)]
-    pub fn unrecognized_subcommand<S, N>(subcmd: S, name: N, color: ColorWhen) -> Self
+    pub fn unrecognized_subcommand<S, N>(subcmd: S, name: N) -> Self
         where S: Into<String>,
               N: Display
     {
         let s = subcmd.into();
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} The subcommand '{}' wasn't recognized\n\n\
-                            {}\n\t\
-                                {} help <subcommands>...\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             c.warning(&*s),
-                             c.warning("USAGE:"),
-                             name,
-                             c.good("--help")),
-            kind: ErrorKind::UnrecognizedSubcommand,
-            info: Some(vec![s]),
-        }
+        Error::new(ErrorKind::UnrecognizedSubcommand, Some(vec![s.clone()]))
+            .with(ContextKind::InvalidSubcommand, s)
+            .with(ContextKind::BinName, name.to_string())
     }
 
     #[doc(hidden)]
-    pub fn missing_required_argument<R, U>(required: R, usage: U, color: ColorWhen) -> Self
-        where R: Display,
+    pub fn missing_required_argument<U>(required: Vec<String>, usage: U) -> Self
+        where U: Display
+    {
+        Error::new(ErrorKind::MissingRequiredArgument, None)
+            .with(ContextKind::MissingArgs, required)
+            .with(ContextKind::Usage, usage.to_string())
+    }
+
+    #[doc(hidden)]
+    pub fn missing_subcommand<N, U>(name: N, usage: U) -> Self
+        where N: AsRef<str> + Display,
               U: Display
     {
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} The following required arguments were not provided:{}\n\n\
-                            {}\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             required,
-                             usage,
-                             c.good("--help")),
-            kind: ErrorKind::MissingRequiredArgument,
-            info: None,
-        }
+        Error::new(ErrorKind::MissingSubcommand, None)
+            .with(ContextKind::BinName, name.as_ref())
+            .with(ContextKind::Usage, usage.to_string())
     }
 
     #[doc(hidden)]
-    pub fn missing_subcommand<N, U>(name: N, usage: U, color: ColorWhen) -> Self
+    pub fn missing_subcommand_auto<N, U>(name: N, usage: U) -> Self
         where N: AsRef<str> + Display,
               U: Display
     {
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} '{}' requires a subcommand, but one was not provided\n\n\
-                            {}\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             c.warning(name),
-                             usage,
-                             c.good("--help")),
-            kind: ErrorKind::MissingSubcommand,
-            info: None,
-        }
+        Error::missing_subcommand(name, usage).render_message(ColorWhen::Auto, None)
     }
 
 
     #[doc(hidden)]
-    pub fn invalid_utf8<U>(usage: U, color: ColorWhen) -> Self
+    pub fn invalid_utf8<U>(usage: U) -> Self
         where U: Display
     {
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} Invalid UTF-8 was detected in one or more arguments\n\n\
-                            {}\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             usage,
-                             c.good("--help")),
-            kind: ErrorKind::InvalidUtf8,
-            info: None,
-        }
+        Error::new(ErrorKind::InvalidUtf8, None)
+            .with(ContextKind::Usage, usage.to_string())
     }
 
     #[doc(hidden)]
     pub fn too_many_values<'a, 'b, V, A, U>(val: V,
                                             arg: &A,
-                                            usage: U,
-                                            color: ColorWhen)
+                                            usage: U)
                                             -> Self
         where V: AsRef<str> + Display + ToOwned,
               A: AnyArg<'a, 'b> + Display,
               U: Display
     {
         let v = val.as_ref();
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} The value '{}' was provided to '{}', but it wasn't expecting \
-                            any more values\n\n\
-                            {}\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             c.warning(v),
-                             c.warning(arg.to_string()),
-                             usage,
-                             c.good("--help")),
-            kind: ErrorKind::TooManyValues,
-            info: Some(vec![arg.name().to_owned(), v.to_owned()]),
-        }
+        Error::new(ErrorKind::TooManyValues, Some(vec![arg.name().to_owned(), v.to_owned()]))
+            .with(ContextKind::InvalidArg, arg.to_string())
+            .with(ContextKind::InvalidValue, v)
+            .with(ContextKind::Usage, usage.to_string())
     }
 
     #[doc(hidden)]
     pub fn too_few_values<'a, 'b, A, U>(arg: &A,
                                         min_vals: u64,
                                         curr_vals: usize,
-                                        usage: U,
-                                        color: ColorWhen)
+                                        usage: U)
                                         -> Self
         where A: AnyArg<'a, 'b> + Display,
               U: Display
     {
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} The argument '{}' requires at least {} values, but only {} w{} \
-                            provided\n\n\
-                            {}\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             c.warning(arg.to_string()),
-                             c.warning(min_vals.to_string()),
-                             c.warning(curr_vals.to_string()),
-                             if curr_vals > 1 { "ere" } else { "as" },
-                             usage,
-                             c.good("--help")),
-            kind: ErrorKind::TooFewValues,
-            info: Some(vec![arg.name().to_owned()]),
-        }
+        Error::new(ErrorKind::TooFewValues, Some(vec![arg.name().to_owned()]))
+            .with(ContextKind::InvalidArg, arg.to_string())
+            .with(ContextKind::MinValues, min_vals as usize)
+            .with(ContextKind::ActualNumValues, curr_vals)
+            .with(ContextKind::Usage, usage.to_string())
     }
 
     #[doc(hidden)]
-    pub fn value_validation<'a, 'b, A>(arg: Option<&A>, err: String, color: ColorWhen) -> Self
+    pub fn value_validation<'a, 'b, A>(arg: Option<&A>,
+                                       bad_val: Option<String>,
+                                       err: String)
+                                       -> Self
         where A: AnyArg<'a, 'b> + Display
     {
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} Invalid value{}: {}",
-                             c.error("error:"),
-                             if let Some(a) = arg {
-                                 format!(" for '{}'", c.warning(a.to_string()))
-                             } else {
-                                 "".to_string()
-                             },
-                             err),
-            kind: ErrorKind::ValueValidation,
-            info: None,
+        let mut e = Error::new(ErrorKind::ValueValidation, None);
+        if let Some(a) = arg {
+            e = e.with(ContextKind::InvalidArg, a.to_string());
         }
+        if let Some(v) = bad_val {
+            e = e.with(ContextKind::InvalidValue, v);
+        }
+        e.with(ContextKind::Custom, err)
+    }
+
+    #[doc(hidden)]
+    pub fn value_parse<N, B, E, U>(name: N, bad_val: B, err: E, usage: U) -> Self
+        where N: Into<String>,
+              B: Into<String>,
+              E: Display,
+              U: Display
+    {
+        let name = name.into();
+        let bad_val = bad_val.into();
+        Error::new(ErrorKind::ValueValidation, Some(vec![name.clone(), bad_val.clone()]))
+            .with(ContextKind::InvalidArg, name)
+            .with(ContextKind::InvalidValue, bad_val)
+            .with(ContextKind::Custom, err.to_string())
+            .with(ContextKind::Usage, usage.to_string())
     }
 
     #[doc(hidden)]
     pub fn value_validation_auto(err: String) -> Self {
-        let n: Option<&FlagBuilder> = None; 
-        Error::value_validation(n, err, ColorWhen::Auto)
+        let n: Option<&FlagBuilder> = None;
+        Error::value_validation(n, None, err).render_message(ColorWhen::Auto, None)
     }
 
     #[doc(hidden)]
-    pub fn wrong_number_of_values<'a, 'b, A, S, U>(arg: &A,
-                                                   num_vals: u64,
-                                                   curr_vals: usize,
-                                                   suffix: S,
-                                                   usage: U,
-                                                   color: ColorWhen)
-                                                   -> Self
+    pub fn matches_validation<U: Display>(err: String, usage: U) -> Self {
+        Error::new(ErrorKind::MatchesValidation, None)
+            .with(ContextKind::Custom, err)
+            .with(ContextKind::Usage, usage.to_string())
+    }
+
+    #[doc(hidden)]
+    pub fn wrong_number_of_values<'a, 'b, A, U>(arg: &A,
+                                                num_vals: u64,
+                                                curr_vals: usize,
+                                                usage: U)
+                                                -> Self
         where A: AnyArg<'a, 'b> + Display,
-              S: Display,
               U: Display
     {
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} The argument '{}' requires {} values, but {} w{} \
-                            provided\n\n\
-                            {}\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             c.warning(arg.to_string()),
-                             c.warning(num_vals.to_string()),
-                             c.warning(curr_vals.to_string()),
-                             suffix,
-                             usage,
-                             c.good("--help")),
-            kind: ErrorKind::WrongNumberOfValues,
-            info: Some(vec![arg.name().to_owned()]),
+        Error::new(ErrorKind::WrongNumberOfValues, Some(vec![arg.name().to_owned()]))
+            .with(ContextKind::InvalidArg, arg.to_string())
+            .with(ContextKind::ExpectedNumValues, num_vals as usize)
+            .with(ContextKind::ActualNumValues, curr_vals)
+            .with(ContextKind::Usage, usage.to_string())
+    }
+
+    #[doc(hidden)]
+    pub fn wrong_number_of_group_args<U>(group: &str,
+                                         group_args: Vec<String>,
+                                         given: Vec<String>,
+                                         min: Option<usize>,
+                                         max: Option<usize>,
+                                         usage: U)
+                                         -> Self
+        where U: Display
+    {
+        let mut e = Error::new(ErrorKind::WrongNumberOfGroupArgs, Some(vec![group.to_owned()]))
+            .with(ContextKind::Group, group)
+            .with(ContextKind::GroupArgs, group_args)
+            .with(ContextKind::GivenArgs, given);
+        if let Some(min) = min {
+            e = e.with(ContextKind::MinArgs, min);
         }
+        if let Some(max) = max {
+            e = e.with(ContextKind::MaxArgs, max);
+        }
+        e.with(ContextKind::Usage, usage.to_string())
     }
 
     #[doc(hidden)]
-    pub fn unexpected_multiple_usage<'a, 'b, A, U>(arg: &A, usage: U, color: ColorWhen) -> Self
+    pub fn unexpected_multiple_usage<'a, 'b, A, U>(arg: &A, usage: U) -> Self
         where A: AnyArg<'a, 'b> + Display,
               U: Display
     {
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} The argument '{}' was provided more than once, but cannot \
-                            be used multiple times\n\n\
-                            {}\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             c.warning(arg.to_string()),
-                             usage,
-                             c.good("--help")),
-            kind: ErrorKind::UnexpectedMultipleUsage,
-            info: Some(vec![arg.name().to_owned()]),
-        }
+        Error::new(ErrorKind::UnexpectedMultipleUsage, Some(vec![arg.name().to_owned()]))
+            .with(ContextKind::InvalidArg, arg.to_string())
+            .with(ContextKind::Usage, usage.to_string())
+    }
+
+    #[doc(hidden)]
+    pub fn completions_displayed(candidates: Vec<String>) -> Self {
+        let mut e = Error::raw(ErrorKind::CompletionsDisplayed, candidates.join("\n"));
+        e.info = Some(candidates);
+        e
     }
 
     #[doc(hidden)]
     pub fn unknown_argument<A, U>(arg: A,
-                                  did_you_mean: &str,
-                                  usage: U,
-                                  color: ColorWhen)
+                                  did_you_mean: Option<(&str, Option<&str>)>,
+                                  usage: U)
                                   -> Self
         where A: Into<String>,
               U: Display
     {
         let a = arg.into();
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} Found argument '{}' which wasn't expected, or isn't valid in \
-            this context{}\n\
-                            {}\n\n\
-                            For more information try {}",
-                             c.error("error:"),
-                             c.warning(&*a),
-                             if did_you_mean.is_empty() {
-                                 "\n".to_owned()
-                             } else {
-                                 format!("{}\n", did_you_mean)
-                             },
-                             usage,
-                             c.good("--help")),
-            kind: ErrorKind::UnknownArgument,
-            info: Some(vec![a]),
+        let mut err = Error::new(ErrorKind::UnknownArgument, Some(vec![a.clone()]))
+            .with(ContextKind::InvalidArg, a);
+        if let Some((arg, sc)) = did_you_mean {
+            err = err.with(ContextKind::SuggestedArg, arg);
+            if let Some(sc) = sc {
+                err = err.with(ContextKind::SuggestedSubcommand, sc);
+            }
         }
+        err.with(ContextKind::Usage, usage.to_string())
     }
 
     #[doc(hidden)]
     pub fn io_error(e: &Error, color: ColorWhen) -> Self {
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: color,
-        });
-        Error {
-            message: format!("{} {}", c.error("error:"), e.description()),
-            kind: ErrorKind::Io,
-            info: None,
+        Error::described(ErrorKind::Io, e.description(), color)
+    }
+
+    #[doc(hidden)]
+    pub fn invalid_config<O, R>(origin: O, reason: R) -> Self
+        where O: Into<String>,
+              R: Display
+    {
+        let origin = origin.into();
+        Error::new(ErrorKind::InvalidConfig, Some(vec![origin.clone()]))
+            .with(ContextKind::ConfigOrigin, origin)
+            .with(ContextKind::Custom, reason.to_string())
+            .render_message(ColorWhen::Auto, None)
+    }
+
+    #[doc(hidden)]
+    pub fn invalid_definition(problems: Vec<(String, String)>,
+                              positions: Option<Vec<(usize, usize)>>)
+                              -> Self {
+        let (paths, reasons): (Vec<_>, Vec<_>) = problems.into_iter().unzip();
+        let mut err = Error::new(ErrorKind::InvalidDefinition, Some(paths.clone()))
+            .with(ContextKind::KeyPath, paths)
+            .with(ContextKind::Custom, reasons);
+        if let Some(positions) = positions {
+            let (lines, cols): (Vec<_>, Vec<_>) = positions.into_iter().unzip();
+            err = err.with(ContextKind::Line, ContextValue::Numbers(lines))
+                .with(ContextKind::Column, ContextValue::Numbers(cols));
+        }
+        err.render_message(ColorWhen::Auto, None)
+    }
+
+    #[doc(hidden)]
+    pub fn definition_problems(problems: Vec<(String, String)>) -> Self {
+        let (paths, reasons): (Vec<_>, Vec<_>) = problems.into_iter().unzip();
+        Error::new(ErrorKind::InvalidDefinition, Some(paths.clone()))
+            .with(ContextKind::DefinitionPath, paths)
+            .with(ContextKind::Custom, reasons)
+            .render_message(ColorWhen::Auto, None)
+    }
+
+    #[doc(hidden)]
+    pub fn definition_syntax<R: Into<String>>(pos: Option<(usize, usize)>, reason: R) -> Self {
+        // The parsers append the position to their messages, which is stored separately instead
+        let mut reason = reason.into();
+        if let Some(i) = reason.rfind(" at line ") {
+            reason.truncate(i);
+        }
+        let mut err = Error::new(ErrorKind::InvalidDefinition, None);
+        if let Some((line, col)) = pos {
+            err = err.with(ContextKind::Line, line).with(ContextKind::Column, col);
         }
+        err.with(ContextKind::Custom, reason).render_message(ColorWhen::Auto, None)
     }
 
     #[doc(hidden)]
@@ -835,17 +1333,9 @@ impl Error {
         where A: Into<String>
     {
         let a = arg.into();
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: ColorWhen::Auto,
-        });
-        Error {
-            message: format!("{} The argument '{}' wasn't found",
-                             c.error("error:"),
-                             a.clone()),
-            kind: ErrorKind::ArgumentNotFound,
-            info: Some(vec![a]),
-        }
+        Error::new(ErrorKind::ArgumentNotFound, Some(vec![a.clone()]))
+            .with(ContextKind::InvalidArg, a)
+            .render_message(ColorWhen::Auto, None)
     }
 
     /// Create an error with a custom description.
@@ -853,30 +1343,59 @@ impl Error {
     /// This can be used in combination with `Error::exit` to exit your program
     /// with a custom error message.
     pub fn with_description(description: &str, kind: ErrorKind) -> Self {
-        let c = Colorizer::new(ColorizerOption {
-            use_stderr: true,
-            when: ColorWhen::Auto,
-        });
-        Error {
-            message: format!("{} {}", c.error("error:"), description),
-            kind: kind,
-            info: None,
-        }
+        Error::described(kind, description, ColorWhen::Auto)
     }
 }
 
+impl<'a> From<&'a str> for ContextValue {
+    fn from(s: &'a str) -> Self { ContextValue::String(s.to_owned()) }
+}
+
+impl From<String> for ContextValue {
+    fn from(s: String) -> Self { ContextValue::String(s) }
+}
+
+impl From<Vec<String>> for ContextValue {
+    fn from(v: Vec<String>) -> Self { ContextValue::Strings(v) }
+}
+
+impl From<usize> for ContextValue {
+    fn from(n: usize) -> Self { ContextValue::Number(n) }
+}
+
 impl StdError for Error {
     fn description(&self) -> &str { &*self.message }
 }
 
 impl Display for Error {
-    fn fmt(&self, f: &mut std_fmt::Formatter) -> std_fmt::Result { writeln!(f, "{}", self.message) }
+    fn fmt(&self, f: &mut std_fmt::Formatter) -> std_fmt::Result {
+        writeln!(f, "{}", self.message())
+    }
 }
 
 impl From<io::Error> for Error {
     fn from(e: io::Error) -> Self { Error::with_description(e.description(), ErrorKind::Io) }
 }
 
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        let pos = if e.line() > 0 {
+            Some((e.line(), e.column()))
+        } else {
+            None
+        };
+        Error::definition_syntax(pos, e.to_string())
+    }
+}
+
+#[cfg(feature = "toml")]
+impl From<toml::de::Error> for Error {
+    fn from(e: toml::de::Error) -> Self {
+        Error::definition_syntax(e.line_col().map(|(l, c)| (l + 1, c + 1)), e.to_string())
+    }
+}
+
 impl From<std_fmt::Error> for Error {
     fn from(e: std_fmt::Error) -> Self {
         Error::with_description(e.description(), ErrorKind::Format)
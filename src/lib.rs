@@ -353,6 +353,10 @@
 //! #### Opt-in features
 //! 
 //! * **"yaml"**: Enables building CLIs from YAML documents. (builds dependency `yaml-rust`)
+//! * **"toml"**: Enables building CLIs from TOML documents, and reading TOML files with
+//! `ConfigSource`. (builds dependency `toml`)
+//! * **"json"**: Enables building CLIs from JSON documents, and exporting CLIs as JSON documents.
+//! (builds dependency `serde_json`)
 //! * **"unstable"**: Enables unstable `clap` features that may change from release to release
 //! 
 //! ### Dependencies Tree
@@ -535,6 +539,10 @@ extern crate strsim;
 extern crate ansi_term;
 #[cfg(feature = "yaml")]
 extern crate yaml_rust;
+#[cfg(feature = "toml")]
+extern crate toml;
+#[cfg(feature = "json")]
+extern crate serde_json;
 extern crate unicode_width;
 #[macro_use]
 extern crate bitflags;
@@ -548,11 +556,16 @@ extern crate atty;
 
 #[cfg(feature = "yaml")]
 pub use yaml_rust::YamlLoader;
-pub use args::{Arg, ArgGroup, ArgMatches, ArgSettings, SubCommand, Values, OsValues};
+pub use args::{Arg, ArgGroup, ArgKey, ArgMatches, ArgSettings, SubCommand, Values, OsValues,
+               Indices, ValueHint, ValueSource, ValueParser, IntParser, PathParser, BoolParser,
+               DurationParser, EnumParser};
 pub use app::{App, AppSettings};
 pub use fmt::Format;
-pub use errors::{Error, ErrorKind, Result};
+pub use errors::{Error, ErrorKind, ContextKind, ContextValue, Result};
 pub use completions::Shell;
+pub use config::ConfigSource;
+pub use catalog::MessageCatalog;
+pub use derive::{Clap, FromArgMatches, IntoApp, Subcommand};
 
 #[macro_use]
 mod macros;
@@ -565,6 +578,9 @@ mod errors;
 mod osstringext;
 mod strext;
 mod completions;
+mod config;
+mod catalog;
+mod derive;
 
 const INTERNAL_ERROR_MSG: &'static str = "Fatal internal error. Please consider filing a bug \
                                           report at https://github.com/kbknapp/clap-rs/issues";
@@ -37,6 +37,9 @@ macro_rules! load_yaml {
 /// and those where the argument wasn't present (such as a non-required argument). You can use
 /// it to get a single value, or a iterator as with the [`ArgMatches::values_of`]
 ///
+/// **NOTE:** Prefer [`ArgMatches::value_of_t`] whose parse failures name the argument and include
+/// the usage string
+///
 /// # Examples
 ///
 /// ```no_run
@@ -57,6 +60,7 @@ macro_rules! load_yaml {
 /// [`std::str::FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
 /// [`ArgMatches::values_of`]: ./struct.ArgMatches.html#method.values_of
 /// [`Result<T,String>`]: https://doc.rust-lang.org/std/result/enum.Result.html
+/// [`ArgMatches::value_of_t`]: ./struct.ArgMatches.html#method.value_of_t
 #[macro_export]
 macro_rules! value_t {
     ($m:ident, $v:expr, $t:ty) => {
@@ -125,6 +129,9 @@ macro_rules! value_t_or_exit {
 /// This macro returns a [`clap::Result<Vec<T>>`] which allows you as the developer to decide
 /// what you'd like to do on a failed parse.
 ///
+/// **NOTE:** Prefer [`ArgMatches::values_of_t`] whose parse failures name the argument and
+/// include the usage string
+///
 /// # Examples
 ///
 /// ```no_run
@@ -150,6 +157,7 @@ macro_rules! value_t_or_exit {
 /// [`std::str::FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
 /// [`Vec<T>`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
 /// [`clap::Result<Vec<T>>`]: ./type.Result.html
+/// [`ArgMatches::values_of_t`]: ./struct.ArgMatches.html#method.values_of_t
 #[macro_export]
 macro_rules! values_t {
     ($m:ident, $v:expr, $t:ty) => {
@@ -262,7 +270,9 @@ macro_rules! _clap_count_exprs {
 /// retrieve a `Vec<&'static str>` of the variant names, as well as implementing [`FromStr`] and
 /// [`Display`] automatically.
 ///
-/// **NOTE:** Case insensitivity is supported for ASCII characters only
+/// **NOTE:** Case insensitivity is supported for ASCII characters only. The generated [`FromStr`]
+/// always ignores case, so to have the argument's [possible values] accept the same spellings pair
+/// `possible_values(&Foo::variants())` with [`Arg::case_insensitive(true)`]
 ///
 /// **NOTE:** This macro automatically implements [`std::str::FromStr`] and [`std::fmt::Display`]
 ///
@@ -286,7 +296,9 @@ macro_rules! _clap_count_exprs {
 /// // and implements std::str::FromStr to use with the value_t! macros
 /// fn main() {
 ///     let m = App::new("app")
-///                 .arg_from_usage("<foo> 'the foo'")
+///                 .arg(Arg::from_usage("<foo> 'the foo'")
+///                     .possible_values(&Foo::variants())
+///                     .case_insensitive(true))
 ///                 .get_matches();
 ///     let f = value_t!(m, "foo", Foo).unwrap_or_else(|e| e.exit());
 ///
@@ -297,6 +309,8 @@ macro_rules! _clap_count_exprs {
 /// [`std::str::FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
 /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
 /// [`std::fmt::Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+/// [possible values]: ./struct.Arg.html#method.possible_values
+/// [`Arg::case_insensitive(true)`]: ./struct.Arg.html#method.case_insensitive
 #[macro_export]
 macro_rules! arg_enum {
     (@as_item $($i:item)*) => ($($i)*);
@@ -370,6 +384,92 @@ macro_rules! arg_enum {
     };
 }
 
+/// Declares an enum of [`ArgKey`]s, mapping each variant to the name of an argument or group. The
+/// enum also implements `AsRef<str>`, so that the keys can be passed to the methods of
+/// [`ArgMatches`], and [`Display`].
+///
+/// **NOTE:** The enum always derives `Clone`, `Copy`, `PartialEq` and `Eq`, other traits can be
+/// derived with `#[derive()]`
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate clap;
+/// # use clap::{App, Arg, ArgKey};
+/// arg_keys!{
+///     #[derive(Debug)]
+///     pub enum Key {
+///         Config => "config",
+///         Verbose => "verbose",
+///     }
+/// }
+///
+/// fn main() {
+///     let m = App::new("app")
+///         .arg_keys(Key::all())
+///         .arg(Arg::with_name(Key::Config.name())
+///             .long("config")
+///             .takes_value(true)
+///             .requires(Key::Verbose.name()))
+///         .arg(Arg::with_name(Key::Verbose.name())
+///             .short("v"))
+///         .get_matches_from(vec!["app", "--config", "app.toml", "-v"]);
+///
+///     assert_eq!(m.value_of(Key::Config), Some("app.toml"));
+///     assert!(m.is_present(Key::Verbose));
+/// }
+/// ```
+/// [`ArgKey`]: ./trait.ArgKey.html
+/// [`ArgMatches`]: ./struct.ArgMatches.html
+/// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+#[macro_export]
+macro_rules! arg_keys {
+    (@as_item $($i:item)*) => ($($i)*);
+    (@impls ( $($tts:tt)* ) -> ($e:ident, $($v:ident => $n:expr),+)) => {
+        arg_keys!(@as_item
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        $($tts)*
+
+        impl $crate::ArgKey for $e {
+            fn name(self) -> &'static str {
+                match self {
+                    $($e::$v => $n,)+
+                }
+            }
+
+            fn all() -> &'static [Self] {
+                const ALL: &'static [$e] = &[$($e::$v),+];
+                ALL
+            }
+        }
+        impl ::std::convert::AsRef<str> for $e {
+            fn as_ref(&self) -> &str { $crate::ArgKey::name(*self) }
+        }
+        impl ::std::fmt::Display for $e {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str($crate::ArgKey::name(*self))
+            }
+        });
+    };
+    ($(#[$($m:meta),+])* pub enum $e:ident { $($v:ident => $n:expr),+ $(,)* } ) => {
+        arg_keys!(@impls
+            ($(#[$($m),+])*
+            pub enum $e {
+                $($v),+
+            }) -> ($e, $($v => $n),+)
+        );
+    };
+    ($(#[$($m:meta),+])* enum $e:ident { $($v:ident => $n:expr),+ $(,)* } ) => {
+        arg_keys!(@impls
+            ($(#[$($m),+])*
+            enum $e {
+                $($v),+
+            }) -> ($e, $($v => $n),+)
+        );
+    };
+}
+
 /// Allows you to pull the version from your Cargo.toml at compile time as
 /// `MAJOR.MINOR.PATCH_PKGVERSION_PRE`
 ///
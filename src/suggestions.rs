@@ -3,9 +3,6 @@ use app::App;
 #[cfg(feature = "suggestions")]
 use strsim;
 
-// Internal
-use fmt::Format;
-
 /// Produces a string from a given list of possible values which is similar to
 /// the passed in value `v` with a certain confidence.
 /// Thus in a list of possible values like ["foo", "bar"], the value "fop" will yield
@@ -16,10 +13,30 @@ pub fn did_you_mean<'a, T: ?Sized, I>(v: &str, possible_values: I) -> Option<&'a
     where T: AsRef<str> + 'a,
           I: IntoIterator<Item = &'a T>
 {
+    best_match(possible_values, |pv| strsim::jaro_winkler(v, pv))
+}
+
+/// The same as `did_you_mean`, but the values are compared ignoring case, so "FOP" yields
+/// `Some("foo")` as well. Used for the values of `ArgSettings::CaseInsensitive` args.
+#[cfg(feature = "suggestions")]
+#[cfg_attr(feature = "lints", allow(needless_lifetimes))]
+pub fn did_you_mean_ignore_case<'a, T: ?Sized, I>(v: &str, possible_values: I) -> Option<&'a str>
+    where T: AsRef<str> + 'a,
+          I: IntoIterator<Item = &'a T>
+{
+    let v = v.to_lowercase();
+    best_match(possible_values, |pv| strsim::jaro_winkler(&*v, &*pv.to_lowercase()))
+}
 
+#[cfg(feature = "suggestions")]
+fn best_match<'a, T: ?Sized, I, F>(possible_values: I, confidence_of: F) -> Option<&'a str>
+    where T: AsRef<str> + 'a,
+          I: IntoIterator<Item = &'a T>,
+          F: Fn(&str) -> f64
+{
     let mut candidate: Option<(f64, &str)> = None;
     for pv in possible_values {
-        let confidence = strsim::jaro_winkler(v, pv.as_ref());
+        let confidence = confidence_of(pv.as_ref());
         if confidence > 0.8 &&
            (candidate.is_none() || (candidate.as_ref().unwrap().0 < confidence)) {
             candidate = Some((confidence, pv.as_ref()));
@@ -39,48 +56,36 @@ pub fn did_you_mean<'a, T: ?Sized, I>(_: &str, _: I) -> Option<&'a str>
     None
 }
 
-/// Returns a suffix that can be empty, or is the standard 'did you mean' phrase
-#[cfg_attr(feature = "lints", allow(needless_lifetimes))]
-pub fn did_you_mean_flag_suffix<'z, T, I>(arg: &str, longs: I, subcommands: &'z [App])
-                                     -> (String, Option<&'z str>)
-    where T: AsRef<str> + 'z,
-          I: IntoIterator<Item = &'z T>
+#[cfg(not(feature = "suggestions"))]
+pub fn did_you_mean_ignore_case<'a, T: ?Sized, I>(_: &str, _: I) -> Option<&'a str>
+    where T: AsRef<str> + 'a,
+          I: IntoIterator<Item = &'a T>
 {
-    match did_you_mean(arg, longs) {
-        Some(candidate) => {
-            let suffix = format!("\n\tDid you mean {}{}?", Format::Good("--"), Format::Good(candidate));
-            return (suffix, Some(candidate))
-        }
-        None => {
-            for subcommand in subcommands {
-                let opts = subcommand.p.flags.iter().filter_map(|f| f.s.long).chain(
-                    subcommand.p.opts.iter().filter_map(|o| o.s.long));
-
-                if let Some(candidate) = did_you_mean(arg, opts) {
-                    let suffix = format!(
-                        "\n\tDid you mean to put '--{}' after the subcommand '{}'?",
-                        Format::Good(arg),
-                        Format::Good(candidate));
-                    return (suffix, Some(candidate));
-                }
-            }
-        }
-    }
-    return (String::new(), None)
+    None
 }
 
-/// Returns a suffix that can be empty, or is the standard 'did you mean' phrase
-pub fn did_you_mean_value_suffix<'z, T, I>(arg: &str, values: I) -> (String, Option<&'z str>)
+/// Finds the long flag the user most likely meant to use, either on the current command or on
+/// one of its subcommands, in which case the name of that subcommand is returned as well
+#[cfg_attr(feature = "lints", allow(needless_lifetimes))]
+pub fn did_you_mean_flag<'z, T, I>(arg: &str,
+                                   longs: I,
+                                   subcommands: &'z [App])
+                                   -> Option<(&'z str, Option<&'z str>)>
     where T: AsRef<str> + 'z,
           I: IntoIterator<Item = &'z T>
 {
-    match did_you_mean(arg, values) {
-        Some(candidate) => {
-            let suffix = format!("\n\tDid you mean '{}'?", Format::Good(candidate));
-            (suffix, Some(candidate))
+    if let Some(candidate) = did_you_mean(arg, longs) {
+        return Some((candidate, None));
+    }
+    for subcommand in subcommands {
+        let opts = subcommand.p.flags.iter().filter_map(|f| f.s.long).chain(
+            subcommand.p.opts.iter().filter_map(|o| o.s.long));
+
+        if let Some(candidate) = did_you_mean(arg, opts) {
+            return Some((candidate, Some(&*subcommand.p.meta.name)));
         }
-        None => (String::new(), None),
     }
+    None
 }
 
 #[cfg(all(test, features = "suggestions"))]
@@ -100,16 +105,8 @@ mod test {
     }
 
     #[test]
-    fn suffix_long() {
-        let p_vals = ["test", "possible", "values"];
-        let suffix = "\n\tDid you mean \'--test\'?";
-        assert_eq!(did_you_mean_flag_suffix("tst", p_vals.iter(), []), (suffix, Some("test")));
-    }
-
-    #[test]
-    fn suffix_enum() {
+    fn flag_long() {
         let p_vals = ["test", "possible", "values"];
-        let suffix = "\n\tDid you mean \'test\'?";
-        assert_eq!(did_you_mean_value_suffix("tst", p_vals.iter()), (suffix, Some("test")));
+        assert_eq!(did_you_mean_flag("tst", p_vals.iter(), &[]), Some(("test", None)));
     }
 }
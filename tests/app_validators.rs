@@ -0,0 +1,99 @@
+extern crate clap;
+extern crate regex;
+
+include!("../clap-test.rs");
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use clap::{App, Arg, ArgMatches, ContextKind, ErrorKind, SubCommand};
+
+static RANGE_ERR: &'static str = "error: Invalid arguments: --end (5) must be greater than --start \
+                                  (10)
+
+USAGE:
+    clap-test --end <end> --start <start>
+
+For more information try --help";
+
+fn ordered(m: &ArgMatches) -> Result<(), String> {
+    let start: u32 = m.value_of("start").unwrap().parse().unwrap();
+    let end: u32 = m.value_of("end").unwrap().parse().unwrap();
+    if end > start {
+        Ok(())
+    } else {
+        Err(format!("--end ({}) must be greater than --start ({})", end, start))
+    }
+}
+
+fn range() -> App<'static, 'static> {
+    App::new("range")
+        .arg(Arg::with_name("start").long("start").default_value("0"))
+        .arg(Arg::with_name("end").long("end").takes_value(true).required(true))
+        .validator(ordered)
+}
+
+#[test]
+fn app_validator_passes() {
+    let m = range().get_matches_from_safe(vec!["range", "--start", "3", "--end", "5"]);
+    assert!(m.is_ok());
+    let m = range().get_matches_from_safe(vec!["range", "--end", "5"]);
+    assert!(m.is_ok());
+}
+
+#[test]
+fn app_validator_fails() {
+    let err = range()
+        .get_matches_from_safe(vec!["range", "--start", "10", "--end", "5"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MatchesValidation);
+    assert_eq!(err.get(ContextKind::Custom).unwrap().to_string(),
+               "--end (5) must be greater than --start (10)");
+}
+
+#[test]
+fn app_validator_error_output() {
+    assert!(test::compare_output(range(),
+                                 "clap-test --start 10 --end 5",
+                                 RANGE_ERR,
+                                 true));
+}
+
+#[test]
+fn app_validator_runs_after_other_checks() {
+    let err = range().get_matches_from_safe(vec!["range", "--start", "10"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn app_validators_run_in_order() {
+    let err = App::new("prog")
+        .arg(Arg::with_name("a").short("a"))
+        .validator(|_| Err(String::from("first")))
+        .validator(|_| Err(String::from("second")))
+        .get_matches_from_safe(vec!["prog"])
+        .unwrap_err();
+    assert_eq!(err.get(ContextKind::Custom).unwrap().to_string(), "first");
+}
+
+#[test]
+fn app_validators_of_subcommands() {
+    let seen = Rc::new(RefCell::new(vec![]));
+    let (outer, inner) = (seen.clone(), seen.clone());
+    let m = App::new("prog")
+        .arg(Arg::with_name("verbose").short("v"))
+        .validator(move |m| {
+                       outer.borrow_mut().push(format!("prog:{}", m.subcommand_name().unwrap()));
+                       Ok(())
+                   })
+        .subcommand(SubCommand::with_name("build")
+                        .arg(Arg::with_name("jobs").short("j").takes_value(true))
+                        .validator(move |m| {
+                                       inner.borrow_mut()
+                                           .push(format!("build:{}", m.value_of("jobs").unwrap()));
+                                       Ok(())
+                                   }))
+        .get_matches_from_safe(vec!["prog", "-v", "build", "-j", "4"]);
+    assert!(m.is_ok());
+    assert_eq!(*seen.borrow(), ["build:4", "prog:build"]);
+}
@@ -0,0 +1,112 @@
+#[macro_use]
+extern crate clap;
+
+use clap::{App, Arg, ArgGroup, ArgKey, SubCommand};
+
+arg_keys!{
+    #[derive(Debug)]
+    enum Key {
+        Input => "input",
+        Output => "output",
+        Force => "force",
+        Files => "files",
+    }
+}
+
+fn app() -> App<'static, 'static> {
+    App::new("keys")
+        .arg_keys(Key::all())
+        .arg(Arg::with_name(Key::Input.name()).required(true))
+        .arg(Arg::with_name(Key::Output.name())
+                 .long("output")
+                 .takes_value(true)
+                 .requires(Key::Force.name()))
+        .arg(Arg::with_name(Key::Force.name()).short("f"))
+        .group(ArgGroup::with_name(Key::Files.name())
+                   .args(&[Key::Input.name(), Key::Output.name()])
+                   .multiple(true))
+}
+
+#[test]
+fn typed_lookups() {
+    let m = app().get_matches_from(vec!["keys", "in.txt", "--output", "out.txt", "-f"]);
+    assert_eq!(m.value_of(Key::Input), Some("in.txt"));
+    assert_eq!(m.value_of(Key::Output), Some("out.txt"));
+    assert!(m.is_present(Key::Force));
+    assert!(m.is_present(Key::Files));
+    assert_eq!(Key::Output.to_string(), "output");
+}
+
+#[test]
+#[should_panic(expected = "'outptu' isn't one of the argument keys of the app")]
+fn unknown_lookup() {
+    let m = app().get_matches_from(vec!["keys", "in.txt"]);
+    let _ = m.value_of("outptu");
+}
+
+#[test]
+fn unknown_lookup_without_keys() {
+    let m = App::new("keys")
+        .arg(Arg::with_name("input"))
+        .get_matches_from(vec!["keys", "in.txt"]);
+    assert_eq!(m.value_of("outptu"), None);
+}
+
+#[test]
+#[should_panic(expected = "The argument 'extra' isn't one of the keys of the app")]
+fn arg_without_key() {
+    app()
+        .arg(Arg::with_name("extra").long("extra"))
+        .get_matches_from(vec!["keys", "in.txt"]);
+}
+
+#[test]
+#[should_panic(expected = "The key 'force' isn't the name of an argument or group")]
+fn key_without_arg() {
+    App::new("keys")
+        .arg_keys(Key::all())
+        .arg(Arg::with_name(Key::Input.name()))
+        .arg(Arg::with_name(Key::Output.name()).long("output"))
+        .group(ArgGroup::with_name(Key::Files.name()).arg(Key::Input.name()))
+        .get_matches_from(vec!["keys", "in.txt"]);
+}
+
+#[test]
+#[should_panic(expected = "'force' conflicts with 'inptu', which isn't an argument or group")]
+fn unknown_relation() {
+    App::new("keys")
+        .arg_keys(Key::all())
+        .arg(Arg::with_name(Key::Input.name()))
+        .arg(Arg::with_name(Key::Output.name()).long("output"))
+        .arg(Arg::with_name(Key::Force.name()).short("f").conflicts_with("inptu"))
+        .group(ArgGroup::with_name(Key::Files.name()).arg(Key::Input.name()))
+        .get_matches_from(vec!["keys", "in.txt"]);
+}
+
+#[test]
+#[should_panic(expected = "'outptu'")]
+fn unknown_group_arg() {
+    App::new("keys")
+        .arg_keys(Key::all())
+        .arg(Arg::with_name(Key::Input.name()))
+        .arg(Arg::with_name(Key::Output.name()).long("output"))
+        .arg(Arg::with_name(Key::Force.name()).short("f"))
+        .group(ArgGroup::with_name(Key::Files.name()).args(&[Key::Input.name(), "outptu"]))
+        .get_matches_from(vec!["keys", "in.txt"]);
+}
+
+#[test]
+fn global_args_in_subcommands_with_keys() {
+    let m = App::new("keys")
+        .arg(Arg::with_name("verbose").short("v").global(true))
+        .subcommand(SubCommand::with_name("copy")
+                        .arg_keys(Key::all())
+                        .arg(Arg::with_name(Key::Input.name()))
+                        .arg(Arg::with_name(Key::Output.name()).long("output"))
+                        .arg(Arg::with_name(Key::Force.name()).short("f"))
+                        .group(ArgGroup::with_name(Key::Files.name()).arg(Key::Input.name())))
+        .get_matches_from(vec!["keys", "copy", "in.txt", "-v"]);
+    let sub = m.subcommand_matches("copy").unwrap();
+    assert_eq!(sub.value_of(Key::Input), Some("in.txt"));
+    assert!(sub.is_present("verbose"));
+}
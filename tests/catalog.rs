@@ -0,0 +1,173 @@
+extern crate clap;
+extern crate regex;
+
+use clap::{App, Arg, SubCommand, MessageCatalog};
+
+struct German;
+
+impl MessageCatalog for German {
+    fn error_label(&self) -> &str { "Fehler:" }
+    fn usage_heading(&self) -> &str { "VERWENDUNG:" }
+    fn flags_heading(&self) -> &str { "SCHALTER:" }
+    fn options_heading(&self) -> &str { "OPTIONEN:" }
+    fn args_heading(&self) -> &str { "ARGUMENTE:" }
+    fn subcommands_heading(&self) -> &str { "UNTERBEFEHLE:" }
+    fn help_description(&self) -> &'static str { "Zeigt die Hilfe an" }
+    fn version_description(&self) -> &'static str { "Zeigt die Version an" }
+    fn help_subcommand_description(&self) -> &'static str {
+        "Zeigt die Hilfe des Unterbefehls an"
+    }
+    fn default_label(&self) -> &str { "Standard" }
+    fn values_label(&self) -> &str { "Werte" }
+    fn more_info(&self, help: &str) -> String { format!("Weitere Informationen mit {}", help) }
+    fn did_you_mean(&self, suggestion: &str) -> String { format!("Meinten Sie {}?", suggestion) }
+    fn unknown_argument(&self, arg: &str) -> String {
+        format!("Unerwartetes Argument '{}'", arg)
+    }
+    fn wrong_number_of_values(&self, arg: &str, expected: usize, actual: usize) -> String {
+        format!("Das Argument '{}' erwartet {} Werte, aber {} {} angegeben",
+                arg,
+                expected,
+                actual,
+                if actual == 1 { "wurde" } else { "wurden" })
+    }
+}
+
+struct Shouting;
+
+impl MessageCatalog for Shouting {
+    fn error_label(&self) -> &str { "ERROR!" }
+}
+
+fn strip_colors(s: &str) -> String {
+    regex::Regex::new("\x1b[^m]*m").unwrap().replace_all(s, "").into_owned()
+}
+
+fn app() -> App<'static, 'static> {
+    App::new("prog")
+        .version("1.0")
+        .catalog(German)
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .arg(Arg::with_name("mode")
+            .long("mode")
+            .takes_value(true)
+            .possible_values(&["fast", "slow"])
+            .default_value("fast"))
+        .arg(Arg::with_name("point").long("point").number_of_values(2))
+        .arg(Arg::with_name("input"))
+        .subcommand(SubCommand::with_name("sub").arg(Arg::with_name("level").long("level")))
+}
+
+static GERMAN_HELP: &'static str = "prog 1.0
+
+VERWENDUNG:
+    prog [FLAGS] [OPTIONS] [input] [SUBCOMMAND]
+
+SCHALTER:
+    -h, --help       Zeigt die Hilfe an
+    -V, --version    Zeigt die Version an
+        --verbose    
+
+OPTIONEN:
+        --mode <mode>               [Standard: fast]  [Werte: fast, slow]
+        --point <point> <point>    
+
+ARGUMENTE:
+    <input>    
+
+UNTERBEFEHLE:
+    help    Zeigt die Hilfe des Unterbefehls an
+    sub     ";
+
+#[test]
+fn catalog_help() {
+    let err = app().get_matches_from_safe(vec!["prog", "--help"]).unwrap_err();
+    assert_eq!(strip_colors(&err.message()), GERMAN_HELP);
+}
+
+#[test]
+fn catalog_error() {
+    let err = app().get_matches_from_safe(vec!["prog", "--point", "1"]).unwrap_err();
+    let msg = strip_colors(&err.message());
+    assert!(msg.starts_with("Fehler: Das Argument '--point <point> <point>' erwartet 2 Werte, \
+                             aber 1 wurde angegeben"));
+    assert!(msg.contains("VERWENDUNG:\n    prog"));
+    assert!(msg.ends_with("Weitere Informationen mit --help"));
+}
+
+#[test]
+fn catalog_error_plural() {
+    let err = App::new("prog")
+        .catalog(German)
+        .arg(Arg::with_name("point").long("point").number_of_values(3))
+        .get_matches_from_safe(vec!["prog", "--point", "1", "2"])
+        .unwrap_err();
+    assert!(strip_colors(&err.message()).contains("aber 2 wurden angegeben"));
+}
+
+#[test]
+#[cfg(feature = "suggestions")]
+fn catalog_did_you_mean() {
+    let err = app().get_matches_from_safe(vec!["prog", "--mode", "fats"]).unwrap_err();
+    let msg = strip_colors(&err.message());
+    assert!(msg.contains("[Werte: fast, slow]"));
+    assert!(msg.contains("Meinten Sie 'fast'?"));
+
+    let err = app().get_matches_from_safe(vec!["prog", "--verbos"]).unwrap_err();
+    let msg = strip_colors(&err.message());
+    assert!(msg.starts_with("Fehler: Unerwartetes Argument '--verbos'\n\tMeinten Sie --verbose?"));
+}
+
+#[test]
+fn catalog_inherited_by_subcommands() {
+    let err = app().get_matches_from_safe(vec!["prog", "sub", "--nope"]).unwrap_err();
+    let msg = strip_colors(&err.message());
+    assert!(msg.starts_with("Fehler: Unerwartetes Argument '--nope'"));
+
+    let err = app().get_matches_from_safe(vec!["prog", "sub", "--help"]).unwrap_err();
+    let msg = strip_colors(&err.message());
+    assert!(msg.contains("VERWENDUNG:\n    prog sub [FLAGS]"));
+    assert!(msg.contains("-h, --help       Zeigt die Hilfe an"));
+}
+
+#[test]
+fn catalog_overridden_by_subcommand() {
+    let err = App::new("prog")
+        .catalog(German)
+        .subcommand(SubCommand::with_name("sub").catalog(Shouting))
+        .get_matches_from_safe(vec!["prog", "sub", "--nope"])
+        .unwrap_err();
+    let msg = strip_colors(&err.message());
+    assert!(msg.starts_with("ERROR! Found argument '--nope' which wasn't expected"));
+    assert!(msg.contains("USAGE:\n    prog sub"));
+}
+
+#[test]
+fn catalog_defaults_are_english() {
+    let err = App::new("prog")
+        .catalog(Shouting)
+        .arg(Arg::with_name("input").required(true))
+        .get_matches_from_safe(vec!["prog"])
+        .unwrap_err();
+    let msg = strip_colors(&err.message());
+    assert_eq!(msg,
+               "ERROR! The following required arguments were not provided:
+    <input>
+
+USAGE:
+    prog <input>
+
+For more information try --help");
+}
+
+#[test]
+fn catalog_help_message_takes_priority() {
+    let err = App::new("prog")
+        .catalog(German)
+        .help_message("Show help")
+        .get_matches_from_safe(vec!["prog", "--help"])
+        .unwrap_err();
+    let help = err.message();
+    assert!(help.contains("-h, --help       Show help"));
+    assert!(help.contains("-V, --version    Zeigt die Version an"));
+}
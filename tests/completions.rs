@@ -1,7 +1,7 @@
 extern crate regex;
 extern crate clap;
 
-use clap::{App, Arg, SubCommand, Shell};
+use clap::{App, Arg, SubCommand, Shell, ValueHint};
 use regex::Regex;
 
 static BASH: &'static str = r#"_myapp() {
@@ -593,6 +593,123 @@ static BASH_WUS: &'static str = r#"_my_app() {
 complete -F _my_app -o bashdefault -o default my_app
 "#;
 
+static ELVISH: &'static str = r#"
+use builtin;
+use path;
+use str;
+
+set edit:completion:arg-completer['myapp'] = {|@words|
+    fn spaces {|n|
+        builtin:repeat $n ' ' | str:join ''
+    }
+    fn cand {|text desc|
+        edit:complex-candidate $text &display=$text' '(spaces (- 14 (wcswidth $text)))$desc
+    }
+    var command = 'myapp'
+    for word $words[1..-1] {
+        if (str:has-prefix $word '-') {
+            break
+        }
+        set command = $command';'$word
+    }
+    var completions = [
+        &'myapp'= {
+            cand -h 'Prints help information'
+            cand --help 'Prints help information'
+            cand -V 'Prints version information'
+            cand --version 'Prints version information'
+            cand test 'tests things'
+            cand help 'Prints this message or the help of the given subcommand(s)'
+        }
+        &'myapp;test'= {
+            cand --case 'the case to test'
+            cand -h 'Prints help information'
+            cand --help 'Prints help information'
+            cand -V 'Prints version information'
+            cand --version 'Prints version information'
+        }
+        &'myapp;help'= {
+            cand -h 'Prints help information'
+            cand --help 'Prints help information'
+            cand -V 'Prints version information'
+            cand --version 'Prints version information'
+        }
+    ]
+    var values = [&]
+    var prev = $command';'$words[-2]
+    if (has-key $values $prev) {
+        $values[$prev]
+    } else {
+        $completions[$command]
+    }
+}
+"#;
+
+static ELVISH_VALUES: &'static str = r#"
+use builtin;
+use path;
+use str;
+
+set edit:completion:arg-completer['my_app'] = {|@words|
+    fn spaces {|n|
+        builtin:repeat $n ' ' | str:join ''
+    }
+    fn cand {|text desc|
+        edit:complex-candidate $text &display=$text' '(spaces (- 14 (wcswidth $text)))$desc
+    }
+    var command = 'my_app'
+    for word $words[1..-1] {
+        if (str:has-prefix $word '-') {
+            break
+        }
+        set command = $command';'$word
+    }
+    var completions = [
+        &'my_app'= {
+            cand -m 'the mode''s speed'
+            cand --mode 'the mode''s speed'
+            cand -h 'Prints help information'
+            cand --help 'Prints help information'
+            cand -V 'Prints version information'
+            cand --version 'Prints version information'
+            cand test 'tests things'
+            cand some_cmd 'tests other things'
+            cand help 'Prints this message or the help of the given subcommand(s)'
+        }
+        &'my_app;test'= {
+            cand --case 'the case to test'
+            cand -h 'Prints help information'
+            cand --help 'Prints help information'
+            cand -V 'Prints version information'
+            cand --version 'Prints version information'
+        }
+        &'my_app;some_cmd'= {
+            cand --config 'the other case to test'
+            cand -h 'Prints help information'
+            cand --help 'Prints help information'
+            cand -V 'Prints version information'
+            cand --version 'Prints version information'
+        }
+        &'my_app;help'= {
+            cand -h 'Prints help information'
+            cand --help 'Prints help information'
+            cand -V 'Prints version information'
+            cand --version 'Prints version information'
+        }
+    ]
+    var values = [
+        &'my_app;-m'= { put 'fast' 'slow' }
+        &'my_app;--mode'= { put 'fast' 'slow' }
+    ]
+    var prev = $command';'$words[-2]
+    if (has-key $values $prev) {
+        $values[$prev]
+    } else {
+        $completions[$command]
+    }
+}
+"#;
+
 fn compare(left: &str, right: &str) -> bool {
     let b = left == right;
     if !b {
@@ -623,6 +740,15 @@ fn build_app_with_name(s: &'static str) -> App<'static, 'static> {
                 .help("the case to test")))
 }
 
+fn build_app_with_possible_values() -> App<'static, 'static> {
+    build_app_with_underscore().arg(Arg::with_name("mode")
+        .short("m")
+        .long("mode")
+        .takes_value(true)
+        .possible_values(&["fast", "slow"])
+        .help("the mode's speed"))
+}
+
 fn build_app_with_underscore() -> App<'static, 'static> {
     build_app_with_name("my_app").subcommand(SubCommand::with_name("some_cmd")
         .about("tests other things")
@@ -713,3 +839,146 @@ fn zsh_with_underscore() {
 
     assert!(compare(&*string, ZSH_WUS));
 }
+
+
+#[test]
+fn elvish() {
+    let mut app = build_app();
+    let mut buf = vec![];
+    app.gen_completions_to("myapp", Shell::Elvish, &mut buf);
+    let string = String::from_utf8(buf).unwrap();
+
+    assert!(compare(&*string, ELVISH));
+}
+
+#[test]
+fn elvish_with_possible_values() {
+    let mut app = build_app_with_possible_values();
+    let mut buf = vec![];
+    app.gen_completions_to("my_app", Shell::Elvish, &mut buf);
+    let string = String::from_utf8(buf).unwrap();
+
+    assert!(compare(&*string, ELVISH_VALUES));
+}
+
+fn build_app_with_value_hints() -> App<'static, 'static> {
+    App::new("myapp")
+        .arg(Arg::with_name("out")
+            .long("out-dir")
+            .value_hint(ValueHint::DirPath)
+            .help("the output directory"))
+        .arg(Arg::with_name("input")
+            .short("i")
+            .value_hint(ValueHint::FilePath)
+            .help("the input file"))
+        .arg(Arg::with_name("host")
+            .long("host")
+            .value_hint(ValueHint::Hostname)
+            .help("the host to connect to"))
+        .arg(Arg::with_name("exec")
+            .long("exec")
+            .value_hint(ValueHint::CommandName)
+            .help("the command to run"))
+        .arg(Arg::with_name("format")
+            .long("format")
+            .value_hint(ValueHint::FilePath)
+            .possible_values(&["json", "yaml"])
+            .help("the output format"))
+}
+
+fn gen_value_hints(shell: Shell) -> String {
+    let mut buf = vec![];
+    build_app_with_value_hints().gen_completions_to("myapp", shell, &mut buf);
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn bash_value_hints() {
+    let string = gen_value_hints(Shell::Bash);
+
+    assert!(string.contains("--out-dir)\n                    COMPREPLY=($(compgen -d -- ${cur}))"));
+    assert!(string.contains("-i)\n                    COMPREPLY=($(compgen -f -- ${cur}))"));
+    assert!(string.contains("--host)\n                    COMPREPLY=($(compgen -A hostname -- ${cur}))"));
+    assert!(string.contains("--exec)\n                    COMPREPLY=($(compgen -c -- ${cur}))"));
+    assert!(string.contains("--format)\n                    COMPREPLY=($(compgen -W \"json yaml\" -- ${cur}))"));
+}
+
+#[test]
+fn zsh_value_hints() {
+    let string = gen_value_hints(Shell::Zsh);
+
+    assert!(string.contains("\"--out-dir+[the output directory]: :_files -/\""));
+    assert!(string.contains("\"-i+[the input file]: :_files\""));
+    assert!(string.contains("\"--host+[the host to connect to]: :_hosts\""));
+    assert!(string.contains("\"--exec+[the command to run]: :_command_names -e\""));
+    assert!(string.contains("\"--format+[the output format]: :(json yaml)\""));
+}
+
+#[test]
+fn fish_value_hints() {
+    let string = gen_value_hints(Shell::Fish);
+
+    assert!(string.contains("-l out-dir -d \"the output directory\" -r -f -a \"(__fish_complete_directories)\""));
+    assert!(string.contains("-s i -d \"the input file\" -r -F"));
+    assert!(string.contains("-l host -d \"the host to connect to\" -r -f -a \"(__fish_print_hostnames)\""));
+    assert!(string.contains("-l exec -d \"the command to run\" -r -f -a \"(__fish_complete_command)\""));
+    assert!(string.contains("-l format -d \"the output format\" -r -f -a \"json yaml\""));
+}
+
+#[test]
+fn elvish_value_hints() {
+    let string = gen_value_hints(Shell::Elvish);
+
+    assert!(string.contains("&'myapp;--out-dir'= { edit:complete-filename $words[-1] | each {|c| if (path:is-dir $c[stem]) { put $c } } }"));
+    assert!(string.contains("&'myapp;-i'= { edit:complete-filename $words[-1] }"));
+    assert!(string.contains("&'myapp;--format'= { put 'json' 'yaml' }"));
+    assert!(!string.contains("&'myapp;--host'="));
+}
+
+fn gen_positional_value_hints(shell: Shell) -> String {
+    let mut buf = vec![];
+    App::new("myapp")
+        .arg(Arg::with_name("input")
+            .value_hint(ValueHint::FilePath)
+            .help("the input file"))
+        .arg(Arg::with_name("cmd")
+            .multiple(true)
+            .last(true)
+            .value_hint(ValueHint::CommandWithArguments)
+            .help("the command to run"))
+        .gen_completions_to("myapp", shell, &mut buf);
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn bash_positional_value_hints() {
+    let string = gen_positional_value_hints(Shell::Bash);
+
+    assert!(string.contains("COMPREPLY=( $(compgen -W \"${opts}\" -- ${cur}) \
+                             $(compgen -f -- ${cur}) $(compgen -c -- ${cur}) )"));
+}
+
+#[test]
+fn zsh_positional_value_hints() {
+    let string = gen_positional_value_hints(Shell::Zsh);
+
+    assert!(string.contains("\"1:input:_files\" \\\n\"*::cmd:_cmdambivalent\" \\"));
+    assert!(!string.contains("\"1:: :_myapp_commands\""));
+}
+
+#[test]
+fn fish_positional_value_hints() {
+    let string = gen_positional_value_hints(Shell::Fish);
+
+    assert!(string.contains("complete -c myapp -n \"__fish_using_command myapp\" \
+                             -d \"the input file\" -F\n"));
+    assert!(string.contains("complete -c myapp -n \"__fish_using_command myapp\" \
+                             -d \"the command to run\" -f -a \"(__fish_complete_command)\"\n"));
+}
+
+#[test]
+fn elvish_positional_value_hints() {
+    let string = gen_positional_value_hints(Shell::Elvish);
+
+    assert!(string.contains("\n            { edit:complete-filename $words[-1] }"));
+}
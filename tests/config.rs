@@ -0,0 +1,281 @@
+extern crate clap;
+
+use std::env;
+use std::fs::File;
+use std::io::Write;
+
+use clap::{App, Arg, ArgGroup, ConfigSource, ErrorKind, SubCommand, ValueSource};
+
+fn build_app() -> App<'static, 'static> {
+    App::new("tool")
+        .arg(Arg::with_name("jobs").long("jobs").takes_value(true).default_value("1"))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .possible_values(&["always", "auto", "never"]))
+        .arg(Arg::with_name("verbose").short("v").multiple(true))
+        .arg(Arg::with_name("include").long("include").takes_value(true).multiple(true))
+        .arg(Arg::with_name("input"))
+        .subcommand(SubCommand::with_name("build")
+            .arg(Arg::with_name("release").long("release"))
+            .arg(Arg::with_name("target").long("target").takes_value(true)))
+}
+
+#[test]
+fn config_below_cli() {
+    let m = build_app()
+        .config(ConfigSource::new("cfg").value("jobs", "4").value("color", "never"))
+        .get_matches_from(vec!["tool", "--jobs", "8"]);
+    assert_eq!(m.value_of("jobs"), Some("8"));
+    assert_eq!(m.config_origin("jobs"), None);
+    assert_eq!(m.value_of("color"), Some("never"));
+    assert_eq!(m.config_origin("color"), Some("cfg"));
+}
+
+#[test]
+fn config_above_defaults() {
+    let m = build_app()
+        .config(ConfigSource::new("cfg").value("jobs", "4"))
+        .get_matches_from(vec!["tool"]);
+    assert_eq!(m.value_of("jobs"), Some("4"));
+    assert_eq!(m.config_origin("jobs"), Some("cfg"));
+}
+
+#[test]
+fn config_later_sources_win() {
+    let m = build_app()
+        .config(ConfigSource::new("system").value("jobs", "2").value("color", "auto"))
+        .config(ConfigSource::new("user").value("jobs", "4"))
+        .get_matches_from(vec!["tool"]);
+    assert_eq!(m.value_of("jobs"), Some("4"));
+    assert_eq!(m.config_origin("jobs"), Some("user"));
+    assert_eq!(m.value_of("color"), Some("auto"));
+    assert_eq!(m.config_origin("color"), Some("system"));
+}
+
+#[test]
+fn config_below_env() {
+    env::set_var("CLP_TEST_CONFIG_ENV", "env");
+    let m = App::new("tool")
+        .arg(Arg::with_name("name").long("name").takes_value(true).env("CLP_TEST_CONFIG_ENV"))
+        .config(ConfigSource::new("cfg").value("name", "cfg"))
+        .get_matches_from(vec!["tool"]);
+    assert_eq!(m.value_of("name"), Some("env"));
+    assert_eq!(m.config_origin("name"), None);
+}
+
+#[test]
+fn config_flags() {
+    let m = build_app()
+        .config(ConfigSource::new("cfg").value("verbose", "3"))
+        .get_matches_from(vec!["tool"]);
+    assert_eq!(m.occurrences_of("verbose"), 3);
+    assert_eq!(m.config_origin("verbose"), Some("cfg"));
+
+    let m = build_app()
+        .config(ConfigSource::new("cfg").value("verbose", "false"))
+        .get_matches_from(vec!["tool"]);
+    assert!(!m.is_present("verbose"));
+
+    let m = build_app()
+        .config(ConfigSource::new("cfg").value("verbose", "3"))
+        .get_matches_from(vec!["tool", "-v"]);
+    assert_eq!(m.occurrences_of("verbose"), 1);
+}
+
+#[test]
+fn config_flags_have_no_index() {
+    let m = App::new("tool")
+        .arg(Arg::with_name("v").short("v"))
+        .arg(Arg::with_name("q").short("q"))
+        .config(ConfigSource::new("cfg").value("q", "true"))
+        .get_matches_from(vec!["tool", "-v"]);
+    assert_eq!(m.index_of("v"), Some(1));
+    assert!(m.is_present("q"));
+    assert_eq!(m.index_of("q"), None);
+}
+
+#[test]
+fn config_flag_invalid() {
+    let cfg = ConfigSource::new("cfg")
+        .subcommand("build", ConfigSource::new("").value("release", "2"));
+    let res = build_app().config(cfg).get_matches_from_safe(vec!["tool", "build"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn config_multiple_values() {
+    let m = build_app()
+        .config(ConfigSource::new("cfg").value("include", "src").value("include", "tests"))
+        .get_matches_from(vec!["tool"]);
+    assert_eq!(m.values_of("include").unwrap().collect::<Vec<_>>(), ["src", "tests"]);
+}
+
+#[test]
+fn config_positional() {
+    let m = build_app()
+        .config(ConfigSource::new("cfg").value("input", "file.txt"))
+        .get_matches_from(vec!["tool"]);
+    assert_eq!(m.value_of("input"), Some("file.txt"));
+}
+
+#[test]
+fn config_values_are_validated() {
+    let res = build_app()
+        .config(ConfigSource::new("cfg").value("color", "sometimes"))
+        .get_matches_from_safe(vec!["tool"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn config_validator() {
+    let res = App::new("tool")
+        .arg(Arg::with_name("jobs")
+            .long("jobs")
+            .takes_value(true)
+            .validator(|v| v.parse::<u32>().map(|_| ()).map_err(|e| e.to_string())))
+        .config(ConfigSource::new("cfg").value("jobs", "many"))
+        .get_matches_from_safe(vec!["tool"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::ValueValidation);
+}
+
+#[test]
+fn config_conflicts_checked_after_merge() {
+    let res = App::new("tool")
+        .arg(Arg::with_name("quiet").long("quiet").conflicts_with("loud"))
+        .arg(Arg::with_name("loud").long("loud"))
+        .config(ConfigSource::new("cfg").value("loud", "true"))
+        .get_matches_from_safe(vec!["tool", "--quiet"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::ArgumentConflict);
+}
+
+#[test]
+fn config_satisfies_requirements() {
+    let m = App::new("tool")
+        .arg(Arg::with_name("user").long("user").takes_value(true).required(true))
+        .config(ConfigSource::new("cfg").value("user", "me"))
+        .get_matches_from_safe(vec!["tool"]);
+    assert!(m.is_ok());
+    assert_eq!(m.unwrap().value_of("user"), Some("me"));
+}
+
+#[test]
+fn config_requirements_checked_after_merge() {
+    let res = App::new("tool")
+        .arg(Arg::with_name("user").long("user").takes_value(true).requires("password"))
+        .arg(Arg::with_name("password").long("password").takes_value(true))
+        .config(ConfigSource::new("cfg").value("user", "me"))
+        .get_matches_from_safe(vec!["tool"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn config_groups() {
+    let m = App::new("tool")
+        .arg(Arg::with_name("major").long("major"))
+        .arg(Arg::with_name("minor").long("minor"))
+        .group(ArgGroup::with_name("vers").args(&["major", "minor"]).required(true))
+        .config(ConfigSource::new("cfg").value("minor", "true"))
+        .get_matches_from_safe(vec!["tool"]);
+    assert!(m.is_ok());
+    assert!(m.unwrap().is_present("vers"));
+}
+
+#[test]
+fn config_unknown_keys_are_ignored() {
+    let m = build_app()
+        .config(ConfigSource::new("cfg").value("unknown", "1"))
+        .get_matches_from_safe(vec!["tool"]);
+    assert!(m.is_ok());
+}
+
+#[test]
+fn config_subcommand() {
+    let cfg = ConfigSource::from_ini("tool.conf", "jobs = 3\n[build]\nrelease\ntarget = x86").unwrap();
+    let m = build_app()
+        .config(cfg)
+        .get_matches_from(vec!["tool", "build", "--target", "arm"]);
+    assert_eq!(m.value_of("jobs"), Some("3"));
+    let sub_m = m.subcommand_matches("build").unwrap();
+    assert!(sub_m.is_present("release"));
+    assert_eq!(sub_m.config_origin("release"), Some("tool.conf"));
+    assert_eq!(sub_m.value_of("target"), Some("arm"));
+    assert_eq!(sub_m.config_origin("target"), None);
+}
+
+#[test]
+fn config_global_reaches_subcommand() {
+    let app = || {
+        App::new("tool")
+            .arg(Arg::with_name("e").long("e").takes_value(true).global(true))
+            .subcommand(SubCommand::with_name("s"))
+            .config(ConfigSource::new("cfg").value("e", "fromcfg"))
+    };
+    let m = app().get_matches_from(vec!["tool", "s"]);
+    let sub_m = m.subcommand_matches("s").unwrap();
+    assert_eq!(sub_m.value_of("e"), Some("fromcfg"));
+    assert_eq!(sub_m.value_source("e"), Some(ValueSource::Config));
+    assert_eq!(sub_m.config_origin("e"), Some("cfg"));
+
+    let m = app().get_matches_from(vec!["tool", "s", "--e", "cli"]);
+    let sub_m = m.subcommand_matches("s").unwrap();
+    assert_eq!(sub_m.value_of("e"), Some("cli"));
+    assert_eq!(sub_m.config_origin("e"), None);
+}
+
+#[test]
+fn config_ini_last_key_wins() {
+    let cfg = ConfigSource::from_ini("tool.conf", "color = auto\ncolor = never").unwrap();
+    assert_eq!(cfg.get("color"), Some(&["never".to_owned()][..]));
+    let m = build_app().config(cfg).get_matches_from(vec!["tool"]);
+    assert_eq!(m.value_of("color"), Some("never"));
+}
+
+#[test]
+fn config_from_file() {
+    let path = env::temp_dir().join("clap_config_from_file.conf");
+    File::create(&path).unwrap().write_all(b"color = \"always\"\n").unwrap();
+    let cfg = ConfigSource::from_file(&path).unwrap().unwrap();
+    let m = build_app().config(cfg).get_matches_from(vec!["tool"]);
+    assert_eq!(m.value_of("color"), Some("always"));
+    assert_eq!(m.config_origin("color"), Some(&*path.display().to_string()));
+}
+
+#[test]
+fn config_from_missing_file() {
+    let path = env::temp_dir().join("clap_config_does_not_exist.conf");
+    assert!(ConfigSource::from_file(&path).unwrap().is_none());
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn config_from_yaml() {
+    use clap::YamlLoader;
+
+    let docs = YamlLoader::load_from_str("jobs: 5\ninclude: [a, b]\nbuild:\n  release: true").unwrap();
+    let cfg = ConfigSource::from_yaml("tool.yml", &docs[0]).unwrap();
+    let m = build_app().config(cfg).get_matches_from(vec!["tool", "build"]);
+    assert_eq!(m.value_of("jobs"), Some("5"));
+    assert_eq!(m.values_of("include").unwrap().collect::<Vec<_>>(), ["a", "b"]);
+    assert!(m.subcommand_matches("build").unwrap().is_present("release"));
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn config_from_toml() {
+    let cfg = ConfigSource::from_toml("tool.toml",
+                                      "jobs = 5\ninclude = [\"a\", \"b\"]\n[build]\nrelease = true")
+        .unwrap();
+    let m = build_app().config(cfg).get_matches_from(vec!["tool", "build"]);
+    assert_eq!(m.value_of("jobs"), Some("5"));
+    assert_eq!(m.values_of("include").unwrap().collect::<Vec<_>>(), ["a", "b"]);
+    assert!(m.subcommand_matches("build").unwrap().is_present("release"));
+
+    let res = ConfigSource::from_toml("tool.toml", "jobs = ");
+    assert_eq!(res.unwrap_err().kind, ErrorKind::InvalidConfig);
+}
@@ -0,0 +1,146 @@
+extern crate clap;
+
+use std::env;
+
+use clap::{App, AppSettings, Arg, ErrorKind, Shell, SubCommand};
+
+fn branches(_: &str) -> Vec<String> {
+    vec!["master".into(), "develop".into(), "dev-docs".into()]
+}
+
+fn build_app() -> App<'static, 'static> {
+    App::new("myapp")
+        .setting(AppSettings::DynamicCompletion)
+        .arg(Arg::with_name("verbose").short("v").long("verbose").multiple(true))
+        .arg(Arg::with_name("quiet").short("q").long("quiet"))
+        .arg(Arg::with_name("secret").long("secret").hidden(true))
+        .arg(Arg::with_name("mode")
+            .long("mode")
+            .takes_value(true)
+            .possible_values(&["fast", "slow"]))
+        .arg(Arg::with_name("branch")
+            .short("b")
+            .long("branch")
+            .takes_value(true)
+            .completer(branches))
+        .subcommand(SubCommand::with_name("checkout")
+            .visible_alias("co")
+            .arg(Arg::with_name("force").short("f").long("force"))
+            .arg(Arg::with_name("target").completer(branches)))
+        .subcommand(SubCommand::with_name("internal").setting(AppSettings::Hidden))
+}
+
+#[test]
+fn dynamic_long_switches() {
+    let c = build_app().get_dynamic_completions_from(vec!["myapp", "--"], 1);
+    assert_eq!(c, &["--verbose", "--quiet", "--help", "--version", "--mode", "--branch"]);
+}
+
+#[test]
+fn dynamic_switches_prefix() {
+    let c = build_app().get_dynamic_completions_from(vec!["myapp", "--ve"], 1);
+    assert_eq!(c, &["--verbose", "--version"]);
+}
+
+#[test]
+fn dynamic_used_switches_are_skipped() {
+    let c = build_app().get_dynamic_completions_from(vec!["myapp", "-q", "-v", "--"], 3);
+    assert_eq!(c, &["--verbose", "--help", "--version", "--mode", "--branch"]);
+}
+
+#[test]
+fn dynamic_subcommands() {
+    let c = build_app().get_dynamic_completions_from(vec!["myapp", ""], 1);
+    assert_eq!(c, &["checkout", "co", "help"]);
+}
+
+#[test]
+fn dynamic_index_past_end() {
+    let c = build_app().get_dynamic_completions_from(vec!["myapp", "-v"], 2);
+    assert_eq!(c, &["checkout", "co", "help"]);
+}
+
+#[test]
+fn dynamic_possible_values() {
+    let c = build_app().get_dynamic_completions_from(vec!["myapp", "--mode", ""], 2);
+    assert_eq!(c, &["fast", "slow"]);
+}
+
+#[test]
+fn dynamic_opt_completer() {
+    let c = build_app().get_dynamic_completions_from(vec!["myapp", "-b", "dev"], 2);
+    assert_eq!(c, &["develop", "dev-docs"]);
+}
+
+#[test]
+fn dynamic_opt_completer_equals() {
+    let c = build_app().get_dynamic_completions_from(vec!["myapp", "--branch=m"], 1);
+    assert_eq!(c, &["--branch=master"]);
+}
+
+#[test]
+fn dynamic_subcommand_context() {
+    let c = build_app().get_dynamic_completions_from(vec!["myapp", "-v", "checkout", "--f"], 3);
+    assert_eq!(c, &["--force"]);
+}
+
+#[test]
+fn dynamic_subcommand_alias_context() {
+    let c = build_app().get_dynamic_completions_from(vec!["myapp", "co", "-f", "ma"], 3);
+    assert_eq!(c, &["master"]);
+}
+
+#[test]
+fn dynamic_ignores_words_after_index() {
+    let c = build_app().get_dynamic_completions_from(vec!["myapp", "checkout", "d", "--bad"], 2);
+    assert_eq!(c, &["develop", "dev-docs"]);
+}
+
+#[test]
+fn dynamic_parse_error() {
+    let c = build_app().get_dynamic_completions_from(vec!["myapp", "--unknown", ""], 2);
+    assert!(c.is_empty());
+}
+
+#[test]
+fn dynamic_env_protocol() {
+    env::set_var("_CLAP_COMPLETE_INDEX", "2");
+    let res = build_app().get_matches_from_safe(vec!["myapp", "--mode", "s"]);
+    env::remove_var("_CLAP_COMPLETE_INDEX");
+
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::CompletionsDisplayed);
+    assert_eq!(err.info, Some(vec!["slow".to_owned()]));
+    assert_eq!(err.message(), "slow");
+}
+
+#[test]
+fn dynamic_bash_shim() {
+    let mut buf = vec![];
+    build_app().gen_dynamic_completions_to("myapp", Shell::Bash, &mut buf);
+    let script = String::from_utf8(buf).unwrap();
+
+    assert!(script.contains("COMPREPLY=( $(_CLAP_COMPLETE_INDEX=${COMP_CWORD} \"${COMP_WORDS[@]}\" 2>/dev/null) )"));
+    assert!(script.contains("complete -F _myapp -o bashdefault -o default myapp"));
+}
+
+#[test]
+fn dynamic_zsh_shim() {
+    let mut buf = vec![];
+    build_app().gen_dynamic_completions_to("myapp", Shell::Zsh, &mut buf);
+    let script = String::from_utf8(buf).unwrap();
+
+    assert!(script.starts_with("#compdef myapp\n"));
+    assert!(script.contains("_CLAP_COMPLETE_INDEX=$((CURRENT - 1)) \"${words[@]}\""));
+}
+
+#[test]
+fn dynamic_fish_shim() {
+    let mut buf = vec![];
+    build_app().gen_dynamic_completions_to("myapp", Shell::Fish, &mut buf);
+    let script = String::from_utf8(buf).unwrap();
+
+    assert!(script.contains("env _CLAP_COMPLETE_INDEX=(count $tokens) $tokens (commandline -ct)"));
+    assert!(script.contains("complete -c myapp -f -a \"(__myapp_complete)\""));
+}
@@ -0,0 +1,303 @@
+extern crate clap;
+extern crate regex;
+
+include!("../clap-test.rs");
+
+use std::env;
+use std::ffi::OsStr;
+
+use clap::{App, Arg, ErrorKind};
+
+#[test]
+fn env() {
+    env::set_var("CLP_TEST_ENV", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'").env("CLP_TEST_ENV"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn env_os() {
+    env::set_var("CLP_TEST_ENV_OS", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'").env_os(OsStr::new("CLP_TEST_ENV_OS")))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn no_env() {
+    // All the other tests use the presence of the Environment variable...
+    // we need another variable just in case one of the others is running at the same time...
+    env::remove_var("CLP_TEST_ENV_NONE");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'").env("CLP_TEST_ENV_NONE"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(!m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg"), None);
+}
+
+#[test]
+fn with_default() {
+    env::set_var("CLP_TEST_ENV_DEFAULT", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_DEFAULT")
+            .default_value("default"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn default_when_no_env() {
+    env::remove_var("CLP_TEST_ENV_DEFAULT_NONE");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_DEFAULT_NONE")
+            .default_value("default"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.value_of("arg").unwrap(), "default");
+}
+
+#[test]
+fn with_default_if() {
+    env::set_var("CLP_TEST_ENV_DEFAULT_IF", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("--flag 'some flag'"))
+        .arg(Arg::from_usage("--opt [FILE] 'some opt'")
+            .env("CLP_TEST_ENV_DEFAULT_IF")
+            .default_value_if("flag", None, "default"))
+        .get_matches_from_safe(vec!["", "--flag"]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("opt").unwrap(), "env");
+}
+
+#[test]
+fn opt_user_override() {
+    env::set_var("CLP_TEST_ENV_OR", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("--arg [FILE] 'some arg'").env("CLP_TEST_ENV_OR"))
+        .get_matches_from_safe(vec!["", "--arg", "opt"]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 1);
+    assert_eq!(m.value_of("arg").unwrap(), "opt");
+}
+
+#[test]
+fn positionals() {
+    env::set_var("CLP_TEST_ENV_P", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'").env("CLP_TEST_ENV_P"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn positionals_user_override() {
+    env::set_var("CLP_TEST_ENV_POR", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'").env("CLP_TEST_ENV_POR"))
+        .get_matches_from_safe(vec!["", "opt"]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 1);
+    assert_eq!(m.value_of("arg").unwrap(), "opt");
+}
+
+#[test]
+fn multiple_one() {
+    env::set_var("CLP_TEST_ENV_MO", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_MO")
+            .use_delimiter(true)
+            .multiple(true))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.values_of("arg").unwrap().collect::<Vec<_>>(), vec!["env"]);
+}
+
+#[test]
+fn multiple_three() {
+    env::set_var("CLP_TEST_ENV_MULTI1", "env1,env2,env3");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_MULTI1")
+            .use_delimiter(true)
+            .multiple(true))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.values_of("arg").unwrap().collect::<Vec<_>>(),
+               vec!["env1", "env2", "env3"]);
+}
+
+#[test]
+fn multiple_no_delimiter() {
+    env::set_var("CLP_TEST_ENV_MULTI2", "env1 env2 env3");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_MULTI2")
+            .multiple(true))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.values_of("arg").unwrap().collect::<Vec<_>>(),
+               vec!["env1 env2 env3"]);
+}
+
+#[test]
+fn possible_value() {
+    env::set_var("CLP_TEST_ENV_PV", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_PV")
+            .possible_value("env"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn not_possible_value() {
+    env::set_var("CLP_TEST_ENV_NPV", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_NPV")
+            .possible_value("never"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn validator() {
+    env::set_var("CLP_TEST_ENV_VDOR", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_VDOR")
+            .validator(|s| if s == "env" {
+                Ok(())
+            } else {
+                Err("not equal".to_string())
+            }))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn validator_invalid() {
+    env::set_var("CLP_TEST_ENV_IV", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_IV")
+            .validator(|s| if s != "env" {
+                Ok(())
+            } else {
+                Err("is equal".to_string())
+            }))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::ValueValidation);
+}
+
+static ENV_HELP: &'static str = "df 1.0
+
+USAGE:
+    df [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+        --password <password>    the password [env: CLP_TEST_ENV_SECRET]
+        --user <user>            the user name [env: CLP_TEST_ENV_HELP=kevin]";
+
+#[test]
+fn env_in_help() {
+    env::set_var("CLP_TEST_ENV_HELP", "kevin");
+    env::set_var("CLP_TEST_ENV_SECRET", "hunter2");
+
+    let app = App::new("df")
+        .version("1.0")
+        .arg(Arg::from_usage("--user [user] 'the user name'").env("CLP_TEST_ENV_HELP"))
+        .arg(Arg::from_usage("--password [password] 'the password'")
+            .env("CLP_TEST_ENV_SECRET")
+            .hide_env_values(true));
+
+    assert!(test::compare_output(app, "df --help", ENV_HELP, false));
+}
@@ -0,0 +1,182 @@
+extern crate clap;
+
+use std::error::Error as StdError;
+
+use clap::{App, Arg, SubCommand, Error, ErrorKind, ContextKind, ContextValue};
+
+fn s(v: &str) -> Option<ContextValue> { Some(ContextValue::String(v.to_owned())) }
+
+#[test]
+fn context_invalid_value() {
+    let err = App::new("prog")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .possible_values(&["never", "always", "auto"]))
+        .get_matches_from_safe(vec!["prog", "--color", "sometimes"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidValue);
+    assert_eq!(err.get(ContextKind::InvalidArg).cloned(), s("--color <color>"));
+    assert_eq!(err.get(ContextKind::InvalidValue).cloned(), s("sometimes"));
+    assert_eq!(err.get(ContextKind::ValidValues).cloned(),
+               Some(ContextValue::Strings(vec!["never".into(), "always".into(), "auto".into()])));
+    assert_eq!(err.get(ContextKind::SuggestedValue), None);
+}
+
+#[test]
+#[cfg(feature = "suggestions")]
+fn context_suggested_value() {
+    let err = App::new("prog")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .possible_values(&["never", "always", "auto"]))
+        .get_matches_from_safe(vec!["prog", "--color", "alwys"])
+        .unwrap_err();
+    assert_eq!(err.get(ContextKind::SuggestedValue).cloned(), s("always"));
+}
+
+#[test]
+#[cfg(feature = "suggestions")]
+fn context_suggested_arg() {
+    let err = App::new("prog")
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .get_matches_from_safe(vec!["prog", "--verbos"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    assert_eq!(err.get(ContextKind::InvalidArg).cloned(), s("--verbos"));
+    assert_eq!(err.get(ContextKind::SuggestedArg).cloned(), s("verbose"));
+    assert_eq!(err.get(ContextKind::SuggestedSubcommand), None);
+}
+
+#[test]
+#[cfg(feature = "suggestions")]
+fn context_suggested_arg_in_subcommand() {
+    let err = App::new("prog")
+        .subcommand(SubCommand::with_name("build").arg(Arg::with_name("release").long("release")))
+        .get_matches_from_safe(vec!["prog", "--releas"])
+        .unwrap_err();
+    assert_eq!(err.get(ContextKind::SuggestedArg).cloned(), s("release"));
+    assert_eq!(err.get(ContextKind::SuggestedSubcommand).cloned(), s("build"));
+    assert!(err.message().contains("after the subcommand 'build'"));
+}
+
+#[test]
+fn context_unknown_argument_without_suggestion() {
+    let err = App::new("prog")
+        .get_matches_from_safe(vec!["prog", "--zzz"])
+        .unwrap_err();
+    assert_eq!(err.get(ContextKind::InvalidArg).cloned(), s("--zzz"));
+    assert_eq!(err.get(ContextKind::SuggestedArg), None);
+    assert!(err.get(ContextKind::Usage).is_some());
+}
+
+#[test]
+fn context_conflict() {
+    let err = App::new("prog")
+        .arg(Arg::with_name("a").long("aaa").conflicts_with("b"))
+        .arg(Arg::with_name("b").long("bbb"))
+        .get_matches_from_safe(vec!["prog", "--aaa", "--bbb"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ArgumentConflict);
+    assert!(err.get(ContextKind::InvalidArg).is_some());
+    assert!(err.get(ContextKind::PriorArg).is_some());
+}
+
+#[test]
+fn context_missing_required() {
+    let err = App::new("prog")
+        .arg(Arg::with_name("input").required(true))
+        .arg(Arg::with_name("output").long("output").takes_value(true).required(true))
+        .get_matches_from_safe(vec!["prog"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    match err.get(ContextKind::MissingArgs) {
+        Some(&ContextValue::Strings(ref v)) => {
+            assert_eq!(v.len(), 2);
+            assert!(v.contains(&"<input>".to_owned()));
+            assert!(v.contains(&"--output <output>".to_owned()));
+        }
+        other => panic!("unexpected context {:?}", other),
+    }
+}
+
+#[test]
+fn context_wrong_number_of_values() {
+    let err = App::new("prog")
+        .arg(Arg::with_name("pos").long("pos").takes_value(true).number_of_values(3))
+        .get_matches_from_safe(vec!["prog", "--pos", "1", "2"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::WrongNumberOfValues);
+    assert_eq!(err.get(ContextKind::ExpectedNumValues), Some(&ContextValue::Number(3)));
+    assert_eq!(err.get(ContextKind::ActualNumValues), Some(&ContextValue::Number(2)));
+}
+
+#[test]
+fn context_too_few_values() {
+    let err = App::new("prog")
+        .arg(Arg::with_name("vals").long("vals").takes_value(true).min_values(3))
+        .get_matches_from_safe(vec!["prog", "--vals", "1"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::TooFewValues);
+    assert_eq!(err.get(ContextKind::MinValues), Some(&ContextValue::Number(3)));
+    assert_eq!(err.get(ContextKind::ActualNumValues), Some(&ContextValue::Number(1)));
+}
+
+#[test]
+fn context_validator() {
+    let err = App::new("prog")
+        .arg(Arg::with_name("num")
+            .long("num")
+            .takes_value(true)
+            .validator(|v| v.parse::<u8>().map(|_| ()).map_err(|e| e.to_string())))
+        .get_matches_from_safe(vec!["prog", "--num", "300"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert_eq!(err.get(ContextKind::InvalidValue).cloned(), s("300"));
+    assert_eq!(err.get(ContextKind::Custom).cloned(),
+               s("number too large to fit in target type"));
+}
+
+#[test]
+fn context_help_has_none() {
+    let err = App::new("prog")
+        .get_matches_from_safe(vec!["prog", "--help"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::HelpDisplayed);
+    assert_eq!(err.context().count(), 0);
+    assert!(err.message().contains("USAGE:"));
+}
+
+#[test]
+fn context_value_display() {
+    assert_eq!(ContextValue::String("a".into()).to_string(), "a");
+    assert_eq!(ContextValue::Strings(vec!["a".into(), "b".into()]).to_string(), "a, b");
+    assert_eq!(ContextValue::Number(2).to_string(), "2");
+}
+
+#[test]
+fn message_field_and_description() {
+    let mut err = App::new("prog")
+        .arg(Arg::with_name("input").required(true))
+        .get_matches_from_safe(vec!["prog", "--zzz"])
+        .unwrap_err();
+    assert!(err.message().starts_with("error: Found argument '--zzz'"));
+    assert_eq!(err.message, err.message());
+    assert_eq!(err.description(), err.message());
+
+    err.message = String::from("error: custom");
+    assert_eq!(err.message(), "error: custom");
+    assert_eq!(err.to_string(), "error: custom\n");
+}
+
+#[test]
+fn struct_literal() {
+    let err = Error {
+        message: String::new(),
+        kind: ErrorKind::ArgumentNotFound,
+        info: None,
+        context: vec![(ContextKind::InvalidArg, ContextValue::String("input".into()))],
+    };
+    assert_eq!(err.message(), "error: The argument 'input' wasn't found");
+}
@@ -3,7 +3,7 @@ extern crate regex;
 
 include!("../clap-test.rs");
 
-use clap::{App, Arg, ArgGroup, ErrorKind};
+use clap::{App, Arg, ArgGroup, ContextKind, ContextValue, ErrorKind};
 
 static REQ_GROUP_USAGE: &'static str = "error: The following required arguments were not provided:
     <base|--delete>
@@ -191,3 +191,114 @@ fn group_multiple_args_error() {
     let err = result.unwrap_err();
     assert_eq!(err.kind, ErrorKind::ArgumentConflict);
 }
+
+static GROUP_MIN_ARGS: &'static str = "error: At least 2 of '--red', '--green', '--blue' must be used, but only '--red' was provided
+
+USAGE:
+    clap-test <--red|--green|--blue>{2,}
+
+For more information try --help";
+
+static GROUP_MAX_ARGS: &'static str = "error: At most 1 of '--json', '--yaml' can be used, but '--json', '--yaml' were provided
+
+USAGE:
+    clap-test <--json|--yaml>{,1}
+
+For more information try --help";
+
+fn colors() -> App<'static, 'static> {
+    App::new("group")
+        .args_from_usage("--red 'red'
+                          --green 'green'
+                          --blue 'blue'")
+}
+
+#[test]
+fn group_min_args() {
+    let app = colors().group(ArgGroup::with_name("colors")
+        .args(&["red", "green", "blue"])
+        .min_args(2));
+    assert!(test::compare_output(app, "clap-test --red", GROUP_MIN_ARGS, true));
+
+    let m = colors()
+        .group(ArgGroup::with_name("colors")
+            .args(&["red", "green", "blue"])
+            .min_args(2))
+        .get_matches_from_safe(vec!["group", "--red", "--green", "--blue"]);
+    assert!(m.is_ok());
+}
+
+#[test]
+fn group_min_args_none_given() {
+    let result = colors()
+        .group(ArgGroup::with_name("colors")
+            .args(&["red", "green", "blue"])
+            .min_args(2))
+        .get_matches_from_safe(vec!["group"]);
+    assert_eq!(result.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn group_max_args() {
+    let app = App::new("group")
+        .args_from_usage("--json 'json'
+                          --yaml 'yaml'")
+        .group(ArgGroup::with_name("format")
+            .args(&["json", "yaml"])
+            .max_args(1));
+    assert!(test::compare_output(app, "clap-test --json --yaml", GROUP_MAX_ARGS, true));
+}
+
+#[test]
+fn group_number_of_args() {
+    let group = || {
+        ArgGroup::with_name("colors")
+            .args(&["red", "green", "blue"])
+            .number_of_args(2)
+    };
+    let ok = colors()
+        .group(group())
+        .get_matches_from_safe(vec!["group", "--green", "--blue"]);
+    assert!(ok.is_ok());
+    assert!(ok.unwrap().is_present("colors"));
+
+    let err = colors()
+        .group(group())
+        .get_matches_from_safe(vec!["group", "--red", "--green", "--blue"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::WrongNumberOfGroupArgs);
+    assert_eq!(err.get(ContextKind::Group),
+               Some(&ContextValue::String("colors".into())));
+    assert_eq!(err.get(ContextKind::MinArgs), Some(&ContextValue::Number(2)));
+    assert_eq!(err.get(ContextKind::MaxArgs), Some(&ContextValue::Number(2)));
+
+    let err = colors()
+        .group(group())
+        .get_matches_from_safe(vec!["group", "--blue"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::WrongNumberOfGroupArgs);
+    assert_eq!(err.get(ContextKind::GivenArgs),
+               Some(&ContextValue::Strings(vec!["--blue".into()])));
+}
+
+#[test]
+fn group_number_of_args_usage() {
+    let app = colors().group(ArgGroup::with_name("colors")
+        .args(&["red", "green", "blue"])
+        .number_of_args(2));
+    let mut help = vec![];
+    app.write_help(&mut help).unwrap();
+    assert!(String::from_utf8(help).unwrap().contains("group <--red|--green|--blue>{2}"));
+}
+
+#[test]
+fn group_args_defaults_dont_count() {
+    let result = App::new("group")
+        .arg(Arg::from_usage("--level [level] 'level'").default_value("1"))
+        .arg(Arg::from_usage("--preset [preset] 'preset'"))
+        .group(ArgGroup::with_name("setting")
+            .args(&["level", "preset"])
+            .max_args(1))
+        .get_matches_from_safe(vec!["group", "--preset", "fast"]);
+    assert!(result.is_ok());
+}
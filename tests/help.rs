@@ -955,3 +955,129 @@ fn override_help() {
     assert!(m.is_ok());
     assert!(m.unwrap().is_present("help"));
 }
+
+static HELP_HEADINGS: &'static str = "fetch 1.0
+
+USAGE:
+    fetch [FLAGS] [OPTIONS] <url> [output]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+    -v, --verbose    Prints more
+
+OPTIONS:
+    -j, --jobs <jobs>    Number of jobs
+
+ARGS:
+    <url>    The URL to fetch
+
+NETWORK OPTIONS:
+        --offline          Don't connect
+        --proxy <proxy>    The proxy to use
+
+OUTPUT:
+    <output>        The file to write
+    -q, --quiet     Prints nothing";
+
+fn help_headings_app() -> App<'static, 'static> {
+    App::new("fetch")
+        .version("1.0")
+        .arg(Arg::with_name("verbose").short("v").long("verbose").help("Prints more"))
+        .arg(Arg::with_name("proxy")
+            .long("proxy")
+            .takes_value(true)
+            .help_heading("NETWORK OPTIONS")
+            .help("The proxy to use"))
+        .arg(Arg::with_name("quiet")
+            .short("q")
+            .long("quiet")
+            .help_heading("OUTPUT")
+            .help("Prints nothing"))
+        .arg(Arg::with_name("jobs").short("j").long("jobs").takes_value(true).help("Number of jobs"))
+        .arg(Arg::with_name("offline")
+            .long("offline")
+            .help_heading("NETWORK OPTIONS")
+            .help("Don't connect"))
+        .arg(Arg::with_name("url").required(true).help("The URL to fetch"))
+        .arg(Arg::with_name("output").help_heading("OUTPUT").help("The file to write"))
+        .arg(Arg::with_name("secret").long("secret").hidden(true).help_heading("SECRET"))
+}
+
+#[test]
+fn help_headings() {
+    assert!(test::compare_output(help_headings_app(), "fetch --help", HELP_HEADINGS, false));
+}
+
+static HELP_HEADINGS_UNIFIED: &'static str = "fetch 1.0
+
+USAGE:
+    fetch [OPTIONS] <url> [output]
+
+OPTIONS:
+    -h, --help           Prints help information
+    -j, --jobs <jobs>    Number of jobs
+    -V, --version        Prints version information
+    -v, --verbose        Prints more
+
+ARGS:
+    <url>    The URL to fetch
+
+NETWORK OPTIONS:
+        --offline          Don't connect
+        --proxy <proxy>    The proxy to use
+
+OUTPUT:
+    <output>        The file to write
+    -q, --quiet     Prints nothing";
+
+#[test]
+fn help_headings_unified() {
+    let app = help_headings_app().setting(AppSettings::UnifiedHelpMessage);
+    assert!(test::compare_output(app, "fetch --help", HELP_HEADINGS_UNIFIED, false));
+}
+
+static SUBCOMMAND_HEADINGS: &'static str = "git 
+
+USAGE:
+    git [SUBCOMMAND]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+SUBCOMMANDS:
+    help    Prints this message or the help of the given subcommand(s)
+
+PORCELAIN COMMANDS:
+    status    Show the working tree status
+    add       Add file contents to the index
+    commit    Record changes to the repository
+
+PLUMBING COMMANDS:
+    cat-file    Provide content for repository objects";
+
+#[test]
+fn subcommand_headings() {
+    let app = App::new("git")
+        .subcommand(SubCommand::with_name("commit")
+            .help_heading("PORCELAIN COMMANDS")
+            .about("Record changes to the repository"))
+        .subcommand(SubCommand::with_name("cat-file")
+            .help_heading("PLUMBING COMMANDS")
+            .about("Provide content for repository objects"))
+        .subcommand(SubCommand::with_name("hash-object")
+            .help_heading("PLUMBING COMMANDS")
+            .setting(AppSettings::Hidden))
+        .subcommand(SubCommand::with_name("gc")
+            .help_heading("MAINTENANCE")
+            .setting(AppSettings::Hidden))
+        .subcommand(SubCommand::with_name("add")
+            .help_heading("PORCELAIN COMMANDS")
+            .about("Add file contents to the index"))
+        .subcommand(SubCommand::with_name("status")
+            .help_heading("PORCELAIN COMMANDS")
+            .display_order(1)
+            .about("Show the working tree status"));
+    assert!(test::compare_output(app, "git --help", SUBCOMMAND_HEADINGS, false));
+}
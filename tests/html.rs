@@ -0,0 +1,164 @@
+extern crate clap;
+
+use clap::{App, AppSettings, Arg, SubCommand};
+
+static HTML: &'static str = r##"<section id="myapp">
+<h1>myapp 3.0</h1>
+<p>Tests the generation of *markdown* references</p>
+<p><strong>Usage:</strong></p>
+<pre><code>myapp [FLAGS] [OPTIONS] [file] [SUBCOMMAND]</code></pre>
+<p><strong>Flags:</strong></p>
+<table>
+<thead>
+<tr><th>Argument</th><th>Description</th></tr>
+</thead>
+<tbody>
+<tr><td><code>-v, --verbose</code></td><td>Increases the verbosity,<br>
+may be used multiple times</td></tr>
+<tr><td><code>-h, --help</code></td><td>Prints help information</td></tr>
+<tr><td><code>-V, --version</code></td><td>Prints version information</td></tr>
+</tbody>
+</table>
+<p><strong>Options:</strong></p>
+<table>
+<thead>
+<tr><th>Argument</th><th>Description</th><th>Default</th><th>Possible values</th></tr>
+</thead>
+<tbody>
+<tr><td><code>--mode &lt;mode&gt;</code></td><td>the mode to run in</td><td><code>fast</code></td><td><code>fast</code>, <code>slow</code></td></tr>
+</tbody>
+</table>
+<p><strong>Arguments:</strong></p>
+<table>
+<thead>
+<tr><th>Argument</th><th>Description</th></tr>
+</thead>
+<tbody>
+<tr><td><code>&lt;file&gt;</code></td><td>some input file</td></tr>
+</tbody>
+</table>
+<p><strong>Network:</strong></p>
+<table>
+<thead>
+<tr><th>Argument</th><th>Description</th></tr>
+</thead>
+<tbody>
+<tr><td><code>--proxy &lt;proxy&gt;</code></td><td>the proxy to use</td></tr>
+</tbody>
+</table>
+<p><strong>Subcommands:</strong></p>
+<table>
+<thead>
+<tr><th>Subcommand</th><th>Description</th></tr>
+</thead>
+<tbody>
+<tr><td><a href="#myapp-test"><code>test</code></a></td><td>tests | things</td></tr>
+<tr><td><a href="#myapp-help"><code>help</code></a></td><td>Prints this message or the help of the given subcommand(s)</td></tr>
+</tbody>
+</table>
+<p>See the website for more</p>
+<section id="myapp-test">
+<h2>myapp test</h2>
+<p>tests | things</p>
+<p><strong>Usage:</strong></p>
+<pre><code>myapp test [OPTIONS]</code></pre>
+<p><strong>Flags:</strong></p>
+<table>
+<thead>
+<tr><th>Argument</th><th>Description</th></tr>
+</thead>
+<tbody>
+<tr><td><code>-h, --help</code></td><td>Prints help information</td></tr>
+<tr><td><code>-V, --version</code></td><td>Prints version information</td></tr>
+</tbody>
+</table>
+<p><strong>Options:</strong></p>
+<table>
+<thead>
+<tr><th>Argument</th><th>Description</th></tr>
+</thead>
+<tbody>
+<tr><td><code>-c, --case &lt;CASE&gt;</code></td><td>the case to test</td></tr>
+</tbody>
+</table>
+</section>
+<section id="myapp-help">
+<h2>myapp help</h2>
+<p>Prints this message or the help of the given subcommand(s)</p>
+<p><strong>Usage:</strong></p>
+<pre><code>myapp help</code></pre>
+<p><strong>Flags:</strong></p>
+<table>
+<thead>
+<tr><th>Argument</th><th>Description</th></tr>
+</thead>
+<tbody>
+<tr><td><code>-h, --help</code></td><td>Prints help information</td></tr>
+<tr><td><code>-V, --version</code></td><td>Prints version information</td></tr>
+</tbody>
+</table>
+</section>
+</section>
+"##;
+
+fn build_app() -> App<'static, 'static> {
+    App::new("myapp")
+        .version("3.0")
+        .about("Tests markdown")
+        .long_about("Tests the generation of *markdown* references")
+        .after_help("See the website for more")
+        .arg(Arg::with_name("file").help("some input file"))
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .multiple(true)
+            .help("be verbose")
+            .long_help("Increases the verbosity,{n}may be used multiple times"))
+        .arg(Arg::with_name("mode")
+            .long("mode")
+            .takes_value(true)
+            .possible_values(&["fast", "slow"])
+            .default_value("fast")
+            .help("the mode to run in"))
+        .arg(Arg::with_name("proxy")
+            .long("proxy")
+            .takes_value(true)
+            .help_heading("Network")
+            .help("the proxy to use"))
+        .arg(Arg::with_name("secret").long("secret").hidden(true))
+        .subcommand(SubCommand::with_name("test")
+            .about("tests | things")
+            .arg(Arg::with_name("case")
+                .short("c")
+                .long("case")
+                .value_name("CASE")
+                .help("the case to test")))
+        .subcommand(SubCommand::with_name("internal").setting(AppSettings::Hidden))
+}
+
+#[test]
+fn html() {
+    let mut buf = vec![];
+    build_app().gen_html_to("myapp", &mut buf);
+    let doc = String::from_utf8(buf).unwrap();
+
+    assert_eq!(doc, HTML);
+}
+
+#[test]
+fn html_escapes_text() {
+    let mut buf = vec![];
+    App::new("myapp")
+        .about("uses <tags> & \"quotes\"")
+        .arg(Arg::with_name("opt")
+            .long("some-opt")
+            .takes_value(true)
+            .value_names(&["FROM", "TO"])
+            .help("moves files from <FROM> to <TO>"))
+        .gen_html_to("myapp", &mut buf);
+    let doc = String::from_utf8(buf).unwrap();
+
+    assert!(doc.contains("<p>uses &lt;tags&gt; &amp; &quot;quotes&quot;</p>"));
+    assert!(doc.contains("<tr><td><code>--some-opt &lt;FROM&gt; &lt;TO&gt;</code></td>\
+                          <td>moves files from &lt;FROM&gt; to &lt;TO&gt;</td></tr>"));
+}
@@ -0,0 +1,183 @@
+extern crate clap;
+
+use clap::{App, Arg, ArgGroup};
+
+#[test]
+fn indices_mult_opts() {
+    let m = App::new("ind")
+        .arg(Arg::with_name("exclude")
+            .short("e")
+            .takes_value(true)
+            .multiple(true))
+        .arg(Arg::with_name("include")
+            .short("i")
+            .takes_value(true)
+            .multiple(true))
+        .get_matches_from(vec!["ind", "-e", "A", "B", "-i", "B", "C", "-e", "C"]);
+
+    assert_eq!(m.indices_of("exclude").unwrap().collect::<Vec<_>>(), &[2, 3, 8]);
+    assert_eq!(m.indices_of("include").unwrap().collect::<Vec<_>>(), &[5, 6]);
+}
+
+#[test]
+fn index_mult_opts() {
+    let m = App::new("ind")
+        .arg(Arg::with_name("exclude")
+            .short("e")
+            .takes_value(true)
+            .multiple(true))
+        .arg(Arg::with_name("include")
+            .short("i")
+            .takes_value(true)
+            .multiple(true))
+        .get_matches_from(vec!["ind", "-e", "A", "B", "-i", "B", "C", "-e", "C"]);
+
+    assert_eq!(m.index_of("exclude"), Some(2));
+    assert_eq!(m.index_of("include"), Some(5));
+}
+
+#[test]
+fn index_flag() {
+    let m = App::new("ind")
+        .arg(Arg::from_usage("-e, --exclude 'some flag'"))
+        .arg(Arg::from_usage("-i, --include 'some flag'"))
+        .get_matches_from(vec!["ind", "-e", "-i"]);
+
+    assert_eq!(m.index_of("exclude"), Some(1));
+    assert_eq!(m.index_of("include"), Some(2));
+}
+
+#[test]
+fn index_flags() {
+    let m = App::new("ind")
+        .arg(Arg::from_usage("-e, --exclude... 'some flag'"))
+        .arg(Arg::from_usage("-i, --include 'some flag'"))
+        .get_matches_from(vec!["ind", "-e", "-i", "--exclude"]);
+
+    assert_eq!(m.index_of("exclude"), Some(1));
+    assert_eq!(m.index_of("include"), Some(2));
+}
+
+#[test]
+fn indices_mult_flags() {
+    let m = App::new("ind")
+        .arg(Arg::from_usage("-e, --exclude... 'some flag'"))
+        .arg(Arg::from_usage("-i, --include... 'some flag'"))
+        .get_matches_from(vec!["ind", "-e", "-i", "-e", "-e", "-i"]);
+
+    assert_eq!(m.indices_of("exclude").unwrap().collect::<Vec<_>>(), &[1, 3, 4]);
+    assert_eq!(m.indices_of("include").unwrap().collect::<Vec<_>>(), &[2, 5]);
+}
+
+#[test]
+fn indices_mult_flags_combined() {
+    let m = App::new("ind")
+        .arg(Arg::from_usage("-e, --exclude... 'some flag'"))
+        .arg(Arg::from_usage("-i, --include... 'some flag'"))
+        .get_matches_from(vec!["ind", "-eieei"]);
+
+    assert_eq!(m.indices_of("exclude").unwrap().collect::<Vec<_>>(), &[1, 3, 4]);
+    assert_eq!(m.indices_of("include").unwrap().collect::<Vec<_>>(), &[2, 5]);
+}
+
+#[test]
+fn indices_mult_flags_opt_combined() {
+    let m = App::new("ind")
+        .arg(Arg::from_usage("-e, --exclude... 'some flag'"))
+        .arg(Arg::from_usage("-i, --include... 'some flag'"))
+        .arg(Arg::from_usage("-o [option] 'some opt'"))
+        .get_matches_from(vec!["ind", "-eieeio", "val"]);
+
+    assert_eq!(m.indices_of("exclude").unwrap().collect::<Vec<_>>(), &[1, 3, 4]);
+    assert_eq!(m.indices_of("include").unwrap().collect::<Vec<_>>(), &[2, 5]);
+    assert_eq!(m.indices_of("o").unwrap().collect::<Vec<_>>(), &[7]);
+}
+
+#[test]
+fn indices_mult_flags_opt_combined_eq() {
+    let m = App::new("ind")
+        .arg(Arg::from_usage("-e, --exclude... 'some flag'"))
+        .arg(Arg::from_usage("-i, --include... 'some flag'"))
+        .arg(Arg::from_usage("-o [option] 'some opt'"))
+        .get_matches_from(vec!["ind", "-eieeio=val"]);
+
+    assert_eq!(m.indices_of("exclude").unwrap().collect::<Vec<_>>(), &[1, 3, 4]);
+    assert_eq!(m.indices_of("include").unwrap().collect::<Vec<_>>(), &[2, 5]);
+    assert_eq!(m.indices_of("o").unwrap().collect::<Vec<_>>(), &[7]);
+}
+
+#[test]
+fn indices_mult_opt_value_delim_eq() {
+    let m = App::new("myapp")
+        .arg(Arg::with_name("option")
+            .short("o")
+            .takes_value(true)
+            .use_delimiter(true)
+            .multiple(true))
+        .get_matches_from(vec!["myapp", "-o=val1,val2,val3"]);
+    assert_eq!(m.indices_of("option").unwrap().collect::<Vec<_>>(), &[2, 3, 4]);
+}
+
+#[test]
+fn indices_mult_opt_value_no_delim_eq() {
+    let m = App::new("myapp")
+        .arg(Arg::with_name("option")
+            .short("o")
+            .takes_value(true)
+            .multiple(true))
+        .get_matches_from(vec!["myapp", "-o=val1,val2,val3"]);
+    assert_eq!(m.indices_of("option").unwrap().collect::<Vec<_>>(), &[2]);
+}
+
+#[test]
+fn indices_mult_opt_mult_flag() {
+    let m = App::new("myapp")
+        .arg(Arg::with_name("option")
+            .short("o")
+            .takes_value(true)
+            .multiple(true))
+        .arg(Arg::with_name("flag")
+            .short("f")
+            .multiple(true))
+        .get_matches_from(vec!["myapp", "-o", "val1", "-f", "-o", "val2", "-f"]);
+
+    assert_eq!(m.indices_of("option").unwrap().collect::<Vec<_>>(), &[2, 5]);
+    assert_eq!(m.indices_of("flag").unwrap().collect::<Vec<_>>(), &[3, 6]);
+}
+
+#[test]
+fn indices_positionals() {
+    let m = App::new("myapp")
+        .arg(Arg::from_usage("-f, --flag 'some flag'"))
+        .arg(Arg::from_usage("[first] 'first pos'"))
+        .arg(Arg::from_usage("[rest]... 'other pos'"))
+        .get_matches_from(vec!["myapp", "one", "--flag", "two", "three"]);
+
+    assert_eq!(m.index_of("first"), Some(1));
+    assert_eq!(m.index_of("flag"), Some(2));
+    assert_eq!(m.indices_of("rest").unwrap().collect::<Vec<_>>(), &[3, 4]);
+}
+
+#[test]
+fn indices_group() {
+    let m = App::new("myapp")
+        .arg(Arg::from_usage("-a 'flag a'"))
+        .arg(Arg::from_usage("-b [val] 'opt b'"))
+        .arg(Arg::from_usage("-c 'flag c'"))
+        .group(ArgGroup::with_name("grp").args(&["a", "b"]).multiple(true))
+        .get_matches_from(vec!["myapp", "-c", "-b", "val", "-a"]);
+
+    assert_eq!(m.indices_of("grp").unwrap().collect::<Vec<_>>(), &[3, 4]);
+}
+
+#[test]
+fn indices_subcommand() {
+    let m = App::new("myapp")
+        .arg(Arg::from_usage("-f 'some flag'"))
+        .subcommand(App::new("sub").arg(Arg::from_usage("-o [opt] 'some opt'")))
+        .get_matches_from(vec!["myapp", "-f", "sub", "-o", "val"]);
+
+    assert_eq!(m.index_of("f"), Some(1));
+    let sub_m = m.subcommand_matches("sub").unwrap();
+    assert_eq!(sub_m.index_of("o"), Some(2));
+}
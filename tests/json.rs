@@ -0,0 +1,147 @@
+#![cfg(feature="json")]
+
+extern crate clap;
+extern crate serde_json;
+
+use clap::{App, AppSettings, Arg, ArgGroup, SubCommand};
+use serde_json::Value;
+
+fn export(mut app: App) -> Value {
+    let mut buf = Vec::new();
+    app.gen_json_to("myapp", &mut buf);
+    serde_json::from_slice(&buf).unwrap()
+}
+
+fn arg<'v>(app: &'v Value, name: &str) -> &'v Value {
+    app["args"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|a| a["name"] == name)
+        .unwrap_or_else(|| panic!("no arg named {}", name))
+}
+
+fn build_app() -> App<'static, 'static> {
+    App::new("myapp")
+        .version("1.0")
+        .author("Kevin K.")
+        .about("Does awesome things")
+        .setting(AppSettings::SubcommandsNegateReqs)
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .multiple(true)
+            .visible_alias("loud")
+            .help("Sets the level of verbosity"))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .value_name("WHEN")
+            .possible_values(&["always", "never", "auto"])
+            .default_value("auto")
+            .env("MYAPP_COLOR")
+            .conflicts_with("plain"))
+        .arg(Arg::with_name("plain").long("plain").hidden(true))
+        .arg(Arg::with_name("input").required(true).index(1).help("The input file"))
+        .group(ArgGroup::with_name("style").args(&["color", "plain"]))
+        .subcommand(SubCommand::with_name("test")
+            .about("Tests things")
+            .alias("t")
+            .arg(Arg::with_name("debug").short("d").requires_if("file", "input")))
+}
+
+#[test]
+fn json_app_metadata() {
+    let json = export(build_app());
+    assert_eq!(json["schema_version"], 1);
+    assert_eq!(json["name"], "myapp");
+    assert_eq!(json["bin_name"], "myapp");
+    assert_eq!(json["version"], "1.0");
+    assert_eq!(json["author"], "Kevin K.");
+    assert_eq!(json["about"], "Does awesome things");
+    assert_eq!(json["long_about"], Value::Null);
+    assert_eq!(json["usage"],
+               "myapp [FLAGS] [OPTIONS] <input>\n    myapp [FLAGS] [OPTIONS] <SUBCOMMAND>");
+    let settings = json["settings"].as_array().unwrap();
+    assert!(settings.contains(&Value::from("SubcommandsNegateReqs")));
+    assert!(!settings.contains(&Value::from("SubcommandRequired")));
+}
+
+#[test]
+fn json_flags() {
+    let json = export(build_app());
+    let verbose = arg(&json, "verbose");
+    assert_eq!(verbose["kind"], "flag");
+    assert_eq!(verbose["short"], "v");
+    assert_eq!(verbose["long"], "verbose");
+    assert_eq!(verbose["help"], "Sets the level of verbosity");
+    assert!(verbose["settings"].as_array().unwrap().contains(&Value::from("Multiple")));
+    assert_eq!(verbose["aliases"], serde_json::json!([{"name": "loud", "visible": true}]));
+
+    let plain = arg(&json, "plain");
+    assert!(plain["settings"].as_array().unwrap().contains(&Value::from("Hidden")));
+    assert!(!plain["settings"].as_array().unwrap().contains(&Value::from("Multiple")));
+}
+
+#[test]
+fn json_generated_flags() {
+    let json = export(build_app());
+    assert_eq!(arg(&json, "help")["long"], "help");
+    assert_eq!(arg(&json, "help")["help"], "Prints help information");
+    assert_eq!(arg(&json, "version")["short"], "V");
+}
+
+#[test]
+fn json_options() {
+    let json = export(build_app());
+    let color = arg(&json, "color");
+    assert_eq!(color["kind"], "option");
+    assert_eq!(color["value_names"], serde_json::json!(["WHEN"]));
+    assert_eq!(color["possible_values"], serde_json::json!(["always", "never", "auto"]));
+    assert_eq!(color["default_value"], "auto");
+    assert_eq!(color["env"], "MYAPP_COLOR");
+    assert_eq!(color["conflicts_with"], serde_json::json!(["plain"]));
+    assert_eq!(color["value_hint"], "Unknown");
+    assert_eq!(color["number_of_values"], Value::Null);
+    assert!(color["settings"].as_array().unwrap().contains(&Value::from("TakesValue")));
+}
+
+#[test]
+fn json_positionals() {
+    let json = export(build_app());
+    let input = arg(&json, "input");
+    assert_eq!(input["kind"], "positional");
+    assert_eq!(input["index"], 1);
+    assert_eq!(input["short"], Value::Null);
+    assert!(input["settings"].as_array().unwrap().contains(&Value::from("Required")));
+}
+
+#[test]
+fn json_groups() {
+    let json = export(build_app());
+    assert_eq!(json["groups"],
+               serde_json::json!([{
+                   "name": "style",
+                   "args": ["color", "plain"],
+                   "required": false,
+                   "multiple": false,
+                   "min_args": null,
+                   "max_args": null,
+                   "requires": [],
+                   "conflicts_with": []
+               }]));
+}
+
+#[test]
+fn json_subcommands() {
+    let json = export(build_app());
+    let subcommands = json["subcommands"].as_array().unwrap();
+    let test = subcommands.iter().find(|sc| sc["name"] == "test").unwrap();
+    assert_eq!(test["bin_name"], "myapp test");
+    assert_eq!(test["about"], "Tests things");
+    assert_eq!(test["aliases"], serde_json::json!([{"name": "t", "visible": false}]));
+    assert!(test.get("schema_version").is_none());
+    assert_eq!(arg(test, "debug")["requires"],
+               serde_json::json!([{"arg": "input", "value": "file"}]));
+    assert!(subcommands.iter().any(|sc| sc["name"] == "help"));
+}
@@ -0,0 +1,231 @@
+#![cfg(feature="json")]
+
+extern crate clap;
+extern crate serde_json;
+
+use clap::{App, AppSettings, Arg, ArgGroup, ErrorKind, ContextKind, ContextValue, SubCommand,
+           ValueHint};
+use serde_json::Value;
+
+fn json(s: &str) -> Value { serde_json::from_str(s).unwrap() }
+
+fn paths(err: &clap::Error) -> Vec<String> {
+    match err.get(ContextKind::KeyPath) {
+        Some(&ContextValue::Strings(ref v)) => v.clone(),
+        other => panic!("unexpected context {:?}", other),
+    }
+}
+
+static DEF: &'static str = r#"{
+    "name": "myprog",
+    "version": "1.0",
+    "about": "tests json",
+    "settings": ["SubcommandsNegateReqs"],
+    "args": [
+        {"verbose": {"short": "v", "long": "verbose", "multiple": true}},
+        {"name": "color",
+         "long": "color",
+         "takes_value": true,
+         "possible_values": ["always", "never"],
+         "default_value_if": [["verbose", null, "always"]]},
+        {"input": {"required": true, "index": 1}}
+    ],
+    "groups": [{"name": "style", "args": ["color"]}],
+    "subcommands": [
+        {"test": {"about": "tests things", "args": [{"debug": {"short": "d"}}]}}
+    ]
+}"#;
+
+#[test]
+fn json_app() {
+    let def = json(DEF);
+    let m = App::from_json(&def)
+        .unwrap()
+        .get_matches_from_safe(vec!["myprog", "-vv", "file"])
+        .unwrap();
+    assert_eq!(m.occurrences_of("verbose"), 2);
+    assert_eq!(m.value_of("input"), Some("file"));
+    assert_eq!(m.value_of("color"), Some("always"));
+}
+
+#[test]
+fn json_subcommand() {
+    let def = json(DEF);
+    let m = App::from_json(&def)
+        .unwrap()
+        .get_matches_from_safe(vec!["myprog", "test", "-d"])
+        .unwrap();
+    assert!(m.subcommand_matches("test").unwrap().is_present("debug"));
+}
+
+#[test]
+fn json_invalid_value() {
+    let def = json(DEF);
+    let err = App::from_json(&def)
+        .unwrap()
+        .get_matches_from_safe(vec!["myprog", "--color", "sometimes", "file"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn json_reports_every_problem() {
+    let def = json(r#"{
+        "name": "myprog",
+        "args": [
+            {"input": {"requried": true}},
+            {"name": "color", "short": 1, "possible_values": ["a", false]}
+        ],
+        "subcommands": [{"test": {"settings": "NotASetting"}}],
+        "verison": "1.0"
+    }"#);
+    let err = App::from_json(&def).err().unwrap();
+    assert_eq!(err.kind, ErrorKind::InvalidDefinition);
+    assert_eq!(paths(&err),
+               ["args[0]",
+                "args[1].possible_values[1]",
+                "args[1].short",
+                "subcommands[0].settings",
+                ""]);
+    let msg = err.message();
+    assert!(msg.contains("Invalid definition at 'args[0]': unknown key 'requried'"));
+    assert!(msg.contains("Invalid definition at 'args[1].short': expected a string, found an \
+                          integer"));
+    assert!(msg.contains("Invalid definition at 'subcommands[0].settings': unknown setting \
+                          'NotASetting'"));
+    assert!(msg.contains("Invalid definition: unknown key 'verison'"));
+}
+
+#[test]
+fn json_problem_positions() {
+    let src = r#"{
+        "name": "myprog",
+        "args": [
+            {"input": {"requried": true}},
+            {"name": "color", "short": 1, "possible_values": ["a", false]}
+        ],
+        "subcommands": [{"test": {"settings": "NotASetting"}}],
+        "verison": "1.0"
+    }"#;
+    let def = json(src);
+    let err = App::from_json_source(&def, src).err().unwrap();
+    assert_eq!(paths(&err),
+               ["args[0]",
+                "args[1].possible_values[1]",
+                "args[1].short",
+                "subcommands[0].settings",
+                ""]);
+    assert_eq!(err.get(ContextKind::Line),
+               Some(&ContextValue::Numbers(vec![4, 5, 5, 7, 8])));
+    assert_eq!(err.get(ContextKind::Column),
+               Some(&ContextValue::Numbers(vec![24, 68, 31, 35, 9])));
+    let msg = err.message();
+    assert!(msg.contains("Invalid definition at 'args[0]' (line 4, column 24): unknown key \
+                          'requried'"));
+    assert!(msg.contains("Invalid definition at 'args[1].short' (line 5, column 31): expected a \
+                          string, found an integer"));
+    assert!(msg.contains("Invalid definition at line 8, column 9: unknown key 'verison'"));
+}
+
+#[test]
+fn json_missing_name() {
+    let def = json(r#"{"version": "1.0", "about": "no name"}"#);
+    let err = App::from_json(&def).err().unwrap();
+    assert_eq!(paths(&err), [""]);
+    assert!(err.message().contains("expected a 'name' key"));
+}
+
+#[test]
+fn json_wrong_tuple() {
+    let def = json(r#"{"name": "myprog", "args": [{"a": {"required_if": [["b"]]}}]}"#);
+    let err = App::from_json(&def).err().unwrap();
+    assert_eq!(paths(&err), ["args[0].required_if[0]"]);
+    assert!(err.message().contains("expected an array of 2 strings, found an array"));
+}
+
+#[test]
+fn json_syntax_error() {
+    let res: Result<Value, clap::Error> = serde_json::from_str("{\n  \"name\": \"myprog\",\n}")
+        .map_err(From::from);
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidDefinition);
+    assert_eq!(err.get(ContextKind::Line), Some(&ContextValue::Number(3)));
+    assert_eq!(err.get(ContextKind::Column), Some(&ContextValue::Number(1)));
+    assert!(err.message().contains("Invalid definition at line 3, column 1: trailing comma"));
+}
+
+fn exported(app: &mut App) -> String {
+    let mut buf = vec![];
+    app.gen_json_to("myprog", &mut buf);
+    String::from_utf8(buf).unwrap()
+}
+
+fn round_trip_app() -> App<'static, 'static> {
+    App::new("myprog")
+        .version("1.0")
+        .long_version("1.0 (abc123)")
+        .about("tests json")
+        .long_about("tests json, at length")
+        .help_short("H")
+        .setting(AppSettings::SubcommandsNegateReqs)
+        .arg(Arg::with_name("verbose").short("v").long("verbose").multiple(true).global(true))
+        .arg(Arg::with_name("color")
+                 .long("color")
+                 .visible_alias("colour")
+                 .possible_values(&["always", "never"])
+                 .default_value_if("verbose", None, "always")
+                 .value_hint(ValueHint::Other))
+        .arg(Arg::with_name("exec")
+                 .long("exec")
+                 .multiple(true)
+                 .value_terminator(";")
+                 .requires_if("all", "verbose"))
+        .arg(Arg::with_name("input")
+                 .index(1)
+                 .required_unless_one(&["exec", "color"])
+                 .conflicts_with("exec"))
+        .group(ArgGroup::with_name("style").args(&["color"]).min_args(0).max_args(1))
+        .subcommand(SubCommand::with_name("test")
+                        .alias("t")
+                        .arg(Arg::with_name("debug").short("d").requires("verbose")))
+}
+
+#[test]
+fn json_round_trip() {
+    let json = exported(&mut round_trip_app());
+    let def = serde_json::from_str(&json).unwrap();
+    let mut app = App::from_json(&def).unwrap();
+    assert_eq!(exported(&mut app), json);
+
+    let m = App::from_json(&def)
+        .unwrap()
+        .get_matches_from_safe(vec!["myprog", "t", "-d", "-v"])
+        .unwrap();
+    let sub = m.subcommand_matches("test").unwrap();
+    assert!(sub.is_present("debug"));
+    assert!(sub.is_present("verbose"));
+    let err = App::from_json(&def)
+        .unwrap()
+        .get_matches_from_safe(vec!["myprog", "-H"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::HelpDisplayed);
+}
+
+#[test]
+fn json_null_is_unset() {
+    let def = json(r#"{
+        "name": "myprog",
+        "version": null,
+        "args": [{"name": "input", "help": null, "index": 1, "default_value": null}]
+    }"#);
+    let m = App::from_json(&def).unwrap().get_matches_from(vec!["myprog"]);
+    assert_eq!(m.value_of("input"), None);
+}
+
+#[test]
+fn json_newer_schema_version() {
+    let def = json(r#"{"name": "myprog", "schema_version": 2}"#);
+    let err = App::from_json(&def).err().unwrap();
+    assert_eq!(paths(&err), ["schema_version"]);
+    assert!(err.message().contains("unsupported schema version 2"));
+}
@@ -0,0 +1,142 @@
+extern crate clap;
+
+use clap::{App, AppSettings, Arg, SubCommand};
+
+static MAN: &'static str = r#".TH "MYAPP" "1" "" "myapp 3.0"
+.SH NAME
+myapp \- Tests man pages
+.SH SYNOPSIS
+myapp [FLAGS] [OPTIONS] [file] [SUBCOMMAND]
+.SH DESCRIPTION
+Tests the generation of man pages.
+\&.This line starts with a dot
+.SH FLAGS
+.TP
+\fB\-v\fR, \fB\-\-verbose\fR
+Increases the verbosity, may be used multiple times
+.TP
+\fB\-h\fR, \fB\-\-help\fR
+Prints help information
+.TP
+\fB\-V\fR, \fB\-\-version\fR
+Prints version information
+.SH OPTIONS
+.TP
+\fB\-\-mode\fR <\fImode\fR>
+the mode to run in
+.br
+[default: fast]
+.br
+[possible values: fast, slow]
+.SH ARGS
+.TP
+<\fIfile\fR>
+some input file
+.SH SUBCOMMANDS
+.TP
+\fBtest\fR
+tests things
+.TP
+\fBhelp\fR
+Prints this message or the help of the given subcommand(s)
+.SH "myapp test"
+tests things
+.SS USAGE
+myapp test [OPTIONS]
+.SS FLAGS
+.TP
+\fB\-h\fR, \fB\-\-help\fR
+Prints help information
+.TP
+\fB\-V\fR, \fB\-\-version\fR
+Prints version information
+.SS OPTIONS
+.TP
+\fB\-c\fR, \fB\-\-case\fR <\fICASE\fR>
+the case to test
+.SH "myapp help"
+Prints this message or the help of the given subcommand(s)
+.SS USAGE
+myapp help
+.SS FLAGS
+.TP
+\fB\-h\fR, \fB\-\-help\fR
+Prints help information
+.TP
+\fB\-V\fR, \fB\-\-version\fR
+Prints version information
+.SH VERSION
+3.0
+.SH AUTHORS
+Kevin K. <kbknapp@gmail.com>
+"#;
+
+fn build_app() -> App<'static, 'static> {
+    App::new("myapp")
+        .version("3.0")
+        .author("Kevin K. <kbknapp@gmail.com>")
+        .about("Tests man pages")
+        .long_about("Tests the generation of man pages.\n.This line starts with a dot")
+        .arg(Arg::with_name("file").help("some input file"))
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .multiple(true)
+            .help("be verbose")
+            .long_help("Increases the verbosity, may be used multiple times"))
+        .arg(Arg::with_name("mode")
+            .long("mode")
+            .takes_value(true)
+            .possible_values(&["fast", "slow"])
+            .default_value("fast")
+            .help("the mode to run in"))
+        .arg(Arg::with_name("secret").long("secret").hidden(true))
+        .subcommand(SubCommand::with_name("test")
+            .about("tests things")
+            .arg(Arg::with_name("case")
+                .short("c")
+                .long("case")
+                .value_name("CASE")
+                .help("the case to test")))
+}
+
+#[test]
+fn man_page() {
+    let mut buf = vec![];
+    build_app().gen_manpage_to("myapp", &mut buf);
+    let page = String::from_utf8(buf).unwrap();
+
+    assert_eq!(page, MAN);
+}
+
+#[test]
+fn man_page_hidden_subcommand() {
+    let mut buf = vec![];
+    App::new("myapp")
+        .subcommand(SubCommand::with_name("visible").about("is shown"))
+        .subcommand(SubCommand::with_name("secret")
+            .about("is not shown")
+            .setting(AppSettings::Hidden))
+        .gen_manpage_to("myapp", &mut buf);
+    let page = String::from_utf8(buf).unwrap();
+
+    assert!(page.contains(".SH \"myapp visible\""));
+    assert!(!page.contains("secret"));
+}
+
+#[test]
+fn man_page_escapes_text() {
+    let mut buf = vec![];
+    App::new("myapp")
+        .about("uses a \\ backslash")
+        .arg(Arg::with_name("opt")
+            .long("some-opt")
+            .takes_value(true)
+            .value_names(&["FROM", "TO"])
+            .help("moves files from FROM to TO"))
+        .gen_manpage_to("myapp", &mut buf);
+    let page = String::from_utf8(buf).unwrap();
+
+    assert!(page.contains("myapp \\- uses a \\e backslash\n"));
+    assert!(page.contains("\\fB\\-\\-some\\-opt\\fR <\\fIFROM\\fR> <\\fITO\\fR>\n"));
+}
@@ -0,0 +1,166 @@
+extern crate clap;
+
+use clap::{App, AppSettings, Arg, SubCommand};
+
+static MARKDOWN: &'static str = r#"<a id="myapp"></a>
+# myapp 3.0
+
+Tests the generation of \*markdown\* references
+
+**Usage:**
+
+```text
+myapp [FLAGS] [OPTIONS] [file] [SUBCOMMAND]
+```
+
+**Flags:**
+
+| Argument | Description |
+| --- | --- |
+| `-v, --verbose` | Increases the verbosity,<br>may be used multiple times |
+| `-h, --help` | Prints help information |
+| `-V, --version` | Prints version information |
+
+**Options:**
+
+| Argument | Description | Default | Possible values |
+| --- | --- | --- | --- |
+| `--mode <mode>` | the mode to run in | `fast` | `fast`, `slow` |
+
+**Arguments:**
+
+| Argument | Description |
+| --- | --- |
+| `<file>` | some input file |
+
+**Network:**
+
+| Argument | Description |
+| --- | --- |
+| `--proxy <proxy>` | the proxy to use |
+
+**Subcommands:**
+
+| Subcommand | Description |
+| --- | --- |
+| [`test`](#myapp-test) | tests \| things |
+| [`help`](#myapp-help) | Prints this message or the help of the given subcommand(s) |
+
+See the website for more
+
+<a id="myapp-test"></a>
+## myapp test
+
+tests \| things
+
+**Usage:**
+
+```text
+myapp test [OPTIONS]
+```
+
+**Flags:**
+
+| Argument | Description |
+| --- | --- |
+| `-h, --help` | Prints help information |
+| `-V, --version` | Prints version information |
+
+**Options:**
+
+| Argument | Description |
+| --- | --- |
+| `-c, --case <CASE>` | the case to test |
+
+<a id="myapp-help"></a>
+## myapp help
+
+Prints this message or the help of the given subcommand(s)
+
+**Usage:**
+
+```text
+myapp help
+```
+
+**Flags:**
+
+| Argument | Description |
+| --- | --- |
+| `-h, --help` | Prints help information |
+| `-V, --version` | Prints version information |
+"#;
+
+fn build_app() -> App<'static, 'static> {
+    App::new("myapp")
+        .version("3.0")
+        .about("Tests markdown")
+        .long_about("Tests the generation of *markdown* references")
+        .after_help("See the website for more")
+        .arg(Arg::with_name("file").help("some input file"))
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .multiple(true)
+            .help("be verbose")
+            .long_help("Increases the verbosity,{n}may be used multiple times"))
+        .arg(Arg::with_name("mode")
+            .long("mode")
+            .takes_value(true)
+            .possible_values(&["fast", "slow"])
+            .default_value("fast")
+            .help("the mode to run in"))
+        .arg(Arg::with_name("proxy")
+            .long("proxy")
+            .takes_value(true)
+            .help_heading("Network")
+            .help("the proxy to use"))
+        .arg(Arg::with_name("secret").long("secret").hidden(true))
+        .subcommand(SubCommand::with_name("test")
+            .about("tests | things")
+            .arg(Arg::with_name("case")
+                .short("c")
+                .long("case")
+                .value_name("CASE")
+                .help("the case to test")))
+        .subcommand(SubCommand::with_name("internal").setting(AppSettings::Hidden))
+}
+
+#[test]
+fn markdown() {
+    let mut buf = vec![];
+    build_app().gen_markdown_to("myapp", &mut buf);
+    let doc = String::from_utf8(buf).unwrap();
+
+    assert_eq!(doc, MARKDOWN);
+}
+
+#[test]
+fn markdown_anchors() {
+    let mut buf = vec![];
+    App::new("myapp")
+        .subcommand(SubCommand::with_name("remote")
+            .subcommand(SubCommand::with_name("add").about("adds a remote")))
+        .gen_markdown_to("myapp", &mut buf);
+    let doc = String::from_utf8(buf).unwrap();
+
+    assert!(doc.contains("| [`add`](#myapp-remote-add) | adds a remote |"));
+    assert!(doc.contains("<a id=\"myapp-remote-add\"></a>\n### myapp remote add\n"));
+}
+
+#[test]
+fn markdown_hides_defaults_and_values() {
+    let mut buf = vec![];
+    App::new("myapp")
+        .arg(Arg::with_name("mode")
+            .long("mode")
+            .possible_values(&["fast", "slow"])
+            .default_value("fast")
+            .hide_possible_values(true)
+            .hide_default_value(true))
+        .gen_markdown_to("myapp", &mut buf);
+    let doc = String::from_utf8(buf).unwrap();
+
+    assert!(doc.contains("| Argument | Description |\n| --- | --- |\n| `--mode <mode>` |  |"));
+    assert!(!doc.contains("fast"));
+}
@@ -162,3 +162,105 @@ fn possible_values_of_option_multiple_fail() {
 fn possible_values_output() {
     assert!(test::compare_output(test::complex_app(), "clap-test -O slo", PV_ERROR, true));
 }
+
+#[test]
+fn case_insensitive() {
+    let m = App::new("pv")
+        .arg(Arg::with_name("option")
+            .short("-o")
+            .long("--option")
+            .takes_value(true)
+            .possible_value("test123")
+            .possible_value("test321")
+            .case_insensitive(true))
+        .get_matches_from_safe(vec![
+            "pv",
+            "--option", "TeSt123",
+        ]);
+
+    assert!(m.is_ok());
+    assert_eq!(m.unwrap().value_of("option"), Some("test123"));
+}
+
+#[test]
+fn case_insensitive_fail() {
+    let m = App::new("pv")
+        .arg(Arg::with_name("option")
+            .short("-o")
+            .long("--option")
+            .takes_value(true)
+            .possible_value("test123")
+            .possible_value("test321"))
+        .get_matches_from_safe(vec![
+            "pv",
+            "--option", "TeSt123",
+        ]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn case_insensitive_multiple() {
+    let m = App::new("pv")
+        .arg(Arg::with_name("option")
+            .short("-o")
+            .long("--option")
+            .takes_value(true)
+            .possible_value("test123")
+            .possible_value("test321")
+            .multiple(true)
+            .case_insensitive(true))
+        .get_matches_from_safe(vec![
+            "pv",
+            "--option", "TeSt123", "teST123", "test321",
+        ]);
+
+    assert!(m.is_ok());
+    assert_eq!(m.unwrap().values_of("option").unwrap().collect::<Vec<_>>(),
+               &["test123", "test123", "test321"]);
+}
+
+#[test]
+fn case_insensitive_default_value() {
+    let m = App::new("pv")
+        .arg(Arg::with_name("option")
+            .long("--option")
+            .takes_value(true)
+            .possible_values(&["fast", "slow"])
+            .default_value("FAST")
+            .case_insensitive(true))
+        .get_matches_from_safe(vec!["pv"]);
+
+    assert!(m.is_ok());
+    assert_eq!(m.unwrap().value_of("option"), Some("fast"));
+}
+
+#[test]
+#[cfg(feature = "suggestions")]
+fn case_insensitive_suggestion_ignores_case() {
+    let m = App::new("pv")
+        .arg(Arg::with_name("option")
+            .long("--option")
+            .takes_value(true)
+            .possible_values(&["slow", "fast"])
+            .case_insensitive(true))
+        .get_matches_from_safe(vec!["pv", "--option", "SLOE"]);
+
+    assert!(m.is_err());
+    assert!(m.unwrap_err().message().contains("Did you mean 'slow'?"));
+}
+
+#[test]
+#[cfg(feature = "suggestions")]
+fn possible_values_suggestion_matches_case() {
+    let m = App::new("pv")
+        .arg(Arg::with_name("option")
+            .long("--option")
+            .takes_value(true)
+            .possible_values(&["slow", "fast"]))
+        .get_matches_from_safe(vec!["pv", "--option", "SLOE"]);
+
+    assert!(m.is_err());
+    assert!(!m.unwrap_err().message().contains("Did you mean"));
+}
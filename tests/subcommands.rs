@@ -44,7 +44,7 @@ For more information try --help";
 
 #[cfg(feature = "suggestions")]
 static DYM2: &'static str = "error: Found argument '--subcmdarg' which wasn't expected, or isn't valid in this context
-\tDid you mean to put '--subcmdarg' after the subcommand 'subcmdarg'?
+\tDid you mean to put '--subcmdarg' after the subcommand 'subcmd'?
 
 USAGE:
     clap-test [FLAGS] [OPTIONS] [ARGS] [SUBCOMMAND]
@@ -133,6 +133,24 @@ fn subcmd_did_you_mean_output() {
     assert!(test::compare_output(test::complex_app(), "clap-test --subcmdarg foo", DYM2, true));
 }
 
+#[cfg(feature = "suggestions")]
+static DYM_SUBCMD_NAME: &'static str = "error: Found argument '--releas' which wasn't expected, or isn't valid in this context
+\tDid you mean to put '--releas' after the subcommand 'build'?
+
+USAGE:
+    myprog [SUBCOMMAND]
+
+For more information try --help";
+
+#[test]
+#[cfg(feature="suggestions")]
+fn subcmd_did_you_mean_names_the_subcommand() {
+    let app = App::new("myprog")
+        .subcommand(SubCommand::with_name("build")
+            .arg(Arg::with_name("release").long("release")));
+    assert!(test::compare_output(app, "myprog --releas", DYM_SUBCMD_NAME, true));
+}
+
 #[test]
 fn alias_help() {
     let m = App::new("myprog")
@@ -1,7 +1,7 @@
 extern crate clap;
 extern crate regex;
 
-use clap::{App, SubCommand};
+use clap::{App, Arg, SubCommand};
 
 include!("../clap-test.rs");
 
@@ -91,6 +91,12 @@ fn template_unknowntag() {
     assert!(test::compare_output(app, "MyApp --help", "test {unknown_tag} test", false));
 }
 
+#[test]
+fn template_question_mark_tag() {
+    let app = App::new("MyApp").template("test {?} test");
+    assert!(test::compare_output(app, "MyApp --help", "test {?} test", false));
+}
+
 #[test]
 fn template_author_version() {
     let app = App::new("MyApp")
@@ -101,6 +107,125 @@ fn template_author_version() {
     assert!(test::compare_output(app, "MyApp --help", "Kevin K. <kbknapp@gmail.com>\n1.0\nDoes awesome things\nMyApp", false));
 }
 
+#[test]
+fn template_section() {
+    let app = App::new("MyApp")
+        .arg(Arg::with_name("proxy")
+            .long("proxy")
+            .takes_value(true)
+            .help_heading("Network options")
+            .help("The proxy to use"))
+        .arg(Arg::with_name("jobs").long("jobs").takes_value(true).help("Number of jobs"))
+        .template("OPTIONS:\n{options}\nNETWORK:\n{section:Network options}{section:nope}");
+    assert!(test::compare_output(app,
+                                 "MyApp --help",
+                                 "OPTIONS:\n        --jobs <jobs>    Number of jobs\nNETWORK:\n        \
+                                  --proxy <proxy>    The proxy to use",
+                                 false));
+}
+
+#[test]
+fn template_subcommand_section() {
+    let app = App::new("MyApp")
+        .subcommand(SubCommand::with_name("add").help_heading("Porcelain").about("Adds things"))
+        .subcommand(SubCommand::with_name("hash").help_heading("Plumbing").about("Hashes things"))
+        .template("{subcommands}\nPORCELAIN:\n{section:Porcelain}");
+    assert!(test::compare_output(app,
+                                 "MyApp --help",
+                                 "    help    Prints this message or the help of the given \
+                                  subcommand(s)\nPORCELAIN:\n    add    Adds things",
+                                 false));
+}
+
+#[test]
+fn template_conditionals() {
+    let app = App::new("MyApp")
+        .about("Does awesome things")
+        .template("{name}{#if about} - {about}{/if}{#if author} by {author}{/if}\n\
+                   {#if !subcommands}No subcommands{#else}{subcommands}{/if}\n\
+                   {#if flags}{#if options}both{#else}flags only{/if}{/if}");
+    assert!(test::compare_output(app,
+                                 "MyApp --help",
+                                 "MyApp - Does awesome things\nNo subcommands\nflags only",
+                                 false));
+}
+
+#[test]
+fn template_long_about() {
+    let app = App::new("MyApp")
+        .about("Does awesome things")
+        .long_about("Does awesome things, at length")
+        .template("{about}\n{long-about}");
+    assert!(test::compare_output(app,
+                                 "MyApp --help",
+                                 "Does awesome things\nDoes awesome things, at length",
+                                 false));
+}
+
+#[test]
+fn template_long_tag() {
+    let app = App::new("MyApp")
+        .arg(Arg::with_name("proxy")
+            .long("proxy")
+            .help_heading("Options which are only used when connecting to the network")
+            .help("Uses a proxy"))
+        .template("{#if section:Options which are only used when connecting to the network}\
+                   {section:Options which are only used when connecting to the network}{/if}");
+    assert!(test::compare_output(app, "MyApp --help", "        --proxy    Uses a proxy", false));
+}
+
+#[test]
+fn arg_template() {
+    let app = App::new("MyApp")
+        .arg(Arg::with_name("verbose").short("v").long("verbose").help("Prints more"))
+        .arg(Arg::with_name("config")
+            .short("c")
+            .long("config")
+            .value_name("FILE")
+            .default_value("app.toml")
+            .help("Sets the config file"))
+        .arg(Arg::with_name("jobs").long("jobs").takes_value(true).help("Number of jobs"))
+        .arg(Arg::with_name("input").help("The input file"))
+        .template("{options}\n{positionals}\n{flags}")
+        .arg_template("  {short:3}{long:auto} {value:auto}  {help}{#if spec} ({spec}){/if}");
+    assert!(test::compare_output(app,
+                                 "MyApp --help",
+                                 "  -c --config <FILE>  Sets the config file ([default: app.toml])
+     --jobs   <jobs>  Number of jobs
+      <input>  The input file
+  -h --help      Prints help information
+  -V --version   Prints version information
+  -v --verbose   Prints more",
+                                 false));
+}
+
+#[test]
+fn arg_template_wraps_help() {
+    let app = App::new("MyApp")
+        .set_term_width(30)
+        .arg(Arg::with_name("verbose")
+            .long("verbose")
+            .help("Prints more information about what is going on"))
+        .subcommand(SubCommand::with_name("test").about("Tests things"))
+        .template("{flags}\n{subcommands}")
+        .arg_template("{#if long}{long:12}{#else}{value:12}{/if}{help}");
+    assert!(test::compare_output(app,
+                                 "MyApp --help",
+                                 "--help      Prints help
+            information
+--version   Prints version
+            information
+--verbose   Prints more
+            information about
+            what is going on
+help        Prints this
+            message or the
+            help of the given
+            subcommand(s)
+test        Tests things",
+                                 false));
+}
+
 // ----------
 
 fn app_example1<'b, 'c>() -> App<'b, 'c> {
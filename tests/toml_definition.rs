@@ -0,0 +1,128 @@
+#![cfg(feature="toml")]
+
+extern crate clap;
+extern crate toml;
+
+use clap::{App, ErrorKind, ContextKind, ContextValue};
+
+static DEF: &'static str = r#"
+name = "myprog"
+version = "1.0"
+settings = ["SubcommandsNegateReqs"]
+
+[[args]]
+name = "verbose"
+short = "v"
+multiple = true
+
+[[args]]
+name = "jobs"
+long = "jobs"
+takes_value = true
+default_value = "4"
+
+[[args]]
+input = { required = true, index = 1 }
+
+[[subcommands]]
+name = "test"
+aliases = ["t"]
+
+[[subcommands.args]]
+name = "debug"
+short = "d"
+"#;
+
+#[test]
+fn toml_app() {
+    let def: toml::Value = DEF.parse().unwrap();
+    let m = App::from_toml(&def)
+        .unwrap()
+        .get_matches_from_safe(vec!["myprog", "-v", "file"])
+        .unwrap();
+    assert!(m.is_present("verbose"));
+    assert_eq!(m.value_of("jobs"), Some("4"));
+    assert_eq!(m.value_of("input"), Some("file"));
+}
+
+#[test]
+fn toml_subcommand() {
+    let def: toml::Value = DEF.parse().unwrap();
+    let m = App::from_toml(&def)
+        .unwrap()
+        .get_matches_from_safe(vec!["myprog", "t", "-d"])
+        .unwrap();
+    assert!(m.subcommand_matches("test").unwrap().is_present("debug"));
+}
+
+#[test]
+fn toml_problems() {
+    let def: toml::Value = r#"
+name = "myprog"
+
+[[args]]
+name = "jobs"
+index = -1
+default_value = 1979-05-27T07:32:00Z
+"#
+        .parse()
+        .unwrap();
+    let err = App::from_toml(&def).err().unwrap();
+    assert_eq!(err.kind, ErrorKind::InvalidDefinition);
+    assert_eq!(err.get(ContextKind::KeyPath),
+               Some(&ContextValue::Strings(vec!["args[0].default_value".into(),
+                                                "args[0].index".into()])));
+    let msg = err.message();
+    assert!(msg.contains("'args[0].default_value': expected a string, found a datetime"));
+    assert!(msg.contains("'args[0].index': expected a non-negative integer, found an integer"));
+}
+
+#[test]
+fn toml_problem_positions() {
+    let src = r#"
+name = "myprog"
+about = """
+Tests "things"
+"""
+
+[[args]]
+name = "jobs"
+index = -1
+
+[[subcommands]]
+name = "test"
+args = [{name = "fast", short = 1}]
+
+[[subcommands]]
+name = "build"
+
+[[subcommands.args]]
+name = "release"
+requried = true # a typo
+"#;
+    let def: toml::Value = src.parse().unwrap();
+    let err = App::from_toml_source(&def, src).err().unwrap();
+    assert_eq!(err.get(ContextKind::KeyPath),
+               Some(&ContextValue::Strings(vec!["args[0].index".into(),
+                                                "subcommands[0].args[0].short".into(),
+                                                "subcommands[1].args[0]".into()])));
+    assert_eq!(err.get(ContextKind::Line),
+               Some(&ContextValue::Numbers(vec![9, 13, 20])));
+    assert_eq!(err.get(ContextKind::Column),
+               Some(&ContextValue::Numbers(vec![1, 25, 1])));
+    assert!(err.message().contains("Invalid definition at 'subcommands[1].args[0]' (line 20, \
+                                    column 1): unknown key 'requried'"));
+}
+
+#[test]
+fn toml_syntax_error() {
+    let res: Result<toml::Value, clap::Error> = "name = \"myprog\"\nversion = \n"
+        .parse::<toml::Value>()
+        .map_err(From::from);
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidDefinition);
+    assert_eq!(err.get(ContextKind::Line), Some(&ContextValue::Number(2)));
+    assert_eq!(err.get(ContextKind::Column), Some(&ContextValue::Number(11)));
+    assert!(err.message()
+        .ends_with("Invalid definition at line 2, column 11: expected a value, found a newline"));
+}
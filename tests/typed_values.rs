@@ -0,0 +1,84 @@
+extern crate clap;
+extern crate regex;
+
+use clap::{App, Arg, ErrorKind, SubCommand};
+use regex::Regex;
+
+static PARSE_ERROR: &'static str = "error: Invalid value 'twelve' for 'len': invalid digit found in string
+
+USAGE:
+    myapp [OPTIONS] [--] [len]
+
+For more information try --help";
+
+fn strip_colors(s: &str) -> String {
+    Regex::new("\x1b[^m]*m").unwrap().replace_all(s, "").into_owned()
+}
+
+fn build_app() -> App<'static, 'static> {
+    App::new("myapp")
+        .arg(Arg::with_name("len").takes_value(true))
+        .arg(Arg::with_name("seq").long("seq").takes_value(true).multiple(true))
+}
+
+#[test]
+fn value_of_t() {
+    let m = build_app().get_matches_from(vec!["myapp", "12"]);
+    assert_eq!(m.value_of_t::<u32, _>("len").unwrap(), 12);
+    assert_eq!(m.value_of_t::<String, _>("len").unwrap(), "12");
+}
+
+#[test]
+fn value_of_t_parse_error() {
+    let m = build_app().get_matches_from(vec!["myapp", "twelve"]);
+    let err = m.value_of_t::<u32, _>("len").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert_eq!(err.info, Some(vec!["len".to_owned(), "twelve".to_owned()]));
+    assert_eq!(strip_colors(&err.message()), PARSE_ERROR);
+}
+
+#[test]
+fn value_of_t_not_present() {
+    let m = build_app().get_matches_from(vec!["myapp"]);
+    let err = m.value_of_t::<u32, _>("len").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ArgumentNotFound);
+}
+
+#[test]
+fn value_of_t_or_exit() {
+    let m = build_app().get_matches_from(vec!["myapp", "12"]);
+    let len: u32 = m.value_of_t_or_exit("len");
+    assert_eq!(len, 12);
+}
+
+#[test]
+fn values_of_t() {
+    let m = build_app().get_matches_from(vec!["myapp", "--seq", "1", "2", "3"]);
+    assert_eq!(m.values_of_t::<u8, _>("seq").unwrap(), [1, 2, 3]);
+    let seq: Vec<u8> = m.values_of_t_or_exit("seq");
+    assert_eq!(seq, [1, 2, 3]);
+}
+
+#[test]
+fn values_of_t_parse_error() {
+    let m = build_app().get_matches_from(vec!["myapp", "--seq", "1", "300", "x"]);
+    let err = m.values_of_t::<u8, _>("seq").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert_eq!(err.info, Some(vec!["seq".to_owned(), "300".to_owned()]));
+}
+
+#[test]
+fn values_of_t_not_present() {
+    let m = build_app().get_matches_from(vec!["myapp"]);
+    let err = m.values_of_t::<u8, _>("seq").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ArgumentNotFound);
+}
+
+#[test]
+fn value_of_t_subcommand_usage() {
+    let m = App::new("myapp")
+        .subcommand(SubCommand::with_name("sub").arg(Arg::with_name("len").long("len").takes_value(true)))
+        .get_matches_from(vec!["myapp", "sub", "--len", "x"]);
+    let err = m.subcommand_matches("sub").unwrap().value_of_t::<u32, _>("len").unwrap_err();
+    assert!(strip_colors(&err.message()).contains("USAGE:\n    myapp sub [OPTIONS]"));
+}
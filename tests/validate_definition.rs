@@ -0,0 +1,140 @@
+extern crate clap;
+
+use clap::{App, AppSettings, Arg, ArgGroup, ContextKind, ErrorKind, SubCommand};
+
+fn problems(app: App) -> Vec<String> {
+    let err = app.validate_definition().unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidDefinition);
+    err.message()
+        .lines()
+        .map(|l| l.trim_left_matches("error: Invalid definition at ").to_owned())
+        .collect()
+}
+
+#[test]
+fn valid_definition() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("verbose").short("v").global(true))
+        .arg(Arg::with_name("mode")
+                 .long("mode")
+                 .possible_values(&["fast", "slow"])
+                 .default_value("fast")
+                 .alias("speed"))
+        .arg(Arg::with_name("input").requires("mode").conflicts_with("output"))
+        .arg(Arg::with_name("output").index(2))
+        .group(ArgGroup::with_name("files").args(&["input", "output"]).multiple(true))
+        .subcommand(SubCommand::with_name("build")
+                        .alias("b")
+                        .arg(Arg::with_name("jobs").short("j").takes_value(true)))
+        .subcommand(SubCommand::with_name("clean"));
+    assert!(app.validate_definition().is_ok());
+}
+
+#[test]
+fn global_switches_in_subcommands() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("verbose").short("v").long("verbose").global(true))
+        .subcommand(SubCommand::with_name("build")
+                        .arg(Arg::with_name("version").short("v"))
+                        .arg(Arg::with_name("noisy").long("noisy").alias("verbose")));
+    assert_eq!(problems(app),
+               vec!["'subcommands.build.args.version': the short '-v' is also used by the global \
+                     argument 'verbose'",
+                    "'subcommands.build.args.noisy': the alias '--verbose' is the long of the \
+                     global argument 'verbose'"]);
+}
+
+#[test]
+fn unknown_relations() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("input").requires("confg"))
+        .arg(Arg::with_name("force").long("force").conflicts_with("dry_run"))
+        .group(ArgGroup::with_name("mode").arg("force").requires("inptu"));
+    assert_eq!(problems(app),
+               vec!["'args.force': it conflicts with 'dry_run', which isn't an argument or group",
+                    "'args.input': it requires 'confg', which isn't an argument or group",
+                    "'groups.mode': it requires 'inptu', which isn't an argument or group"]);
+}
+
+#[test]
+fn impossible_requirements() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("release").long("release").required(true).requires("opt"))
+        .arg(Arg::with_name("opt").long("opt").requires("debug"))
+        .arg(Arg::with_name("debug").long("debug").conflicts_with("release"));
+    let problems = problems(app);
+    assert_eq!(problems,
+               vec!["'args.release': it's required, but it requires 'debug', which conflicts \
+                     with 'release'"]);
+}
+
+#[test]
+fn positional_index_gaps() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("input").index(1))
+        .arg(Arg::with_name("output").index(3))
+        .arg(Arg::with_name("extra").index(5));
+    assert_eq!(problems(app),
+               vec!["'args.output': its index is 3, but there's no positional argument with \
+                     index 2",
+                    "'args.extra': its index is 5, but there's no positional argument with \
+                     index 4"]);
+}
+
+#[test]
+fn defaults_not_possible_values() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("color")
+                 .long("color")
+                 .possible_values(&["auto", "always", "never"])
+                 .default_value("Always")
+                 .case_insensitive(true))
+        .arg(Arg::with_name("mode")
+                 .long("mode")
+                 .possible_values(&["fast", "slow"])
+                 .use_delimiter(true)
+                 .default_value("fast,quick")
+                 .default_value_if("color", None, "fastest"));
+    assert_eq!(problems(app),
+               vec!["'args.mode': its default value 'quick' isn't one of its possible values",
+                    "'args.mode': its default value 'fastest' isn't one of its possible values"]);
+}
+
+#[test]
+fn colliding_aliases() {
+    let app = App::new("prog")
+        .setting(AppSettings::DisableHelpSubcommand)
+        .arg(Arg::with_name("all").long("all").alias("everything"))
+        .arg(Arg::with_name("each").long("each").aliases(&["all", "everything"]))
+        .subcommand(SubCommand::with_name("build").alias("b"))
+        .subcommand(SubCommand::with_name("bench").aliases(&["b", "build"]));
+    let err = app.validate_definition().unwrap_err();
+    assert_eq!(err.get(ContextKind::DefinitionPath).unwrap().to_string(),
+               "args.each, args.each, subcommands.bench, subcommands.bench");
+    assert_eq!(err.get(ContextKind::KeyPath), None);
+    assert_eq!(problems(App::new("prog")
+                            .arg(Arg::with_name("all").long("all").alias("everything"))
+                            .arg(Arg::with_name("each").long("each").alias("everything"))),
+               vec!["'args.each': the alias '--everything' is also an alias of 'all'"]);
+}
+
+#[test]
+fn conflicting_required_args() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("a").long("a").required(true).conflicts_with("b"))
+        .arg(Arg::with_name("b").long("b").required(true))
+        .arg(Arg::with_name("c").long("c").required(true).conflicts_with("mode"))
+        .arg(Arg::with_name("fast").long("fast"))
+        .arg(Arg::with_name("slow").long("slow"))
+        .group(ArgGroup::with_name("mode").args(&["fast", "slow"]).required(true));
+    assert_eq!(problems(app),
+               vec!["'args.a': it's required, but it conflicts with 'b', which is required too",
+                    "'args.c': it's required, but it conflicts with every argument of the \
+                     required group 'mode'"]);
+    let app = App::new("prog")
+        .arg(Arg::with_name("a").long("a").required(true).conflicts_with("fast"))
+        .arg(Arg::with_name("fast").long("fast"))
+        .arg(Arg::with_name("slow").long("slow"))
+        .group(ArgGroup::with_name("mode").args(&["fast", "slow"]).required(true));
+    assert!(app.validate_definition().is_ok());
+}
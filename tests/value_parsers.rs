@@ -0,0 +1,201 @@
+#[macro_use]
+extern crate clap;
+extern crate regex;
+
+include!("../clap-test.rs");
+
+use std::ffi::OsStr;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{App, AppSettings, Arg, ArgMatches, BoolParser, DurationParser, EnumParser, ErrorKind,
+           IntParser, PathParser, SubCommand};
+
+static PORT_RANGE: &'static str = "error: Invalid value '80' for '--port <port>': must be between \
+                                   1024 and 49151
+
+USAGE:
+    clap-test --port <port>
+
+For more information try --help";
+
+arg_enum!{
+    #[derive(Debug, PartialEq)]
+    enum Format {
+        Json,
+        Yaml
+    }
+}
+
+fn app() -> App<'static, 'static> {
+    App::new("prog")
+        .arg(Arg::with_name("port")
+                 .long("port")
+                 .takes_value(true)
+                 .value_parser(IntParser::<u16>::range(1024, 49151)))
+        .arg(Arg::with_name("verbose").long("verbose").takes_value(true).value_parser(BoolParser))
+        .arg(Arg::with_name("timeout")
+                 .long("timeout")
+                 .takes_value(true)
+                 .default_value("30s")
+                 .value_parser(DurationParser))
+        .arg(Arg::with_name("format")
+                 .long("format")
+                 .takes_value(true)
+                 .value_parser(EnumParser::<Format>::new(&Format::variants())))
+        .arg(Arg::with_name("files").multiple(true).value_parser(PathParser::new()))
+}
+
+#[test]
+fn built_in_parsers() {
+    let m = app().get_matches_from(vec!["prog", "--port", "8080", "--verbose", "YES", "--timeout",
+                                        "1m", "--format", "yaml", "a", "b"]);
+    assert_eq!(m.value_of_parsed::<u16, _>("port"), Some(&8080));
+    assert_eq!(m.value_of_parsed::<bool, _>("verbose"), Some(&true));
+    assert_eq!(m.value_of_parsed::<Duration, _>("timeout"), Some(&Duration::from_secs(60)));
+    assert_eq!(m.value_of_parsed::<Format, _>("format"), Some(&Format::Yaml));
+    assert_eq!(m.values_of_parsed::<PathBuf, _>("files"),
+               Some(vec![&PathBuf::from("a"), &PathBuf::from("b")]));
+    // The raw values are still available
+    assert_eq!(m.value_of("port"), Some("8080"));
+}
+
+#[test]
+fn defaults_are_parsed() {
+    let m = app().get_matches_from(vec!["prog"]);
+    assert_eq!(m.value_of_parsed::<Duration, _>("timeout"), Some(&Duration::from_secs(30)));
+    assert_eq!(m.value_of_parsed::<u16, _>("port"), None);
+    assert_eq!(m.values_of_parsed::<PathBuf, _>("files"), None);
+}
+
+#[test]
+fn parse_errors() {
+    let kind = |args: Vec<&str>| app().get_matches_from_safe(args).unwrap_err().kind;
+    assert_eq!(kind(vec!["prog", "--port", "http"]), ErrorKind::ValueValidation);
+    assert_eq!(kind(vec!["prog", "--verbose", "maybe"]), ErrorKind::ValueValidation);
+    assert_eq!(kind(vec!["prog", "--timeout", "soon"]), ErrorKind::ValueValidation);
+    assert_eq!(kind(vec!["prog", "--format", "toml"]), ErrorKind::ValueValidation);
+    assert_eq!(kind(vec!["prog", "--port"]), ErrorKind::EmptyValue);
+}
+
+#[test]
+fn parse_error_output() {
+    let app = App::new("prog").arg(Arg::with_name("port")
+                                       .long("port")
+                                       .takes_value(true)
+                                       .value_parser(IntParser::<u16>::range(1024, 49151)));
+    assert!(test::compare_output(app, "clap-test --port 80", PORT_RANGE, true));
+}
+
+#[test]
+fn path_must_exist() {
+    let res = App::new("prog")
+        .arg(Arg::with_name("config").value_parser(PathParser::new().must_exist(true)))
+        .get_matches_from_safe(vec!["prog", "/does/not/exist.toml"]);
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert!(err.message().contains("doesn't exist"));
+}
+
+#[test]
+fn possible_values_are_checked_first() {
+    let err = App::new("prog")
+        .arg(Arg::with_name("level")
+                 .possible_values(&["1", "2"])
+                 .value_parser(IntParser::<u8>::new()))
+        .get_matches_from_safe(vec!["prog", "x"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn closure_parsers() {
+    let split = |v: &OsStr| -> Result<(String, String), String> {
+        let s = v.to_string_lossy();
+        match s.find('=') {
+            Some(i) => Ok((s[..i].to_owned(), s[i + 1..].to_owned())),
+            None => Err(format!("'{}' isn't of the form KEY=VALUE", s)),
+        }
+    };
+    let m = App::new("prog")
+        .arg(Arg::with_name("define")
+                 .short("D")
+                 .takes_value(true)
+                 .multiple(true)
+                 .value_parser(split))
+        .get_matches_from(vec!["prog", "-D", "a=1", "-D", "b=2"]);
+    assert_eq!(m.values_of_parsed::<(String, String), _>("define").unwrap(),
+               [&("a".to_owned(), "1".to_owned()), &("b".to_owned(), "2".to_owned())]);
+}
+
+#[test]
+fn app_validators_see_parsed_values() {
+    fn ordered(m: &ArgMatches) -> Result<(), String> {
+        let start = m.value_of_parsed::<u32, _>("start").unwrap();
+        let end = m.value_of_parsed::<u32, _>("end").unwrap();
+        if end > start {
+            Ok(())
+        } else {
+            Err(String::from("--end must be greater than --start"))
+        }
+    }
+    let res = App::new("prog")
+        .arg(Arg::with_name("start")
+                 .long("start")
+                 .default_value("0")
+                 .value_parser(IntParser::<u32>::new()))
+        .arg(Arg::with_name("end")
+                 .long("end")
+                 .default_value("10")
+                 .value_parser(IntParser::<u32>::new()))
+        .validator(ordered)
+        .get_matches_from_safe(vec!["prog", "--start", "20"]);
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MatchesValidation);
+}
+
+#[test]
+fn propagated_global_values() {
+    let m = App::new("prog")
+        .setting(AppSettings::PropagateGlobalValuesDown)
+        .arg(Arg::with_name("jobs")
+                 .long("jobs")
+                 .takes_value(true)
+                 .global(true)
+                 .value_parser(IntParser::<u8>::new()))
+        .subcommand(SubCommand::with_name("build"))
+        .get_matches_from(vec!["prog", "--jobs", "4", "build"]);
+    let sub = m.subcommand_matches("build").unwrap();
+    assert_eq!(sub.value_of_parsed::<u8, _>("jobs"), Some(&4));
+}
+
+#[test]
+#[should_panic(expected = "The argument 'plain' doesn't have a value parser")]
+fn no_value_parser() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("plain"))
+        .get_matches_from(vec!["prog", "x"]);
+    m.value_of_parsed::<String, _>("plain");
+}
+
+#[test]
+#[should_panic(expected = "The argument 'verbose' doesn't have a value parser")]
+fn flag_has_no_value_parser() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .get_matches_from(vec!["prog", "--verbose"]);
+    m.value_of_parsed::<bool, _>("verbose");
+}
+
+#[test]
+#[should_panic(expected = "Argument 'verbose' has a value parser but doesn't take a value")]
+fn value_parser_on_flag() {
+    App::new("prog").arg(Arg::with_name("verbose").long("verbose").value_parser(BoolParser));
+}
+
+#[test]
+#[should_panic(expected = "The value parser of 'port' doesn't produce values of the requested \
+                           type")]
+fn wrong_type() {
+    let m = app().get_matches_from(vec!["prog", "--port", "8080"]);
+    m.value_of_parsed::<u32, _>("port");
+}
@@ -0,0 +1,78 @@
+extern crate clap;
+
+use std::env;
+
+use clap::{App, Arg, ConfigSource, ValueSource};
+
+fn build_app() -> App<'static, 'static> {
+    App::new("vs")
+        .arg(Arg::with_name("jobs").long("jobs").takes_value(true).default_value("4"))
+        .arg(Arg::with_name("mode")
+            .long("mode")
+            .takes_value(true)
+            .default_value_if("jobs", Some("1"), "serial"))
+        .arg(Arg::with_name("flag").long("flag"))
+        .arg(Arg::with_name("input"))
+}
+
+#[test]
+fn value_source_command_line() {
+    let m = build_app().get_matches_from(vec!["vs", "--jobs", "4", "--flag", "file"]);
+    assert_eq!(m.value_source("jobs"), Some(ValueSource::CommandLine));
+    assert_eq!(m.value_source("flag"), Some(ValueSource::CommandLine));
+    assert_eq!(m.value_source("input"), Some(ValueSource::CommandLine));
+}
+
+#[test]
+fn value_source_not_present() {
+    let m = build_app().get_matches_from(vec!["vs"]);
+    assert_eq!(m.value_source("flag"), None);
+    assert_eq!(m.value_source("mode"), None);
+    assert_eq!(m.value_source("input"), None);
+}
+
+#[test]
+fn value_source_default_value() {
+    let m = build_app().get_matches_from(vec!["vs"]);
+    assert!(m.is_present("jobs"));
+    assert_eq!(m.value_source("jobs"), Some(ValueSource::DefaultValue));
+}
+
+#[test]
+fn value_source_default_value_if() {
+    let m = build_app().get_matches_from(vec!["vs", "--jobs", "1"]);
+    assert_eq!(m.value_of("mode"), Some("serial"));
+    assert_eq!(m.value_source("mode"), Some(ValueSource::DefaultValueIf));
+}
+
+#[test]
+fn value_source_env() {
+    env::set_var("CLP_TEST_VALUE_SOURCE_ENV", "8");
+    let m = App::new("vs")
+        .arg(Arg::with_name("jobs")
+            .long("jobs")
+            .takes_value(true)
+            .env("CLP_TEST_VALUE_SOURCE_ENV")
+            .default_value("4"))
+        .get_matches_from(vec!["vs"]);
+    assert_eq!(m.value_of("jobs"), Some("8"));
+    assert_eq!(m.value_source("jobs"), Some(ValueSource::EnvVariable));
+}
+
+#[test]
+fn value_source_config() {
+    let m = build_app()
+        .config(ConfigSource::new("cfg").value("jobs", "2").value("flag", "true"))
+        .get_matches_from(vec!["vs"]);
+    assert_eq!(m.value_source("jobs"), Some(ValueSource::Config));
+    assert_eq!(m.value_source("flag"), Some(ValueSource::Config));
+}
+
+#[test]
+fn value_source_cli_overrides_config() {
+    let m = build_app()
+        .config(ConfigSource::new("cfg").value("jobs", "2"))
+        .get_matches_from(vec!["vs", "--jobs=6"]);
+    assert_eq!(m.value_of("jobs"), Some("6"));
+    assert_eq!(m.value_source("jobs"), Some(ValueSource::CommandLine));
+}
@@ -3,7 +3,7 @@
 #[macro_use]
 extern crate clap;
 
-use clap::App;
+use clap::{App, ErrorKind, ContextKind, ContextValue, YamlLoader};
 
 #[test]
 fn create_app_from_yaml() {
@@ -38,3 +38,100 @@ fn author() {
     assert!(help_string.contains(
         "Kevin K. <kbknapp@gmail.com>"));
 }
+
+#[test]
+fn try_from_yaml() {
+    let docs = YamlLoader::load_from_str("
+name: prog
+version: \"1.0\"
+settings: SubcommandsNegateReqs
+args:
+    - verbose:
+        short: v
+        multiple: true
+    - mode:
+        long: mode
+        takes_value: true
+        possible_values: [fast, slow]
+        default_value_if:
+            - [verbose, null, fast]
+    - input:
+        required: true
+        index: 1
+groups:
+    - modes:
+        args: [mode, verbose]
+        multiple: true
+subcommands:
+    - test:
+        args:
+            - name: debug
+              short: d
+").unwrap();
+    let m = App::try_from_yaml(&docs[0])
+        .unwrap()
+        .get_matches_from(vec!["prog", "-vv", "file"]);
+    assert_eq!(m.occurrences_of("verbose"), 2);
+    assert_eq!(m.value_of("mode"), Some("fast"));
+    assert_eq!(m.value_of("input"), Some("file"));
+
+    let m = App::try_from_yaml(&docs[0])
+        .unwrap()
+        .get_matches_from(vec!["prog", "test", "-d"]);
+    assert!(m.subcommand_matches("test").unwrap().is_present("debug"));
+}
+
+#[test]
+fn try_from_yaml_catches_ignored_keys() {
+    // `from_yaml` silently ignores the misspelled `arg_groups` key
+    let yml = load_yaml!("app.yml");
+    let err = App::try_from_yaml(yml).err().unwrap();
+    assert_eq!(err.get(ContextKind::KeyPath),
+               Some(&ContextValue::Strings(vec!["".into()])));
+    assert!(err.message().contains("Invalid definition: unknown key 'arg_groups'"));
+}
+
+#[test]
+fn try_from_yaml_reports_paths() {
+    let docs = YamlLoader::load_from_str("
+name: prog
+args:
+    - verbose:
+        short: v
+        requried: true
+subcommands:
+    - one:
+        about: first
+    - two:
+        about: second
+    - three:
+        args:
+            - mode:
+                possible_values:
+                    fast: true
+        groups:
+            - modes:
+                multiple: yes please
+").unwrap();
+    let err = App::try_from_yaml(&docs[0]).err().unwrap();
+    assert_eq!(err.kind, ErrorKind::InvalidDefinition);
+    assert_eq!(err.get(ContextKind::KeyPath),
+               Some(&ContextValue::Strings(vec!["args[0]".into(),
+                                                "subcommands[2].args[0].possible_values".into(),
+                                                "subcommands[2].groups[0].multiple".into()])));
+    let msg = err.message();
+    assert!(msg.contains("'args[0]': unknown key 'requried'"));
+    assert!(msg.contains("'subcommands[2].args[0].possible_values': expected a string or an \
+                          array of strings, found a table"));
+    assert!(msg.contains("'subcommands[2].groups[0].multiple': expected a boolean, found a \
+                          string"));
+}
+
+#[test]
+fn try_from_yaml_not_a_mapping() {
+    let docs = YamlLoader::load_from_str("- a\n- b").unwrap();
+    let err = App::try_from_yaml(&docs[0]).err().unwrap();
+    assert_eq!(err.get(ContextKind::KeyPath),
+               Some(&ContextValue::Strings(vec!["".into()])));
+    assert!(err.message().contains("Invalid definition: expected a table, found an array"));
+}